@@ -15,6 +15,18 @@ class Endianness(_BaseEnum):
     LITTLE = 1
 
 
+class RoundingMode(_BaseEnum):
+    UP = 0
+    DOWN = 1
+    CEILING = 2
+    FLOOR = 3
+    HALF_UP = 4
+    HALF_DOWN = 5
+    HALF_EVEN = 6
+    UNNECESSARY = 7
+    HALF_ODD = 8
+
+
 class TieBreaking(_BaseEnum):
     AWAY_FROM_ZERO = 0
     TO_EVEN = 1
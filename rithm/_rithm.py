@@ -1,15 +1,19 @@
 from __future__ import annotations
 
+import re as _re
 import typing as _t
-from math import gcd as _gcd
+from math import (frexp as _frexp,
+                  gcd as _gcd)
 from numbers import (Integral as _Integral,
                      Rational as _Rational)
 from operator import mul as _mul
-from sys import hash_info as _hash_info
+from sys import (float_info as _float_info,
+                 hash_info as _hash_info)
 
 import typing_extensions as _te
 
 from .enums import (Endianness as _Endianness,
+                    RoundingMode as _RoundingMode,
                     TieBreaking as _TieBreaking)
 
 
@@ -139,6 +143,40 @@ class Int:
                       if isinstance(other, int)
                       else NotImplemented))
 
+    def __format__(self, format_spec: str, /) -> str:
+        match = _INT_FLOAT_FORMAT_SPECIFICATION_MATCHER(format_spec)
+        if match is None:
+            try:
+                return format(self._value, format_spec)
+            except ValueError as error:
+                raise ValueError(
+                        str(error).replace('type \'int\'', 'type \'Int\'')
+                ) from None
+        zero_padding = bool(match['zeropad'])
+        fill = match['fill'] or ('0' if zero_padding else ' ')
+        align = match['align'] or ('=' if zero_padding else '>')
+        minimum_width = int(match['minimumwidth'] or '0')
+        thousands_separator = match['thousands_sep'] or ''
+        negative, leading, trailing = _to_float_format_parts(
+                self._value, 1, match
+        )
+        sign = '-' if negative else match['sign'].replace('-', '')
+        if align == '=':
+            leading_width = max(minimum_width - len(sign) - len(trailing), 0)
+            leading = format(int(leading),
+                             f'{fill}={leading_width}{thousands_separator}')
+            return sign + leading + trailing
+        body = (sign + format(int(leading), thousands_separator)
+                + trailing)
+        padding = fill * (minimum_width - len(body))
+        if align == '<':
+            return body + padding
+        elif align == '^':
+            half = len(padding) // 2
+            return padding[:half] + body + padding[half:]
+        else:
+            return padding + body
+
     def __ge__(self, other: _t.Union[Int, int], /) -> bool:
         return (self._value >= other._value
                 if isinstance(other, Int)
@@ -350,6 +388,122 @@ def _to_bytes_count(value: int, /) -> int:
     return (8 + (value + (value < 0)).bit_length()) // 8
 
 
+_FRACTION_FORMAT_SPECIFICATION_MATCHER = _re.compile(
+        r'(?:(?P<fill>.)?(?P<align>[<>=^]))?'
+        r'(?P<sign>[-+ ]?)'
+        r'(?P<no_neg_zero>z)?'
+        r'(?P<alt>#)?'
+        r'(?P<zeropad>0(?=[0-9]))?'
+        r'(?P<minimumwidth>0|[1-9][0-9]*)?'
+        r'(?P<thousands_sep>[,_])?'
+        r'(?:\.(?P<precision>0|[1-9][0-9]*))?'
+        r'(?P<presentation_type>[eEfFgG%])',
+        _re.DOTALL
+).fullmatch
+_FRACTION_STRING_MATCHER = _re.compile(
+        r'\s*'
+        r'(?P<sign>[-+]?)'
+        r'(?:(?P<numerator>[0-9]+(?:_[0-9]+)*)'
+        r'/(?P<denominator>[0-9]+(?:_[0-9]+)*)'
+        r'|(?P<integral>[0-9]+(?:_[0-9]+)*)?'
+        r'(?:\.(?P<fractional>[0-9]+(?:_[0-9]+)*)?'
+        r'(?:\((?P<repeating>[0-9]+(?:_[0-9]+)*)\))?)?'
+        r'(?:[eE](?P<exponent>[-+]?[0-9]+(?:_[0-9]+)*))?)'
+        r'\s*'
+).fullmatch
+_INT_FLOAT_FORMAT_SPECIFICATION_MATCHER = _re.compile(
+        r'(?:(?P<fill>.)?(?P<align>[<>=^]))?'
+        r'(?P<sign>[-+ ]?)'
+        r'(?P<no_neg_zero>z)?'
+        r'(?P<alt>#)?'
+        r'(?P<zeropad>0(?=[0-9]))?'
+        r'(?P<minimumwidth>[0-9]*)'
+        r'(?P<thousands_sep>[,_])?'
+        r'(?:\.(?P<precision>[0-9]+))?'
+        r'(?P<presentation_type>[eEfFgG%])',
+        _re.DOTALL
+).fullmatch
+
+
+def _round_to_exponent(
+        numerator: int,
+        denominator: int,
+        exponent: int,
+        no_negative_zero: bool = False,
+        /
+) -> _t.Tuple[bool, int]:
+    if exponent >= 0:
+        denominator *= 10 ** exponent
+    else:
+        numerator *= 10 ** -exponent
+    quotient, remainder = divmod(numerator + (denominator >> 1), denominator)
+    if remainder == 0 and denominator & 1 == 0:
+        quotient &= -2
+    return (quotient < 0 if no_negative_zero else numerator < 0,
+            abs(quotient))
+
+
+def _round_to_figures(
+        numerator: int, denominator: int, figures: int, /
+) -> _t.Tuple[bool, int, int]:
+    if numerator == 0:
+        return False, 0, 1 - figures
+    numerator_string, denominator_string = (str(abs(numerator)),
+                                            str(denominator))
+    exponent = (len(numerator_string) - len(denominator_string)
+                + (denominator_string <= numerator_string) - figures)
+    negative, significand = _round_to_exponent(numerator, denominator,
+                                               exponent)
+    if len(str(significand)) == figures + 1:
+        significand //= 10
+        exponent += 1
+    return negative, significand, exponent
+
+
+def _to_float_format_parts(
+        numerator: int, denominator: int, match: _t.Match[str], /
+) -> _t.Tuple[bool, str, str]:
+    alternate_form = bool(match['alt'])
+    precision = int(match['precision'] or '6')
+    presentation_type = match['presentation_type']
+    if presentation_type in 'fF%':
+        exponent = -precision - (2 if presentation_type == '%' else 0)
+        negative, significand = _round_to_exponent(
+                numerator, denominator, exponent, bool(match['no_neg_zero'])
+        )
+        scientific = False
+        point_position = precision
+    else:
+        figures = (max(precision, 1)
+                   if presentation_type in 'gG'
+                   else precision + 1)
+        negative, significand, exponent = _round_to_figures(
+                numerator, denominator, figures
+        )
+        scientific = (presentation_type in 'eE'
+                      or exponent > 0
+                      or exponent + figures <= -4)
+        point_position = figures - 1 if scientific else -exponent
+    if presentation_type == '%':
+        suffix = '%'
+    elif scientific:
+        exponent_indicator = 'E' if presentation_type in 'EFG' else 'e'
+        suffix = f'{exponent_indicator}{exponent + point_position:+03d}'
+    else:
+        suffix = ''
+    digits = f'{significand:0{point_position + 1}d}'
+    leading = digits[:len(digits) - point_position]
+    fractional = digits[len(digits) - point_position:]
+    if presentation_type in 'gG' and not alternate_form:
+        fractional = fractional.rstrip('0')
+    separator = '' if not alternate_form and not fractional else '.'
+    return negative, leading, separator + fractional + suffix
+
+
+_DIGITS_SYMBOLS = '0123456789abcdefghijklmnopqrstuvwxyz'
+_MAX_EXPONENT = 1 << 20
+_MAX_REPRESENTABLE_BASE = len(_DIGITS_SYMBOLS)
+_MIN_REPRESENTABLE_BASE = 2
 _ONE = Int(1)
 _ZERO = Int()
 _HASH_INF = Int(_hash_info.inf)
@@ -367,7 +521,49 @@ class Fraction:
     def numerator(self) -> Int:
         return self._numerator
 
-    def round(self, tie_breaking: _TieBreaking, /) -> Int:
+    def limit_denominator(
+            self, max_denominator: _t.Union[Int, int] = 1_000_000, /
+    ) -> _te.Self:
+        max_denominator = Int(max_denominator)
+        if max_denominator < _ONE:
+            raise ValueError('max_denominator should be at least 1')
+        if self.denominator <= max_denominator:
+            return self
+        previous_numerator, previous_denominator = _ZERO, _ONE
+        numerator, denominator = _ONE, _ZERO
+        dividend, divisor = self.numerator, self.denominator
+        while True:
+            quotient, remainder = divmod(dividend, divisor)
+            next_denominator = previous_denominator + quotient * denominator
+            if next_denominator > max_denominator:
+                break
+            previous_numerator, numerator = (
+                numerator, previous_numerator + quotient * numerator
+            )
+            previous_denominator, denominator = denominator, next_denominator
+            dividend, divisor = divisor, remainder
+        step = (max_denominator - previous_denominator) // denominator
+        bound_denominator = previous_denominator + step * denominator
+        return (Fraction(numerator, denominator,
+                         _normalize=False)
+                if 2 * divisor * bound_denominator <= self.denominator
+                else Fraction(previous_numerator + step * numerator,
+                              bound_denominator,
+                              _normalize=False))
+
+    def round(self,
+              mode: _t.Union[_RoundingMode, _TieBreaking],
+              /) -> Int:
+        if isinstance(mode, _RoundingMode):
+            is_negative = self.numerator < _ZERO
+            quotient, remainder = divmod(abs(self.numerator),
+                                         self.denominator)
+            if _rounds_magnitude_up(mode, is_negative, bool(quotient % 2),
+                                    not remainder, remainder * 2,
+                                    self.denominator):
+                quotient += _ONE
+            return -quotient if is_negative else quotient
+        tie_breaking = mode
         quotient, remainder = divmod(self.numerator, self.denominator)
         double_remainder = remainder * 2
         if double_remainder == self.denominator:
@@ -387,6 +583,108 @@ class Fraction:
                     if double_remainder > self.denominator
                     else quotient)
 
+    def to_decimal_string(self, /) -> str:
+        denominator, twos_count = _to_without_factor(self.denominator, 2)
+        denominator, fives_count = _to_without_factor(denominator, 5)
+        if denominator != _ONE:
+            raise ValueError('Fraction with denominator having prime factors '
+                             'other than 2 and 5 '
+                             'has no finite decimal representation.')
+        digits_count = max(twos_count, fives_count)
+        digits = str(abs(self.numerator)
+                     * 2 ** (digits_count - twos_count)
+                     * 5 ** (digits_count - fives_count)).zfill(
+                digits_count + 1
+        )
+        integral_digits, fractional_digits = (
+            digits[:len(digits) - digits_count],
+            digits[len(digits) - digits_count:]
+        )
+        return (('-' if self.numerator < _ZERO else '')
+                + integral_digits
+                + ('.' + fractional_digits if fractional_digits else ''))
+
+    def to_fixed_string(self, ndigits: int, /) -> str:
+        if ndigits < 0:
+            raise ValueError('Number of digits should be non-negative, '
+                             f'but found: {ndigits!r}.')
+        scaled_value = (self * 10 ** ndigits).round(_TieBreaking.TO_EVEN)
+        digits = str(abs(scaled_value)).zfill(ndigits + 1)
+        integral_digits, fractional_digits = (
+            digits[:len(digits) - ndigits], digits[len(digits) - ndigits:]
+        )
+        return (('-' if scaled_value < _ZERO else '')
+                + integral_digits
+                + ('.' + fractional_digits if fractional_digits else ''))
+
+    def to_positional_string(self, base: int = 10, /) -> str:
+        if not (_MIN_REPRESENTABLE_BASE <= base <= _MAX_REPRESENTABLE_BASE):
+            raise ValueError('Base should be in range '
+                             f'from {_MIN_REPRESENTABLE_BASE} '
+                             f'to {_MAX_REPRESENTABLE_BASE}, '
+                             f'but found: {base!r}.')
+        integral_part, remainder = divmod(abs(self.numerator),
+                                          self.denominator)
+        result = (('-' if self.numerator < _ZERO else '')
+                  + _to_base_string(integral_part, base))
+        repeating_denominator, non_repeating_digits_count = (
+            self.denominator, 0
+        )
+        while True:
+            gcd = repeating_denominator.gcd(Int(base))
+            if gcd == _ONE:
+                break
+            repeating_denominator //= gcd
+            non_repeating_digits_count += 1
+        period = _to_multiplicative_order(base, repeating_denominator)
+        if not non_repeating_digits_count and not period:
+            return result
+        non_repeating_part, repeating_numerator = divmod(
+                remainder * base ** non_repeating_digits_count,
+                self.denominator
+        )
+        result += '.'
+        if non_repeating_digits_count:
+            result += _to_base_string(non_repeating_part, base).zfill(
+                    non_repeating_digits_count
+            )
+        if period:
+            repeating_part = (
+                    repeating_numerator
+                    // (self.denominator // repeating_denominator)
+                    * (base ** period - 1)
+                    // repeating_denominator
+            )
+            result += ('(' + _to_base_string(repeating_part, base).zfill(period)
+                       + ')')
+        return result
+
+    @classmethod
+    def simplest_from_float(cls, value: float, /) -> _te.Self:
+        exact = cls(value)
+        if not value:
+            return exact
+        modulus = abs(exact)
+        fraction, exponent = _frexp(abs(value))
+        unit_exponent = (max(exponent, _float_info.min_exp)
+                         - _float_info.mant_dig)
+        upper_gap = _to_power_of_two(unit_exponent - 1)
+        lower_gap = (_to_power_of_two(unit_exponent - 2)
+                     if fraction == 0.5 and exponent > _float_info.min_exp
+                     else upper_gap)
+        lower, upper = modulus - lower_gap, modulus + upper_gap
+        if (modulus / _to_power_of_two(unit_exponent)).numerator % 2:
+            epsilon = Fraction(1, 4 * modulus.denominator ** 2)
+            lower, upper = lower + epsilon, upper - epsilon
+        result = Fraction(
+                *_to_simplest_positive_between(lower.numerator,
+                                               lower.denominator,
+                                               upper.numerator,
+                                               upper.denominator),
+                _normalize=False
+        )
+        return -result if value < 0 else result
+
     _denominator: Int
     _numerator: Int
 
@@ -398,7 +696,7 @@ class Fraction:
                         'is not an acceptable base type')
 
     def __new__(cls,
-                numerator: _t.Union[Int, int, float] = _ZERO,
+                numerator: _t.Union[Int, int, float, str] = _ZERO,
                 denominator: _t.Union[Int, None, int] = None,
                 /,
                 *,
@@ -417,9 +715,13 @@ class Fraction:
             elif isinstance(numerator, _Rational):
                 numerator, denominator = (Int(numerator.numerator),
                                           Int(numerator.denominator))
+            elif isinstance(numerator, str):
+                numerator, denominator = _parse_fraction_components(
+                        numerator
+                )
             else:
                 raise TypeError('First argument should be of '
-                                f'type {Int}, {int} or {float}, '
+                                f'type {Int}, {int}, {float} or {str}, '
                                 f'but found: {type(numerator)}.')
         elif isinstance(denominator, (Int, int)):
             if not isinstance(numerator, (Int, int)):
@@ -493,6 +795,49 @@ class Fraction:
                       if isinstance(divisor, (Int, int))
                       else NotImplemented))
 
+    def __format__(self, format_spec: str, /) -> str:
+        if not format_spec:
+            return str(self)
+        match = _FRACTION_FORMAT_SPECIFICATION_MATCHER(format_spec)
+        if match is None:
+            raise ValueError(f'Invalid format specifier {format_spec!r} '
+                             'for object of type \'Fraction\'')
+        elif match['align'] is not None and match['zeropad'] is not None:
+            raise ValueError(f'Invalid format specifier {format_spec!r} '
+                             'for object of type \'Fraction\'; '
+                             'can\'t use explicit alignment '
+                             'when zero-padding')
+        fill = match['fill'] or ' '
+        align = match['align'] or '>'
+        minimum_width = int(match['minimumwidth'] or '0')
+        thousands_separator = match['thousands_sep']
+        negative, leading, trailing = _to_float_format_parts(
+                int(self.numerator), int(self.denominator), match
+        )
+        sign = '-' if negative else match['sign'].replace('-', '')
+        if match['zeropad']:
+            minimum_leading_length = minimum_width - len(sign) - len(trailing)
+            leading = leading.zfill(3 * minimum_leading_length // 4 + 1
+                                    if thousands_separator
+                                    else minimum_leading_length)
+        if thousands_separator:
+            first_position = 1 + (len(leading) - 1) % 3
+            leading = leading[:first_position] + ''.join(
+                    thousands_separator + leading[position:position + 3]
+                    for position in range(first_position, len(leading), 3)
+            )
+        body = leading + trailing
+        padding = fill * (minimum_width - len(sign) - len(body))
+        if align == '>':
+            return padding + sign + body
+        elif align == '<':
+            return sign + body + padding
+        elif align == '^':
+            half = len(padding) // 2
+            return padding[:half] + sign + body + padding[half:]
+        else:
+            return sign + padding + body
+
     def __ge__(self, other: _t.Union[Int, _te.Self, int], /) -> bool:
         return (self.numerator * other.denominator
                 >= other.numerator * self.denominator
@@ -769,6 +1114,161 @@ def _divmod_rationals(
                               dividend.denominator * divisor.denominator)
 
 
+def _parse_fraction_components(string: str, /) -> _t.Tuple[Int, Int]:
+    match = _FRACTION_STRING_MATCHER(string)
+    if match is None or not (match['numerator']
+                             or match['integral']
+                             or match['fractional']
+                             or match['repeating']):
+        raise ValueError(f'Invalid literal for Fraction: {string!r}.')
+    sign = -1 if match['sign'] == '-' else 1
+    if match['numerator']:
+        return (sign * Int(match['numerator']), Int(match['denominator']))
+    integral, fractional, repeating = (
+        (match['integral'] or '').replace('_', ''),
+        (match['fractional'] or '').replace('_', ''),
+        (match['repeating'] or '').replace('_', '')
+    )
+    numerator = Int(integral + fractional or '0')
+    denominator = _to_power_of_ten(len(fractional))
+    if repeating:
+        numerator = Int(integral + fractional + repeating) - numerator
+        denominator *= _to_power_of_ten(len(repeating)) - _ONE
+    exponent = int(match['exponent'] or '0')
+    if abs(exponent) > _MAX_EXPONENT:
+        raise ValueError('Exponent is out of bounds.')
+    if exponent < 0:
+        denominator *= _to_power_of_ten(-exponent)
+    else:
+        numerator *= _to_power_of_ten(exponent)
+    return sign * numerator, denominator
+
+
+def _to_power_of_ten(exponent: int, /) -> Int:
+    return Int(10) ** exponent
+
+
+def _to_power_of_two(exponent: int, /) -> Fraction:
+    return (Fraction(_ONE << exponent)
+            if exponent >= 0
+            else Fraction(_ONE, _ONE << -exponent, _normalize=False))
+
+
+def _to_simplest_positive_between(lower_numerator: Int,
+                                  lower_denominator: Int,
+                                  upper_numerator: Int,
+                                  upper_denominator: Int,
+                                  /) -> _t.Tuple[Int, Int]:
+    quotients = []
+    while True:
+        quotient, remainder = divmod(lower_numerator, lower_denominator)
+        if not remainder:
+            quotients.append(quotient)
+            break
+        if (quotient + _ONE) * upper_denominator <= upper_numerator:
+            quotients.append(quotient + _ONE)
+            break
+        quotients.append(quotient)
+        (lower_numerator, lower_denominator, upper_numerator,
+         upper_denominator) = (upper_denominator,
+                               upper_numerator - quotient * upper_denominator,
+                               lower_denominator, remainder)
+    numerator, denominator = _ONE, _ZERO
+    for quotient in reversed(quotients):
+        numerator, denominator = quotient * numerator + denominator, numerator
+    return numerator, denominator
+
+
+def _rounds_magnitude_up(mode: _RoundingMode,
+                         is_negative: bool,
+                         is_truncated_odd: bool,
+                         is_exact: bool,
+                         doubled_remainder: Int,
+                         divisor: Int,
+                         /) -> bool:
+    if is_exact:
+        return False
+    elif mode is _RoundingMode.UP:
+        return True
+    elif mode is _RoundingMode.DOWN:
+        return False
+    elif mode is _RoundingMode.CEILING:
+        return not is_negative
+    elif mode is _RoundingMode.FLOOR:
+        return is_negative
+    elif mode is _RoundingMode.HALF_UP:
+        return doubled_remainder >= divisor
+    elif mode is _RoundingMode.HALF_DOWN:
+        return doubled_remainder > divisor
+    elif mode is _RoundingMode.HALF_EVEN:
+        return (doubled_remainder > divisor
+                or doubled_remainder == divisor and is_truncated_odd)
+    elif mode is _RoundingMode.HALF_ODD:
+        return (doubled_remainder > divisor
+                or doubled_remainder == divisor and not is_truncated_odd)
+    else:
+        assert mode is _RoundingMode.UNNECESSARY, mode
+        raise ValueError('Rounding is necessary, '
+                         'but rounding mode forbids it.')
+
+
+def _to_base_string(value: Int, base: int, /) -> str:
+    if not value:
+        return '0'
+    digits = []
+    while value:
+        value, digit = divmod(value, base)
+        digits.append(_DIGITS_SYMBOLS[digit])
+    return ''.join(reversed(digits))
+
+
+def _to_multiplicative_order(base: int, modulus: Int, /) -> int:
+    if modulus == _ONE:
+        return 0
+    modulus = int(modulus)
+    # the order divides Euler's totient of the modulus,
+    # so prime factors are stripped from the totient
+    # while the reduced exponent still gives the unit power
+    totient, totient_prime_factors = 1, set()
+    for prime, multiplicity in _to_prime_factors(modulus):
+        totient *= (prime - 1) * prime ** (multiplicity - 1)
+        if multiplicity > 1:
+            totient_prime_factors.add(prime)
+        totient_prime_factors.update(
+                factor for factor, _ in _to_prime_factors(prime - 1)
+        )
+    result = totient
+    for factor in sorted(totient_prime_factors):
+        while (not result % factor
+               and pow(base, result // factor, modulus) == 1):
+            result //= factor
+    return result
+
+
+def _to_prime_factors(value: int, /) -> _t.List[_t.Tuple[int, int]]:
+    result = []
+    divisor = 2
+    while divisor * divisor <= value:
+        multiplicity = 0
+        while not value % divisor:
+            value //= divisor
+            multiplicity += 1
+        if multiplicity:
+            result.append((divisor, multiplicity))
+        divisor += 1 if divisor == 2 else 2
+    if value != 1:
+        result.append((value, 1))
+    return result
+
+
+def _to_without_factor(value: Int, factor: int, /) -> _t.Tuple[Int, int]:
+    count = 0
+    while not value % factor:
+        value //= factor
+        count += 1
+    return value, count
+
+
 def _normalize_components_moduli(
         numerator: Int, denominator: Int, /
 ) -> _t.Tuple[Int, Int]:
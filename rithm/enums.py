@@ -1,6 +1,8 @@
 try:
     from ._crithm import (Endianness,
+                          RoundingMode,
                           TieBreaking)
 except ImportError:
     from ._enums import (Endianness,
+                         RoundingMode,
                          TieBreaking)
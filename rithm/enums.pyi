@@ -18,6 +18,28 @@ class Endianness:
         ...
 
 
+class RoundingMode:
+    UP: RoundingMode = ...
+    DOWN: RoundingMode = ...
+    CEILING: RoundingMode = ...
+    FLOOR: RoundingMode = ...
+    HALF_UP: RoundingMode = ...
+    HALF_DOWN: RoundingMode = ...
+    HALF_EVEN: RoundingMode = ...
+    UNNECESSARY: RoundingMode = ...
+    HALF_ODD: RoundingMode = ...
+
+    @property
+    def value(self) -> int:
+        ...
+
+    def __new__(cls, value: int, /) -> _te.Self:
+        ...
+
+    def __repr__(self) -> str:
+        ...
+
+
 class TieBreaking:
     AWAY_FROM_ZERO: TieBreaking = ...
     TO_EVEN: TieBreaking = ...
@@ -5,7 +5,8 @@ from numbers import Rational as _Rational
 
 import typing_extensions as _te
 
-from .enums import TieBreaking
+from .enums import (RoundingMode,
+                    TieBreaking)
 from .integer import Int
 
 
@@ -18,12 +19,32 @@ class Fraction:
     def numerator(self) -> Int:
         ...
 
-    def round(self, tie_breaking: TieBreaking, /) -> Int:
+    def limit_denominator(
+            self, max_denominator: _t.Union[Int, int] = ..., /
+    ) -> _te.Self:
+        ...
+
+    def round(self, mode: _t.Union[RoundingMode, TieBreaking], /) -> Int:
+        ...
+
+    def to_decimal_string(self, /) -> str:
+        ...
+
+    def to_fixed_string(self, ndigits: int, /) -> str:
+        ...
+
+    def to_positional_string(self, base: int = ..., /) -> str:
+        ...
+
+    @classmethod
+    def simplest_from_float(cls, value: float, /) -> _te.Self:
         ...
 
     @_t.overload
     def __new__(
-            cls, value: _t.Union[_te.Self, Int, _Rational, float, int] = ..., /
+            cls,
+            value: _t.Union[_te.Self, Int, _Rational, float, int, str] = ...,
+            /
     ) -> _te.Self:
         ...
 
@@ -68,6 +89,9 @@ class Fraction:
     def __floordiv__(self, divisor: _t.Union[_te.Self, Int, int], /) -> Int:
         ...
 
+    def __format__(self, format_spec: str, /) -> str:
+        ...
+
     def __ge__(self, other: _t.Union[_te.Self, Int, int], /) -> bool:
         ...
 
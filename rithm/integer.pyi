@@ -80,6 +80,9 @@ class Int:
     def __floordiv__(self, other: _t.Union[_te.Self, int], /) -> _te.Self:
         ...
 
+    def __format__(self, format_spec: str, /) -> str:
+        ...
+
     def __ge__(self, other: _t.Union[_te.Self, int], /) -> bool:
         ...
 
@@ -1,3 +1,4 @@
+import os
 import platform
 
 from setuptools import (find_packages,
@@ -10,6 +11,14 @@ parameters = dict(packages=find_packages(exclude=('tests', 'tests.*')),
 if platform.python_implementation() == 'CPython':
     from setuptools_rust import RustExtension
 
-    parameters.update(rust_extensions=[RustExtension('rithm._crithm')],
-                      zip_safe=False)
+    parameters.update(
+            rust_extensions=[
+                RustExtension(
+                        'rithm._crithm',
+                        features=os.environ.get('RITHM_CARGO_FEATURES',
+                                                '').split()
+                )
+            ],
+            zip_safe=False
+    )
 setup(**parameters)
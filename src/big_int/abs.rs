@@ -5,11 +5,9 @@ use super::types::BigInt;
 impl<Digit, const DIGIT_BITNESS: usize> Abs for BigInt<Digit, DIGIT_BITNESS> {
     type Output = Self;
 
-    fn abs(self) -> Self::Output {
-        Self::Output {
-            sign: self.sign.abs(),
-            digits: self.digits,
-        }
+    fn abs(mut self) -> Self::Output {
+        self.sign = self.sign.abs();
+        self
     }
 }
 
@@ -0,0 +1,17 @@
+use super::digits::MultiplyAddComponentsInPlace;
+use super::types::BigInt;
+
+impl<Digit: MultiplyAddComponentsInPlace, const DIGIT_BITNESS: usize>
+    BigInt<Digit, DIGIT_BITNESS>
+{
+    pub fn add_mul_assign(&mut self, first: &Self, second: &Self) {
+        Digit::multiply_add_components_in_place::<DIGIT_BITNESS>(
+            &mut self.sign,
+            &mut self.digits,
+            first.sign,
+            &first.digits,
+            second.sign,
+            &second.digits,
+        );
+    }
+}
@@ -7,13 +7,13 @@ impl<Digit: BitwiseAndComponents + Clone, const DIGIT_BITNESS: usize>
     BitAndAssign for BigInt<Digit, DIGIT_BITNESS>
 {
     fn bitand_assign(&mut self, other: Self) {
-        (self.sign, self.digits) =
-            Digit::bitwise_and_components::<DIGIT_BITNESS>(
-                self.sign,
-                self.digits.clone(),
-                other.sign,
-                other.digits,
-            );
+        let (sign, digits) = Digit::bitwise_and_components::<DIGIT_BITNESS>(
+            self.sign,
+            self.digits.clone(),
+            other.sign,
+            other.into_digits(),
+        );
+        *self = Self { sign, digits };
     }
 }
 
@@ -21,12 +21,12 @@ impl<Digit: BitwiseAndComponents + Clone, const DIGIT_BITNESS: usize>
     BitAndAssign<&Self> for BigInt<Digit, DIGIT_BITNESS>
 {
     fn bitand_assign(&mut self, other: &Self) {
-        (self.sign, self.digits) =
-            Digit::bitwise_and_components::<DIGIT_BITNESS>(
-                self.sign,
-                self.digits.clone(),
-                other.sign,
-                other.digits.clone(),
-            );
+        let (sign, digits) = Digit::bitwise_and_components::<DIGIT_BITNESS>(
+            self.sign,
+            self.digits.clone(),
+            other.sign,
+            other.digits.clone(),
+        );
+        *self = Self { sign, digits };
     }
 }
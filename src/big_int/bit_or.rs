@@ -11,9 +11,9 @@ impl<Digit: BitwiseOrComponents, const DIGIT_BITNESS: usize> BitOr
     fn bitor(self, other: Self) -> Self::Output {
         let (sign, digits) = Digit::bitwise_or_components::<DIGIT_BITNESS>(
             self.sign,
-            self.digits,
+            self.into_digits(),
             other.sign,
-            other.digits,
+            other.into_digits(),
         );
         Self::Output { sign, digits }
     }
@@ -27,7 +27,7 @@ impl<Digit: BitwiseOrComponents + Clone, const DIGIT_BITNESS: usize>
     fn bitor(self, other: &Self) -> Self::Output {
         let (sign, digits) = Digit::bitwise_or_components::<DIGIT_BITNESS>(
             self.sign,
-            self.digits,
+            self.into_digits(),
             other.sign,
             other.digits.clone(),
         );
@@ -45,7 +45,7 @@ impl<Digit: BitwiseOrComponents + Clone, const DIGIT_BITNESS: usize>
             self.sign,
             self.digits.clone(),
             other.sign,
-            other.digits,
+            other.into_digits(),
         );
         Self::Output { sign, digits }
     }
@@ -7,12 +7,13 @@ impl<Digit: BitwiseOrComponents + Clone, const DIGIT_BITNESS: usize>
     BitOrAssign for BigInt<Digit, DIGIT_BITNESS>
 {
     fn bitor_assign(&mut self, other: Self) {
-        (self.sign, self.digits) = Digit::bitwise_or_components::<DIGIT_BITNESS>(
+        let (sign, digits) = Digit::bitwise_or_components::<DIGIT_BITNESS>(
             self.sign,
             self.digits.clone(),
             other.sign,
-            other.digits,
+            other.into_digits(),
         );
+        *self = Self { sign, digits };
     }
 }
 
@@ -20,11 +21,12 @@ impl<Digit: BitwiseOrComponents + Clone, const DIGIT_BITNESS: usize>
     BitOrAssign<&Self> for BigInt<Digit, DIGIT_BITNESS>
 {
     fn bitor_assign(&mut self, other: &Self) {
-        (self.sign, self.digits) = Digit::bitwise_or_components::<DIGIT_BITNESS>(
+        let (sign, digits) = Digit::bitwise_or_components::<DIGIT_BITNESS>(
             self.sign,
             self.digits.clone(),
             other.sign,
             other.digits.clone(),
         );
+        *self = Self { sign, digits };
     }
 }
@@ -11,9 +11,9 @@ impl<Digit: BitwiseXorComponents, const DIGIT_BITNESS: usize> BitXor
     fn bitxor(self, other: Self) -> Self::Output {
         let (sign, digits) = Digit::bitwise_xor_components::<DIGIT_BITNESS>(
             self.sign,
-            self.digits,
+            self.into_digits(),
             other.sign,
-            other.digits,
+            other.into_digits(),
         );
         Self::Output { sign, digits }
     }
@@ -27,7 +27,7 @@ impl<Digit: BitwiseXorComponents + Clone, const DIGIT_BITNESS: usize>
     fn bitxor(self, other: &Self) -> Self::Output {
         let (sign, digits) = Digit::bitwise_xor_components::<DIGIT_BITNESS>(
             self.sign,
-            self.digits,
+            self.into_digits(),
             other.sign,
             other.digits.clone(),
         );
@@ -45,7 +45,7 @@ impl<Digit: BitwiseXorComponents + Clone, const DIGIT_BITNESS: usize>
             self.sign,
             self.digits.clone(),
             other.sign,
-            other.digits,
+            other.into_digits(),
         );
         Self::Output { sign, digits }
     }
@@ -0,0 +1,46 @@
+use std::ops::{Add, Neg, Shl};
+
+use traiter::numbers::{
+    Abs, CheckedDivRemEuclid, One, Parity, Signed, Zeroable,
+};
+
+use crate::rounding_mode::RoundingMode;
+
+use super::types::BigInt;
+
+impl<Digit, const DIGIT_BITNESS: usize> BigInt<Digit, DIGIT_BITNESS>
+where
+    for<'a> &'a Self: Abs<Output = Self>
+        + CheckedDivRemEuclid<Output = Option<(Self, Self)>>
+        + Parity
+        + Signed
+        + Zeroable,
+    Self: Add<Output = Self>
+        + Neg<Output = Self>
+        + One
+        + Ord
+        + Shl<usize, Output = Self>,
+{
+    pub fn checked_div_rounded(
+        &self,
+        divisor: &Self,
+        mode: RoundingMode,
+    ) -> Option<Self> {
+        let divisor_modulus = divisor.abs();
+        let (quotient, remainder) =
+            (&self.abs()).checked_div_rem_euclid(&divisor_modulus)?;
+        let is_negative = self.is_negative() != divisor.is_negative();
+        let is_exact = (&remainder).is_zero();
+        let magnitude = if mode.rounds_magnitude_up(
+            is_negative,
+            (&quotient).is_odd(),
+            is_exact,
+            (remainder << 1).cmp(&divisor_modulus),
+        )? {
+            quotient + Self::one()
+        } else {
+            quotient
+        };
+        Some(if is_negative { -magnitude } else { magnitude })
+    }
+}
@@ -1,10 +1,13 @@
-use std::ops::{BitAnd, Mul, MulAssign, Shl, ShlAssign, ShrAssign};
+use std::ops::{BitAnd, Shl, ShlAssign, ShrAssign};
 
+use smallvec::CollectionAllocErr;
 use traiter::numbers::{CheckedPow, One, Signed, Unitary, Zero, Zeroable};
 
 use super::constants::{WINDOW_BASE, WINDOW_BITNESS, WINDOW_CUTOFF};
-use super::digits::LesserBinaryBaseFromBinaryDigits;
-use super::types::{BigInt, WindowDigit};
+use super::digits::{
+    expect_allocated, LesserBinaryBaseFromBinaryDigits, MultiplyDigits,
+};
+use super::types::{BigInt, Digits, WindowDigit};
 
 impl<Digit, const DIGIT_BITNESS: usize> CheckedPow<Self>
     for BigInt<Digit, DIGIT_BITNESS>
@@ -77,14 +80,22 @@ where
     }
 }
 
-trait UncheckedPow: Sized {
-    fn unchecked_pow(&self, exponent: &Self) -> Self;
+pub trait UncheckedPow: Sized {
+    fn unchecked_pow(&self, exponent: &Self) -> Self {
+        expect_allocated(self.try_unchecked_pow(exponent))
+    }
+
+    fn try_unchecked_pow(
+        &self,
+        exponent: &Self,
+    ) -> Result<Self, CollectionAllocErr>;
 }
 
 impl<
         Digit: BitAnd<Output = Digit>
             + Copy
             + From<u8>
+            + MultiplyDigits
             + One
             + PartialOrd
             + Shl<usize, Output = Digit>
@@ -94,25 +105,27 @@ impl<
         const DIGIT_BITNESS: usize,
     > UncheckedPow for BigInt<Digit, DIGIT_BITNESS>
 where
-    for<'a> &'a Self: Mul<Output = Self> + Zeroable,
+    for<'a> &'a Self: Zeroable,
     for<'a> &'a Digit: Unitary + Zeroable,
-    for<'a> Self: Mul<Output = Self> + MulAssign<&'a Self>,
     WindowDigit: LesserBinaryBaseFromBinaryDigits<Digit>,
 {
-    fn unchecked_pow(&self, exponent: &Self) -> Self {
+    fn try_unchecked_pow(
+        &self,
+        exponent: &Self,
+    ) -> Result<Self, CollectionAllocErr> {
         debug_assert!(!exponent.is_negative());
         let mut exponent_digit = exponent.digits[exponent.digits.len() - 1];
         if exponent.digits.len() == 1 && exponent_digit <= Digit::from(3) {
             let mut result = Self::one();
             if exponent_digit >= Digit::from(2) {
-                result = self * self;
+                result = try_multiply(self, self)?;
                 if exponent_digit == Digit::from(3) {
-                    result *= self;
+                    result = try_multiply(&result, self)?;
                 }
             } else if exponent_digit.is_one() {
-                result *= self;
+                result = try_multiply(&result, self)?;
             }
-            result
+            Ok(result)
         } else if exponent.digits.len() <= WINDOW_CUTOFF {
             let mut result = self.clone();
             let mut exponent_digit_mask = Digit::from(2);
@@ -128,9 +141,9 @@ where
                 exponent.digits.iter().rev().skip(1);
             loop {
                 while !exponent_digit_mask.is_zero() {
-                    result = &result * &result;
+                    result = try_multiply(&result, &result)?;
                     if !(exponent_digit & exponent_digit_mask).is_zero() {
-                        result *= self;
+                        result = try_multiply(&result, self)?;
                     }
                     exponent_digit_mask >>= 1;
                 }
@@ -145,14 +158,14 @@ where
                     }
                 }
             }
-            result
+            Ok(result)
         } else {
             let mut cache = vec![Self::zero(); WINDOW_BASE];
             cache[0] = Self::one();
             for index in 1..WINDOW_BASE {
-                cache[index] = &cache[index - 1] * self;
+                cache[index] = try_multiply(&cache[index - 1], self)?;
             }
-            let exponent_window_digits: Vec<WindowDigit> =
+            let exponent_window_digits: Digits<WindowDigit> =
                 WindowDigit::lesser_binary_base_from_binary_digits(
                     &exponent.digits,
                     DIGIT_BITNESS,
@@ -161,13 +174,26 @@ where
             let mut result = Self::one();
             for &digit in exponent_window_digits.iter().rev() {
                 for _ in 0..WINDOW_BITNESS {
-                    result = &result * &result;
+                    result = try_multiply(&result, &result)?;
                 }
                 if !digit.is_zero() {
-                    result *= &cache[digit as usize];
+                    result = try_multiply(&result, &cache[digit as usize])?;
                 }
             }
-            result
+            Ok(result)
         }
     }
 }
+
+fn try_multiply<Digit: MultiplyDigits, const DIGIT_BITNESS: usize>(
+    first: &BigInt<Digit, DIGIT_BITNESS>,
+    second: &BigInt<Digit, DIGIT_BITNESS>,
+) -> Result<BigInt<Digit, DIGIT_BITNESS>, CollectionAllocErr> {
+    Ok(BigInt {
+        sign: first.sign * second.sign,
+        digits: Digit::try_multiply_digits::<DIGIT_BITNESS>(
+            &first.digits,
+            &second.digits,
+        )?,
+    })
+}
@@ -7,7 +7,7 @@ use traiter::numbers::{
 
 use super::constants::{WINDOW_BASE, WINDOW_BITNESS, WINDOW_CUTOFF};
 use super::digits::LesserBinaryBaseFromBinaryDigits;
-use super::types::{BigInt, CheckedPowRemEuclidError, WindowDigit};
+use super::types::{BigInt, CheckedPowRemEuclidError, Digits, WindowDigit};
 
 impl<Digit, const DIGIT_BITNESS: usize> CheckedPowRemEuclid<Self, Self>
     for BigInt<Digit, DIGIT_BITNESS>
@@ -415,7 +415,7 @@ where
                             .unwrap_unchecked()
                     };
                 }
-                let exponent_window_digits: Vec<WindowDigit> =
+                let exponent_window_digits: Digits<WindowDigit> =
                     WindowDigit::lesser_binary_base_from_binary_digits(
                         &exponent.digits,
                         DIGIT_BITNESS,
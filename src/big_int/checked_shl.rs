@@ -1,9 +1,14 @@
 use std::convert::TryFrom;
 use std::mem::size_of;
 
-use traiter::numbers::{CheckedShl, DivRem, Sign, Signed, Zeroable};
+use traiter::numbers::{
+    BitLength, CheckedShl, DivRem, Sign, Signed, Zeroable,
+};
 
-use super::digits::{PrimitiveShiftDigitsLeft, ShiftDigitsLeft};
+use super::digits::{
+    digits_bit_length, MaybeReduceDigits, PrimitiveShiftDigitsLeft,
+    ShiftDigitsLeft,
+};
 use super::types::{BigInt, ShlError};
 
 impl<Digit: ShiftDigitsLeft, const DIGIT_BITNESS: usize> CheckedShl
@@ -96,6 +101,48 @@ where
     }
 }
 
+impl<
+        Digit: BitLength<Output = usize>
+            + Copy
+            + MaybeReduceDigits<usize>
+            + ShiftDigitsLeft,
+        const DIGIT_BITNESS: usize,
+    > BigInt<Digit, DIGIT_BITNESS>
+where
+    for<'a> &'a Self: Signed,
+{
+    pub fn checked_shl_within(
+        &self,
+        shift: &Self,
+        max_bit_length: usize,
+    ) -> Result<Self, ShlError> {
+        if shift.is_negative() {
+            return Err(ShlError::NegativeShift);
+        } else if self.sign.is_zero() {
+            return Ok(self.clone());
+        }
+        let shift_value =
+            Digit::maybe_reduce_digits::<DIGIT_BITNESS>(&shift.digits)
+                .ok_or(ShlError::LimitExceeded)?;
+        if digits_bit_length::<Digit, DIGIT_BITNESS>(&self.digits)
+            .saturating_add(shift_value)
+            > max_bit_length
+        {
+            Err(ShlError::LimitExceeded)
+        } else if shift.sign.is_zero() {
+            Ok(self.clone())
+        } else {
+            Ok(Self {
+                sign: self.sign,
+                digits: Digit::shift_digits_left::<DIGIT_BITNESS>(
+                    &self.digits,
+                    &shift.digits,
+                )?,
+            })
+        }
+    }
+}
+
 macro_rules! checked_shl_signed_integer_impl {
     ($($integer:ty)*) => ($(
         impl<
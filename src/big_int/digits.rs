@@ -1,31 +1,35 @@
+use std::alloc::handle_alloc_error;
 use std::cmp::Ordering;
 use std::convert::TryFrom;
 use std::mem::{size_of, transmute};
 use std::ops::{
     Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor,
-    BitXorAssign, Div, DivAssign, Mul, MulAssign, Not, Shl, ShlAssign, Shr,
-    ShrAssign, Sub, SubAssign,
+    BitXorAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Not, Shl,
+    ShlAssign, Shr, ShrAssign, Sub, SubAssign,
 };
 
+use smallvec::{smallvec, CollectionAllocErr};
 use traiter::numbers::{
     BitLength, CheckedShl, FloatInfo, Floor, FractExp, Gcd, LoadExp, One,
     RemEuclid, Signed, Unitary, Zero, Zeroable,
 };
 
-use crate::contracts::{is_signed, is_unsigned};
+use crate::contracts::is_signed;
 use crate::traits::{
     DoublePrecision, DoublePrecisionOf, HasSignBit, MantissaDigits, MaxExp,
-    MinExp, Oppose, OppositionOf, WrappingSub,
+    MinExp, Oppose, OppositionOf, ToFloat, UnsignedAbs, UnsignedAbsOf,
+    WrappingSub,
 };
 
-use super::types::{CheckedDivAsFloatError, ShlError, Sign};
+use super::constants::MAX_ALPHABET_SIZE;
+use super::types::{CheckedDivAsFloatError, Digits, ShlError, Sign};
 
 pub trait BaseFromBinaryDigits<Source>: Sized {
     fn base_from_binary_digits(
         source: &[Source],
         source_bitness: usize,
         target_base: usize,
-    ) -> Vec<Self>;
+    ) -> Digits<Self>;
 }
 
 impl<
@@ -38,7 +42,7 @@ impl<
         source: &[Source],
         source_bitness: usize,
         target_base: usize,
-    ) -> Vec<Self> {
+    ) -> Digits<Self> {
         if target_base & (target_base - 1) == 0 {
             Self::binary_base_from_binary_digits(
                 source,
@@ -55,11 +59,21 @@ impl<
     }
 }
 
-pub(super) trait BinaryBaseFromDigits<Source>: Sized {
+pub trait BinaryBaseFromDigits<Source>: Sized {
     fn binary_base_from_digits<const TARGET_BITNESS: usize>(
         source: &[Source],
         source_base: usize,
-    ) -> Vec<Self>;
+    ) -> Digits<Self> {
+        expect_allocated(Self::try_binary_base_from_digits::<TARGET_BITNESS>(
+            source,
+            source_base,
+        ))
+    }
+
+    fn try_binary_base_from_digits<const TARGET_BITNESS: usize>(
+        source: &[Source],
+        source_base: usize,
+    ) -> Result<Digits<Self>, CollectionAllocErr>;
 }
 
 impl<
@@ -69,23 +83,22 @@ impl<
             + LesserBinaryBaseFromNonBinaryDigits<Source>,
     > BinaryBaseFromDigits<Source> for Target
 {
-    fn binary_base_from_digits<const TARGET_BITNESS: usize>(
+    fn try_binary_base_from_digits<const TARGET_BITNESS: usize>(
         source: &[Source],
         source_base: usize,
-    ) -> Vec<Self> {
+    ) -> Result<Digits<Self>, CollectionAllocErr> {
         if source_base & (source_base - 1) == 0 {
-            Self::binary_base_from_binary_digits(
+            Self::try_binary_base_from_binary_digits(
                 source,
                 floor_log2::<usize>(source_base),
                 TARGET_BITNESS,
             )
         } else if source_base < (1 << TARGET_BITNESS) {
-            Self::greater_binary_base_from_non_binary_digits::<TARGET_BITNESS>(
-                source,
-                source_base,
-            )
+            Self::try_greater_binary_base_from_non_binary_digits::<
+                TARGET_BITNESS,
+            >(source, source_base)
         } else {
-            Self::lesser_binary_base_from_non_binary_digits::<TARGET_BITNESS>(
+            Self::try_lesser_binary_base_from_non_binary_digits::<TARGET_BITNESS>(
                 source,
                 source_base,
             )
@@ -93,12 +106,24 @@ impl<
     }
 }
 
-pub(super) trait BinaryBaseFromBinaryDigits<Source>: Sized {
+pub trait BinaryBaseFromBinaryDigits<Source>: Sized {
     fn binary_base_from_binary_digits(
         source: &[Source],
         source_bitness: usize,
         target_bitness: usize,
-    ) -> Vec<Self>;
+    ) -> Digits<Self> {
+        expect_allocated(Self::try_binary_base_from_binary_digits(
+            source,
+            source_bitness,
+            target_bitness,
+        ))
+    }
+
+    fn try_binary_base_from_binary_digits(
+        source: &[Source],
+        source_bitness: usize,
+        target_bitness: usize,
+    ) -> Result<Digits<Self>, CollectionAllocErr>;
 }
 
 impl<
@@ -108,24 +133,27 @@ impl<
             + TryFrom<Source>,
     > BinaryBaseFromBinaryDigits<Source> for Target
 {
-    fn binary_base_from_binary_digits(
+    fn try_binary_base_from_binary_digits(
         source: &[Source],
         source_bitness: usize,
         target_bitness: usize,
-    ) -> Vec<Self> {
+    ) -> Result<Digits<Self>, CollectionAllocErr> {
         match target_bitness.cmp(&source_bitness) {
-            Ordering::Equal => source
-                .iter()
-                .map(|&digit| unsafe {
+            Ordering::Equal => {
+                let mut result = try_digits_with_capacity(source.len())?;
+                result.extend(source.iter().map(|&digit| unsafe {
                     Self::try_from(digit).unwrap_unchecked()
-                })
-                .collect(),
-            Ordering::Greater => Self::greater_binary_base_from_binary_digits(
-                source,
-                source_bitness,
-                target_bitness,
-            ),
-            Ordering::Less => Self::lesser_binary_base_from_binary_digits(
+                }));
+                Ok(result)
+            }
+            Ordering::Greater => {
+                Self::try_greater_binary_base_from_binary_digits(
+                    source,
+                    source_bitness,
+                    target_bitness,
+                )
+            }
+            Ordering::Less => Self::try_lesser_binary_base_from_binary_digits(
                 source,
                 source_bitness,
                 target_bitness,
@@ -139,7 +167,7 @@ pub(super) trait NonBinaryBaseFromBinaryDigits<Source>: Sized {
         source: &[Source],
         source_bitness: usize,
         target_base: usize,
-    ) -> Vec<Self>;
+    ) -> Digits<Self>;
 }
 
 impl<
@@ -164,11 +192,12 @@ where
         source: &[Source],
         source_bitness: usize,
         target_base: usize,
-    ) -> Vec<Self> {
+    ) -> Digits<Self> {
         let result_max_digits_count: usize = 1
             + ((((source.len() * source_bitness) as f64)
                 / (target_base as f64).log2()) as usize);
-        let mut result = Vec::<Self>::with_capacity(result_max_digits_count);
+        let mut result =
+            Digits::<Self>::with_capacity(result_max_digits_count);
         let target_base = unsafe {
             DoublePrecisionOf::<Self>::try_from(target_base).unwrap_unchecked()
         };
@@ -203,11 +232,11 @@ where
 }
 
 trait GreaterBinaryBaseFromBinaryDigits<Source>: Sized {
-    fn greater_binary_base_from_binary_digits(
+    fn try_greater_binary_base_from_binary_digits(
         source: &[Source],
         source_bitness: usize,
         target_bitness: usize,
-    ) -> Vec<Self>;
+    ) -> Result<Digits<Self>, CollectionAllocErr>;
 }
 
 impl<
@@ -219,7 +248,6 @@ where
         + BitOr<Output = DoublePrecisionOf<Target>>
         + BitOrAssign
         + Copy
-        + From<Source>
         + One
         + Shl<usize, Output = DoublePrecisionOf<Target>>
         + ShlAssign<usize>
@@ -227,26 +255,28 @@ where
         + ShrAssign<usize>
         + Sub<Output = DoublePrecisionOf<Target>>
         + SubAssign
+        + TryFrom<Source>
         + Zero
         + Zeroable,
 {
-    fn greater_binary_base_from_binary_digits(
+    fn try_greater_binary_base_from_binary_digits(
         source: &[Source],
         source_bitness: usize,
         target_bitness: usize,
-    ) -> Vec<Self> {
+    ) -> Result<Digits<Self>, CollectionAllocErr> {
         debug_assert!(target_bitness > source_bitness && source_bitness > 0);
         let target_digit_mask =
             DoublePrecisionOf::<Self>::digit_mask(target_bitness);
         let result_capacity: usize = (source.len() * target_bitness
             + (target_bitness - 1))
             / target_bitness;
-        let mut result = Vec::<Self>::with_capacity(result_capacity);
+        let mut result = try_digits_with_capacity(result_capacity)?;
         let mut accumulator = DoublePrecisionOf::<Self>::zero();
         let mut accumulator_bits_count: usize = 0;
         for digit in source {
-            accumulator |= DoublePrecisionOf::<Self>::from(*digit)
-                << accumulator_bits_count;
+            accumulator |= unsafe {
+                DoublePrecisionOf::<Self>::try_from(*digit).unwrap_unchecked()
+            } << accumulator_bits_count;
             accumulator_bits_count += source_bitness;
             if accumulator_bits_count >= target_bitness {
                 unsafe {
@@ -264,7 +294,7 @@ where
                 result.push(Self::try_from(accumulator).unwrap_unchecked());
             }
         }
-        result
+        Ok(result)
     }
 }
 
@@ -275,7 +305,19 @@ pub(super) trait LesserBinaryBaseFromBinaryDigits<Source>:
         source: &[Source],
         source_bitness: usize,
         target_bitness: usize,
-    ) -> Vec<Self>;
+    ) -> Digits<Self> {
+        expect_allocated(Self::try_lesser_binary_base_from_binary_digits(
+            source,
+            source_bitness,
+            target_bitness,
+        ))
+    }
+
+    fn try_lesser_binary_base_from_binary_digits(
+        source: &[Source],
+        source_bitness: usize,
+        target_bitness: usize,
+    ) -> Result<Digits<Self>, CollectionAllocErr>;
 }
 
 impl<
@@ -292,11 +334,11 @@ where
         + ShrAssign<usize>
         + Zeroable,
 {
-    fn lesser_binary_base_from_binary_digits(
+    fn try_lesser_binary_base_from_binary_digits(
         source: &[Source],
         source_bitness: usize,
         target_bitness: usize,
-    ) -> Vec<Self> {
+    ) -> Result<Digits<Self>, CollectionAllocErr> {
         debug_assert!(source_bitness > target_bitness && target_bitness > 0);
         let target_digit_mask =
             DoublePrecisionOf::<Source>::digit_mask(target_bitness);
@@ -304,7 +346,7 @@ where
             + source[source.len() - 1].bit_length();
         let digits_count: usize =
             (digits_bits_count + (target_bitness - 1)) / target_bitness;
-        let mut result = Vec::<Self>::with_capacity(digits_count);
+        let mut result = try_digits_with_capacity(digits_count)?;
         let mut accumulator = DoublePrecisionOf::<Source>::from(source[0]);
         let mut accumulator_bits_count = source_bitness;
         for &digit in source.iter().skip(1) {
@@ -333,17 +375,17 @@ where
                 break;
             }
         }
-        result
+        Ok(result)
     }
 }
 
 pub(super) trait BitwiseAndComponents: Sized {
     fn bitwise_and_components<const DIGIT_BITNESS: usize>(
         first_sign: Sign,
-        first: Vec<Self>,
+        first: Digits<Self>,
         second_sign: Sign,
-        second: Vec<Self>,
-    ) -> (Sign, Vec<Self>);
+        second: Digits<Self>,
+    ) -> (Sign, Digits<Self>);
 }
 
 impl<Digit: BitAndAssign + ComplementInPlace + Copy + DigitMask>
@@ -353,10 +395,10 @@ where
 {
     fn bitwise_and_components<const DIGIT_BITNESS: usize>(
         first_sign: Sign,
-        first: Vec<Self>,
+        first: Digits<Self>,
         second_sign: Sign,
-        second: Vec<Self>,
-    ) -> (Sign, Vec<Self>) {
+        second: Digits<Self>,
+    ) -> (Sign, Digits<Self>) {
         let (longest_sign, mut longest, shortest_sign, mut shortest) =
             if first.len() < second.len() {
                 (second_sign, second, first_sign, first)
@@ -390,10 +432,10 @@ where
 pub(super) trait BitwiseOrComponents: Sized {
     fn bitwise_or_components<const DIGIT_BITNESS: usize>(
         first_sign: Sign,
-        first: Vec<Self>,
+        first: Digits<Self>,
         second_sign: Sign,
-        second: Vec<Self>,
-    ) -> (Sign, Vec<Self>);
+        second: Digits<Self>,
+    ) -> (Sign, Digits<Self>);
 }
 
 impl<Digit: BitOrAssign + ComplementInPlace + Copy + DigitMask>
@@ -403,10 +445,10 @@ where
 {
     fn bitwise_or_components<const DIGIT_BITNESS: usize>(
         first_sign: Sign,
-        first: Vec<Self>,
+        first: Digits<Self>,
         second_sign: Sign,
-        second: Vec<Self>,
-    ) -> (Sign, Vec<Self>) {
+        second: Digits<Self>,
+    ) -> (Sign, Digits<Self>) {
         let (longest_sign, mut longest, shortest_sign, mut shortest) =
             if first.len() < second.len() {
                 (second_sign, second, first_sign, first)
@@ -439,10 +481,10 @@ where
 pub(super) trait BitwiseXorComponents: Sized {
     fn bitwise_xor_components<const DIGIT_BITNESS: usize>(
         first_sign: Sign,
-        first: Vec<Self>,
+        first: Digits<Self>,
         second_sign: Sign,
-        second: Vec<Self>,
-    ) -> (Sign, Vec<Self>);
+        second: Digits<Self>,
+    ) -> (Sign, Digits<Self>);
 }
 
 impl<Digit: BitXorAssign + ComplementInPlace + Copy + DigitMask>
@@ -452,10 +494,10 @@ where
 {
     fn bitwise_xor_components<const DIGIT_BITNESS: usize>(
         first_sign: Sign,
-        first: Vec<Self>,
+        first: Digits<Self>,
         second_sign: Sign,
-        second: Vec<Self>,
-    ) -> (Sign, Vec<Self>) {
+        second: Digits<Self>,
+    ) -> (Sign, Digits<Self>) {
         let (longest_sign, mut longest, shortest_sign, mut shortest) =
             if first.len() < second.len() {
                 (second_sign, second, first_sign, first)
@@ -594,7 +636,8 @@ macro_rules! checked_div_digits_as_float_impl {
                     return Err(CheckedDivAsFloatError::TooLarge);
                 } else if bit_lengths_difference
                     < (<$float>::MIN_EXP as isize)
-                        - ((NON_EXPONENT_BITS_COUNT as isize) - 1)
+                        - (NON_EXPONENT_BITS_COUNT as isize)
+                        - 1
                 {
                     return Ok(<$float>::zero());
                 }
@@ -613,7 +656,7 @@ macro_rules! checked_div_digits_as_float_impl {
                     let quotient_digits_count =
                         dividend_digits_count + shift_digits + 1;
                     let mut quotient_data =
-                        vec![Self::zero(); quotient_digits_count];
+                        smallvec![Self::zero(); quotient_digits_count];
                     let remainder =
                         Self::shift_digits_left_in_place::<DIGIT_BITNESS>(
                             dividend_digits,
@@ -628,7 +671,7 @@ macro_rules! checked_div_digits_as_float_impl {
                     let quotient_digits_count =
                         dividend_digits_count - shift_digits;
                     let mut quotient_data =
-                        vec![Self::zero(); quotient_digits_count];
+                        smallvec![Self::zero(); quotient_digits_count];
                     let remainder =
                         Self::shift_digits_right_in_place::<DIGIT_BITNESS>(
                             &dividend_digits[shift_digits..],
@@ -714,7 +757,7 @@ pub(super) trait CheckedDivComponents: Sized {
         dividend: &[Self],
         divisor_sign: Sign,
         divisor: &[Self],
-    ) -> Option<(Sign, Vec<Self>)>;
+    ) -> Option<(Sign, Digits<Self>)>;
 }
 
 impl<
@@ -732,22 +775,23 @@ where
         dividend: &[Self],
         divisor_sign: Sign,
         divisor: &[Self],
-    ) -> Option<(Sign, Vec<Self>)> {
+    ) -> Option<(Sign, Digits<Self>)> {
         if divisor_sign.is_zero() {
             None
         } else if dividend_sign.is_zero()
             || digits_lesser_than(dividend, divisor)
         {
-            Some((Sign::zero(), vec![Self::zero()]))
+            Some((Sign::zero(), smallvec![Self::zero()]))
         } else if divisor.len() == 1 {
             let (digits, _) = Self::div_rem_digits_by_digit::<DIGIT_BITNESS>(
                 dividend, divisor[0],
             );
             Some((dividend_sign * divisor_sign, digits))
         } else {
-            let (digits, _) = Self::div_rem_by_two_or_more_digits::<
+            let (digits, mut remainder) = Self::div_rem_by_two_or_more_digits::<
                 DIGIT_BITNESS,
             >(dividend, divisor);
+            wipe_digits(&mut remainder);
             Some((
                 dividend_sign * divisor_sign * to_digits_sign(&digits),
                 digits,
@@ -762,7 +806,7 @@ pub(super) trait CheckedDivEuclidComponents: Sized {
         dividend: &[Self],
         divisor_sign: Sign,
         divisor: &[Self],
-    ) -> Option<(Sign, Vec<Self>)>;
+    ) -> Option<(Sign, Digits<Self>)>;
 }
 
 impl<
@@ -782,45 +826,48 @@ where
         dividend: &[Self],
         divisor_sign: Sign,
         divisor: &[Self],
-    ) -> Option<(Sign, Vec<Self>)> {
+    ) -> Option<(Sign, Digits<Self>)> {
         if divisor_sign.is_zero() {
             None
         } else if dividend_sign.is_zero() {
-            Some((Sign::zero(), vec![Self::zero()]))
+            Some((Sign::zero(), smallvec![Self::zero()]))
         } else if digits_lesser_than(dividend, divisor) {
             Some(
                 if (dividend_sign.is_negative() && divisor_sign.is_positive())
                     || (dividend_sign.is_positive()
                         && divisor_sign.is_negative())
                 {
-                    (-Sign::one(), vec![Self::one()])
+                    (-Sign::one(), smallvec![Self::one()])
                 } else {
-                    (Sign::zero(), vec![Self::zero()])
+                    (Sign::zero(), smallvec![Self::zero()])
                 },
             )
         } else {
-            let (sign, mut digits, remainder_is_non_zero) = if divisor.len()
-                == 1
-            {
-                let (digits, remainder_digit) =
-                    Self::div_rem_digits_by_digit::<DIGIT_BITNESS>(
-                        dividend, divisor[0],
-                    );
-                (
-                    dividend_sign * divisor_sign,
-                    digits,
-                    !remainder_digit.is_zero(),
-                )
-            } else {
-                let (digits, remainder) = Self::div_rem_by_two_or_more_digits::<
-                    DIGIT_BITNESS,
-                >(dividend, divisor);
-                (
-                    dividend_sign * divisor_sign * to_digits_sign(&digits),
-                    digits,
-                    !to_digits_sign(&remainder).is_zero(),
-                )
-            };
+            let (sign, mut digits, remainder_is_non_zero) =
+                if divisor.len() == 1 {
+                    let (digits, remainder_digit) =
+                        Self::div_rem_digits_by_digit::<DIGIT_BITNESS>(
+                            dividend, divisor[0],
+                        );
+                    (
+                        dividend_sign * divisor_sign,
+                        digits,
+                        !remainder_digit.is_zero(),
+                    )
+                } else {
+                    let (digits, mut remainder) =
+                        Self::div_rem_by_two_or_more_digits::<DIGIT_BITNESS>(
+                            dividend, divisor,
+                        );
+                    let remainder_is_non_zero =
+                        !to_digits_sign(&remainder).is_zero();
+                    wipe_digits(&mut remainder);
+                    (
+                        dividend_sign * divisor_sign * to_digits_sign(&digits),
+                        digits,
+                        remainder_is_non_zero,
+                    )
+                };
             if remainder_is_non_zero
                 && ((dividend_sign.is_negative()
                     && divisor_sign.is_positive())
@@ -841,7 +888,7 @@ pub(super) trait CheckedDivRemComponents: Sized {
         dividend: &[Self],
         divisor_sign: Sign,
         divisor: &[Self],
-    ) -> Option<(Sign, Vec<Self>, Sign, Vec<Self>)>;
+    ) -> Option<(Sign, Digits<Self>, Sign, Digits<Self>)>;
 }
 
 impl<
@@ -859,7 +906,7 @@ where
         dividend: &[Self],
         divisor_sign: Sign,
         divisor: &[Self],
-    ) -> Option<(Sign, Vec<Self>, Sign, Vec<Self>)> {
+    ) -> Option<(Sign, Digits<Self>, Sign, Digits<Self>)> {
         if divisor_sign.is_zero() {
             None
         } else if dividend_sign.is_zero()
@@ -869,9 +916,9 @@ where
         {
             Some((
                 Sign::zero(),
-                vec![Self::zero(); 1],
+                smallvec![Self::zero(); 1],
                 dividend_sign,
-                dividend.to_vec(),
+                Digits::from(dividend),
             ))
         } else if divisor.len() == 1 {
             let (quotient_digits, remainder_digit) =
@@ -882,7 +929,7 @@ where
                 dividend_sign * divisor_sign,
                 quotient_digits,
                 dividend_sign * Sign::from(!remainder_digit.is_zero()),
-                vec![remainder_digit],
+                smallvec![remainder_digit],
             ))
         } else {
             let (quotient_digits, remainder_digits) =
@@ -907,7 +954,7 @@ pub(super) trait CheckedDivRemEuclidComponents: Sized {
         dividend: &[Self],
         divisor_sign: Sign,
         divisor: &[Self],
-    ) -> Option<(Sign, Vec<Self>, Sign, Vec<Self>)>;
+    ) -> Option<(Sign, Digits<Self>, Sign, Digits<Self>)>;
 }
 
 impl<
@@ -919,7 +966,7 @@ impl<
         dividend: &[Digit],
         divisor_sign: Sign,
         divisor: &[Digit],
-    ) -> Option<(Sign, Vec<Digit>, Sign, Vec<Digit>)> {
+    ) -> Option<(Sign, Digits<Digit>, Sign, Digits<Digit>)> {
         let (
             mut quotient_sign,
             mut quotient,
@@ -958,7 +1005,7 @@ pub(super) trait CheckedRemComponents: Sized {
         dividend: &[Self],
         divisor_sign: Sign,
         divisor: &[Self],
-    ) -> Option<(Sign, Vec<Self>)>;
+    ) -> Option<(Sign, Digits<Self>)>;
 }
 
 impl<
@@ -975,25 +1022,28 @@ where
         dividend: &[Self],
         divisor_sign: Sign,
         divisor: &[Self],
-    ) -> Option<(Sign, Vec<Self>)> {
+    ) -> Option<(Sign, Digits<Self>)> {
         if divisor_sign.is_zero() {
             None
         } else if dividend_sign.is_zero()
             || digits_lesser_than(dividend, divisor)
         {
-            Some((dividend_sign, dividend.to_vec()))
+            Some((dividend_sign, Digits::from(dividend)))
         } else if divisor.len() == 1 {
-            let (_, remainder) = Self::div_rem_digits_by_digit::<DIGIT_BITNESS>(
-                dividend, divisor[0],
-            );
+            let (mut quotient, remainder) = Self::div_rem_digits_by_digit::<
+                DIGIT_BITNESS,
+            >(dividend, divisor[0]);
+            wipe_digits(&mut quotient);
             Some((
                 dividend_sign * Sign::from(!remainder.is_zero()),
-                vec![remainder],
+                smallvec![remainder],
             ))
         } else {
-            let (_, remainder) = Self::div_rem_by_two_or_more_digits::<
-                DIGIT_BITNESS,
-            >(dividend, divisor);
+            let (mut quotient, remainder) =
+                Self::div_rem_by_two_or_more_digits::<DIGIT_BITNESS>(
+                    dividend, divisor,
+                );
+            wipe_digits(&mut quotient);
             Some((dividend_sign * to_digits_sign(&remainder), remainder))
         }
     }
@@ -1005,7 +1055,7 @@ pub(super) trait CheckedRemEuclidComponents: Sized {
         dividend: &[Self],
         divisor_sign: Sign,
         divisor: &[Self],
-    ) -> Option<(Sign, Vec<Self>)>;
+    ) -> Option<(Sign, Digits<Self>)>;
 }
 
 impl<
@@ -1023,11 +1073,11 @@ where
         dividend: &[Self],
         divisor_sign: Sign,
         divisor: &[Self],
-    ) -> Option<(Sign, Vec<Self>)> {
+    ) -> Option<(Sign, Digits<Self>)> {
         if divisor_sign.is_zero() {
             None
         } else if dividend_sign.is_zero() {
-            Some((dividend_sign, dividend.to_vec()))
+            Some((dividend_sign, Digits::from(dividend)))
         } else if digits_lesser_than(dividend, divisor) {
             Some(
                 if (dividend_sign.is_negative() && divisor_sign.is_positive())
@@ -1040,19 +1090,26 @@ where
                         dividend_sign,
                     )
                 } else {
-                    (dividend_sign, dividend.to_vec())
+                    (dividend_sign, Digits::from(dividend))
                 },
             )
         } else {
             let (mut sign, mut digits) = if divisor.len() == 1 {
-                let (_, digit) = Digit::div_rem_digits_by_digit::<DIGIT_BITNESS>(
-                    dividend, divisor[0],
-                );
-                (dividend_sign * Sign::from(!digit.is_zero()), vec![digit])
+                let (mut quotient, digit) =
+                    Digit::div_rem_digits_by_digit::<DIGIT_BITNESS>(
+                        dividend, divisor[0],
+                    );
+                wipe_digits(&mut quotient);
+                (
+                    dividend_sign * Sign::from(!digit.is_zero()),
+                    smallvec![digit],
+                )
             } else {
-                let (_, digits) = Digit::div_rem_by_two_or_more_digits::<
-                    DIGIT_BITNESS,
-                >(dividend, divisor);
+                let (mut quotient, digits) =
+                    Digit::div_rem_by_two_or_more_digits::<DIGIT_BITNESS>(
+                        dividend, divisor,
+                    );
+                wipe_digits(&mut quotient);
                 (dividend_sign * to_digits_sign(&digits), digits)
             };
             if (divisor_sign.is_negative() && sign.is_positive())
@@ -1120,7 +1177,7 @@ pub(super) trait DivRemDigitsByDigit: Sized {
     fn div_rem_digits_by_digit<const DIGIT_BITNESS: usize>(
         dividend: &[Self],
         divisor: Self,
-    ) -> (Vec<Self>, Self);
+    ) -> (Digits<Self>, Self);
 }
 
 impl<
@@ -1139,8 +1196,8 @@ where
     fn div_rem_digits_by_digit<const DIGIT_BITNESS: usize>(
         dividend: &[Self],
         divisor: Self,
-    ) -> (Vec<Self>, Self) {
-        let mut quotient = vec![Self::zero(); dividend.len()];
+    ) -> (Digits<Self>, Self) {
+        let mut quotient = smallvec![Self::zero(); dividend.len()];
         let mut remainder = DoublePrecisionOf::<Self>::zero();
         let digits_count = dividend.len();
         let divisor = DoublePrecisionOf::<Self>::from(divisor);
@@ -1167,7 +1224,7 @@ pub(super) trait DivRemDigitsByTwoOrMoreDigits: Sized {
     fn div_rem_by_two_or_more_digits<const DIGIT_BITNESS: usize>(
         dividend: &[Self],
         divisor: &[Self],
-    ) -> (Vec<Self>, Vec<Self>);
+    ) -> (Digits<Self>, Digits<Self>);
 }
 
 impl<
@@ -1219,12 +1276,16 @@ where
     fn div_rem_by_two_or_more_digits<const DIGIT_BITNESS: usize>(
         dividend: &[Digit],
         divisor: &[Digit],
-    ) -> (Vec<Digit>, Vec<Digit>) {
+    ) -> (Digits<Digit>, Digits<Digit>) {
         let dividend_digits_count = dividend.len();
         let divisor_digits_count = divisor.len();
-        let mut dividend_normalized =
-            vec![Digit::zero(); dividend_digits_count];
-        let mut divisor_normalized = vec![Digit::zero(); divisor_digits_count];
+        // reserving a digit for the carry to avoid reallocation
+        let mut dividend_normalized = ScratchDigits::from(
+            Digits::<Digit>::with_capacity(dividend_digits_count + 1),
+        );
+        dividend_normalized.resize(dividend_digits_count, Digit::zero());
+        let mut divisor_normalized: Digits<Digit> =
+            smallvec![Digit::zero(); divisor_digits_count];
         let shift = DIGIT_BITNESS - divisor[divisor.len() - 1].bit_length();
         Digit::shift_digits_left_in_place::<DIGIT_BITNESS>(
             divisor,
@@ -1246,7 +1307,7 @@ where
         }
         let quotient_size =
             dividend_normalized.len() - divisor_normalized.len();
-        let mut quotient = vec![Digit::zero(); quotient_size];
+        let mut quotient = smallvec![Digit::zero(); quotient_size];
         let penult_divisor_digit_normalized =
             divisor_normalized[divisor_digits_count - 2];
         let mut quotient_index = quotient_size;
@@ -1340,7 +1401,7 @@ where
             quotient[quotient_index] = quotient_digit;
         }
         if quotient_size.is_zero() {
-            quotient = vec![Digit::zero()];
+            quotient = smallvec![Digit::zero()];
         }
         trim_leading_zeros(&mut quotient);
         let mut remainder = divisor_normalized;
@@ -1361,16 +1422,17 @@ pub(super) trait FractExpDigits<Fraction>: Sized {
 }
 
 impl<
-        Digit: BitAnd<Output = Digit>
+        Digit: Add<Output = Digit>
+            + BitAnd<Output = Digit>
             + BitOrAssign<Digit>
             + BitLength<Output = usize>
             + Copy
             + From<u8>
-            + TryFrom<OppositionOf<Digit>>
             + One
-            + Oppose
             + ShiftDigitsLeftInPlace
             + ShiftDigitsRightInPlace
+            + Sub<Output = Digit>
+            + ToFloat<Fraction>
             + Zero,
         Fraction: Add<Output = Fraction>
             + Div<Output = Fraction>
@@ -1378,7 +1440,6 @@ impl<
             + Copy
             + Floor<Output = Fraction>
             + FractExp<Output = (Fraction, i32)>
-            + From<Digit>
             + From<f32>
             + LoadExp<i32, Output = Fraction>
             + MantissaDigits
@@ -1393,8 +1454,6 @@ impl<
 where
     for<'a> &'a Digit: Zeroable,
     for<'a> &'a Fraction: Unitary + Zeroable,
-    OppositionOf<Digit>:
-        Add<Output = OppositionOf<Digit>> + From<i8> + TryFrom<Digit>,
     usize: TryFrom<Digit>,
 {
     fn fract_exp_digits<const DIGIT_BITNESS: usize>(
@@ -1457,27 +1516,23 @@ where
         };
         {
             const HALF_EVEN_CORRECTION: [i8; 8] = [0, -1, -2, 1, 0, -1, 2, 1];
-            result_digits[0] = unsafe {
-                Self::try_from(
-                    OppositionOf::<Self>::try_from(result_digits[0])
-                        .unwrap_unchecked()
-                        + OppositionOf::<Self>::from(
-                            HALF_EVEN_CORRECTION[usize::try_from(
-                                result_digits[0] & Self::from(7u8),
-                            )
-                            .unwrap_unchecked()],
-                        ),
-                )
-                .unwrap_unchecked()
+            let correction = HALF_EVEN_CORRECTION[unsafe {
+                usize::try_from(result_digits[0] & Self::from(7u8))
+                    .unwrap_unchecked()
+            }];
+            result_digits[0] = if correction < 0 {
+                result_digits[0] - Self::from(correction.unsigned_abs())
+            } else {
+                result_digits[0] + Self::from(correction.unsigned_abs())
             };
         }
         result_digits_count -= 1;
-        let mut fraction = Fraction::from(result_digits[result_digits_count]);
+        let mut fraction = result_digits[result_digits_count].to_float();
         while result_digits_count > 0 {
             result_digits_count -= 1;
             fraction = fraction
                 * Fraction::from((1usize << DIGIT_BITNESS) as f32)
-                + Fraction::from(result_digits[result_digits_count]);
+                + result_digits[result_digits_count].to_float();
         }
         fraction /=
             Fraction::from((1u64 << (Fraction::MANTISSA_DIGITS + 2)) as f32);
@@ -1501,14 +1556,14 @@ pub(super) trait InvertComponents: Sized {
     fn invert_components<const DIGIT_BITNESS: usize>(
         sign: Sign,
         digits: &[Self],
-    ) -> (Sign, Vec<Self>);
+    ) -> (Sign, Digits<Self>);
 }
 
 impl<Digit: One + SumComponents> InvertComponents for Digit {
     fn invert_components<const DIGIT_BITNESS: usize>(
         sign: Sign,
         digits: &[Digit],
-    ) -> (Sign, Vec<Digit>) {
+    ) -> (Sign, Digits<Digit>) {
         let (sign, digits) = Digit::sum_components::<DIGIT_BITNESS>(
             sign,
             digits,
@@ -1519,29 +1574,76 @@ impl<Digit: One + SumComponents> InvertComponents for Digit {
     }
 }
 
-pub(super) trait MultiplyDigits: Sized {
+const KARATSUBA_CUTOFF: usize = 70;
+const PARALLEL_KARATSUBA_CUTOFF: usize = KARATSUBA_CUTOFF * 16;
+
+#[cfg(feature = "rayon")]
+fn maybe_join<First, Second, FirstResult, SecondResult>(
+    is_parallel: bool,
+    first: First,
+    second: Second,
+) -> (FirstResult, SecondResult)
+where
+    First: FnOnce() -> FirstResult + Send,
+    Second: FnOnce() -> SecondResult + Send,
+    FirstResult: Send,
+    SecondResult: Send,
+{
+    if is_parallel {
+        rayon::join(first, second)
+    } else {
+        (first(), second())
+    }
+}
+
+#[cfg(not(feature = "rayon"))]
+fn maybe_join<First, Second, FirstResult, SecondResult>(
+    _is_parallel: bool,
+    first: First,
+    second: Second,
+) -> (FirstResult, SecondResult)
+where
+    First: FnOnce() -> FirstResult + Send,
+    Second: FnOnce() -> SecondResult + Send,
+    FirstResult: Send,
+    SecondResult: Send,
+{
+    (first(), second())
+}
+
+pub trait MultiplyDigits: Sized {
     fn multiply_digits<const DIGIT_BITNESS: usize>(
         first: &[Self],
         second: &[Self],
-    ) -> Vec<Self>;
+    ) -> Digits<Self> {
+        expect_allocated(Self::try_multiply_digits::<DIGIT_BITNESS>(
+            first, second,
+        ))
+    }
+
+    fn try_multiply_digits<const DIGIT_BITNESS: usize>(
+        first: &[Self],
+        second: &[Self],
+    ) -> Result<Digits<Self>, CollectionAllocErr>;
 }
 
 impl<
         Digit: Copy
             + MultiplyDigitsPlain
+            + Send
             + SubtractDigitsInPlace
             + SumDigits
             + SumDigitsInPlace
+            + Sync
             + Zero,
     > MultiplyDigits for Digit
 where
     for<'a> &'a Digit: Zeroable,
 {
-    fn multiply_digits<const DIGIT_BITNESS: usize>(
+    fn try_multiply_digits<const DIGIT_BITNESS: usize>(
         first: &[Self],
         second: &[Self],
-    ) -> Vec<Self> {
-        const KARATSUBA_CUTOFF: usize = 70;
+    ) -> Result<Digits<Self>, CollectionAllocErr> {
         const KARATSUBA_SQUARE_CUTOFF: usize = KARATSUBA_CUTOFF * 2;
         let (longest, shortest) = if first.len() < second.len() {
             (&second, &first)
@@ -1556,9 +1658,11 @@ where
             }
         {
             if shortest.len() == 1 && shortest[0].is_zero() {
-                vec![Self::zero()]
+                Ok(smallvec![Self::zero()])
             } else {
-                Self::multiply_digits_plain::<DIGIT_BITNESS>(shortest, longest)
+                Self::try_multiply_digits_plain::<DIGIT_BITNESS>(
+                    shortest, longest,
+                )
             }
         } else {
             let are_digits_lopsided = 2 * shortest.len() <= longest.len();
@@ -1566,15 +1670,18 @@ where
                 let shortest_size = shortest.len();
                 let mut longest_size = longest.len();
                 let mut result =
-                    vec![Self::zero(); shortest_size + longest_size];
+                    try_zeroed_digits(shortest_size + longest_size)?;
                 let mut processed_digits_count = 0;
                 while longest_size > 0 {
                     let step_digits_count = longest_size.min(shortest_size);
-                    let product = Self::multiply_digits::<DIGIT_BITNESS>(
-                        shortest,
-                        &longest[processed_digits_count
-                            ..processed_digits_count + step_digits_count],
-                    );
+                    let product =
+                        ScratchDigits::from(Self::try_multiply_digits::<
+                            DIGIT_BITNESS,
+                        >(
+                            shortest,
+                            &longest[processed_digits_count
+                                ..processed_digits_count + step_digits_count],
+                        )?);
                     Self::sum_digits_in_place::<DIGIT_BITNESS>(
                         &mut result[processed_digits_count..],
                         &product,
@@ -1583,30 +1690,88 @@ where
                     processed_digits_count += step_digits_count;
                 }
                 trim_leading_zeros(&mut result);
-                result
+                Ok(result)
             } else {
                 let shift = longest.len() >> 1;
                 let (shortest_high, shortest_low) =
-                    split_digits(shortest, shift);
+                    try_split_digits(shortest, shift)?;
                 let (longest_high, longest_low) =
                     if shortest.as_ptr() == longest.as_ptr() {
-                        (shortest_high.clone(), shortest_low.clone())
+                        (
+                            ScratchDigits::from(try_digits_from_slice(
+                                &shortest_high,
+                            )?),
+                            ScratchDigits::from(try_digits_from_slice(
+                                &shortest_low,
+                            )?),
+                        )
                     } else {
-                        split_digits(longest, shift)
+                        try_split_digits(longest, shift)?
                     };
+                let is_parallel = shortest.len() > PARALLEL_KARATSUBA_CUTOFF;
+                let (highs_product, (lows_product, components_sums_product)) =
+                    maybe_join(
+                        is_parallel,
+                        || {
+                            Self::try_multiply_digits::<DIGIT_BITNESS>(
+                                &shortest_high,
+                                &longest_high,
+                            )
+                            .map(ScratchDigits::from)
+                        },
+                        || {
+                            maybe_join(
+                                is_parallel,
+                                || {
+                                    Self::try_multiply_digits::<DIGIT_BITNESS>(
+                                        &shortest_low,
+                                        &longest_low,
+                                    )
+                                    .map(ScratchDigits::from)
+                                },
+                                || {
+                                    let shortest_components_sum =
+                                        ScratchDigits::from(
+                                            Self::try_sum_digits::<
+                                                DIGIT_BITNESS,
+                                            >(
+                                                &shortest_high, &shortest_low
+                                            )?,
+                                        );
+                                    if shortest.as_ptr() == longest.as_ptr() {
+                                        Self::try_multiply_digits::<
+                                            DIGIT_BITNESS,
+                                        >(
+                                            &shortest_components_sum,
+                                            &shortest_components_sum,
+                                        )
+                                    } else {
+                                        Self::try_multiply_digits::<
+                                            DIGIT_BITNESS,
+                                        >(
+                                            &shortest_components_sum,
+                                            &ScratchDigits::from(
+                                                Self::try_sum_digits::<
+                                                    DIGIT_BITNESS,
+                                                >(
+                                                    &longest_high,
+                                                    &longest_low,
+                                                )?,
+                                            ),
+                                        )
+                                    }
+                                    .map(ScratchDigits::from)
+                                },
+                            )
+                        },
+                    );
+                let (highs_product, lows_product, components_sums_product) =
+                    (highs_product?, lows_product?, components_sums_product?);
                 let mut result =
-                    vec![Self::zero(); shortest.len() + longest.len()];
-                let highs_product = Self::multiply_digits::<DIGIT_BITNESS>(
-                    &shortest_high,
-                    &longest_high,
-                );
+                    try_zeroed_digits(shortest.len() + longest.len())?;
                 for (index, &digit) in highs_product.iter().enumerate() {
                     result[index + 2 * shift] = digit;
                 }
-                let lows_product = Self::multiply_digits::<DIGIT_BITNESS>(
-                    &shortest_low,
-                    &longest_low,
-                );
                 for (index, &digit) in lows_product.iter().enumerate() {
                     result[index] = digit;
                 }
@@ -1618,40 +1783,22 @@ where
                     &mut result[shift..],
                     &highs_product,
                 );
-                let shortest_components_sum = Self::sum_digits::<DIGIT_BITNESS>(
-                    &shortest_high,
-                    &shortest_low,
-                );
-                let longest_components_sum =
-                    if shortest.as_ptr() == longest.as_ptr() {
-                        shortest_components_sum.clone()
-                    } else {
-                        Self::sum_digits::<DIGIT_BITNESS>(
-                            &longest_high,
-                            &longest_low,
-                        )
-                    };
-                let components_sums_product =
-                    Self::multiply_digits::<DIGIT_BITNESS>(
-                        &shortest_components_sum,
-                        &longest_components_sum,
-                    );
                 Self::sum_digits_in_place::<DIGIT_BITNESS>(
                     &mut result[shift..],
                     &components_sums_product,
                 );
                 trim_leading_zeros(&mut result);
-                result
+                Ok(result)
             }
         }
     }
 }
 
 pub(super) trait MultiplyDigitsPlain: Sized {
-    fn multiply_digits_plain<const DIGIT_BITNESS: usize>(
+    fn try_multiply_digits_plain<const DIGIT_BITNESS: usize>(
         shortest: &[Self],
         longest: &[Self],
-    ) -> Vec<Self>;
+    ) -> Result<Digits<Self>, CollectionAllocErr>;
 }
 
 impl<
@@ -1674,11 +1821,11 @@ where
         + ShrAssign<usize>
         + Zero,
 {
-    fn multiply_digits_plain<const DIGIT_BITNESS: usize>(
+    fn try_multiply_digits_plain<const DIGIT_BITNESS: usize>(
         shortest: &[Self],
         longest: &[Self],
-    ) -> Vec<Self> {
-        let mut result = vec![Self::zero(); shortest.len() + longest.len()];
+    ) -> Result<Digits<Self>, CollectionAllocErr> {
+        let mut result = try_zeroed_digits(shortest.len() + longest.len())?;
         let digit_mask = DoublePrecisionOf::<Self>::digit_mask(DIGIT_BITNESS);
         if shortest.as_ptr() == longest.as_ptr() {
             for index in 0..shortest.len() {
@@ -1753,7 +1900,246 @@ where
             }
         }
         trim_leading_zeros(&mut result);
-        result
+        Ok(result)
+    }
+}
+
+pub trait MultiplyDigitsInPlace: Sized {
+    fn multiply_digits_in_place<const DIGIT_BITNESS: usize>(
+        first: &mut Digits<Self>,
+        second: &[Self],
+    );
+}
+
+impl<Digit: Copy + MultiplyAddDigitsInPlace + MultiplyDigits + Zero>
+    MultiplyDigitsInPlace for Digit
+where
+    for<'a> &'a Digit: Zeroable,
+{
+    fn multiply_digits_in_place<const DIGIT_BITNESS: usize>(
+        first: &mut Digits<Self>,
+        second: &[Self],
+    ) {
+        if first.len().min(second.len()) > KARATSUBA_CUTOFF {
+            let product =
+                Self::multiply_digits::<DIGIT_BITNESS>(first, second);
+            wipe_digits(first);
+            *first = product;
+        } else if second.len() == 1 && second[0].is_zero() {
+            first.truncate(1);
+            first[0] = Self::zero();
+        } else if first.len() > 1 || !first[0].is_zero() {
+            let first_size = first.len();
+            reserve_digits(first, second.len());
+            first.resize(first_size + second.len(), Self::zero());
+            for index in (0..first_size).rev() {
+                let digit = first[index];
+                first[index] = Self::zero();
+                let carry = Self::multiply_add_digits_in_place::<DIGIT_BITNESS>(
+                    &mut first[index..],
+                    second,
+                    digit,
+                );
+                debug_assert!(carry.is_zero());
+            }
+            trim_leading_zeros(first);
+        }
+    }
+}
+
+pub(super) trait MultiplyAddDigitsInPlace: Sized {
+    fn multiply_add_digits_in_place<const DIGIT_BITNESS: usize>(
+        target: &mut [Self],
+        digits: &[Self],
+        multiplier: Self,
+    ) -> Self;
+
+    fn multiply_subtract_digits_in_place<const DIGIT_BITNESS: usize>(
+        target: &mut [Self],
+        digits: &[Self],
+        multiplier: Self,
+    ) -> Self;
+}
+
+impl<
+        Digit: BitAnd<Output = Digit>
+            + Copy
+            + DigitMask
+            + DoublePrecision
+            + One
+            + Shr<usize, Output = Digit>
+            + TryFrom<DoublePrecisionOf<Digit>>
+            + WrappingSub<Output = Digit>
+            + Zero,
+    > MultiplyAddDigitsInPlace for Digit
+where
+    for<'a> &'a DoublePrecisionOf<Digit>: Zeroable,
+    DoublePrecisionOf<Digit>: Add<Output = DoublePrecisionOf<Digit>>
+        + BitAnd<Output = DoublePrecisionOf<Digit>>
+        + Copy
+        + DigitMask
+        + Mul<Output = DoublePrecisionOf<Digit>>
+        + ShrAssign<usize>
+        + Zero,
+{
+    fn multiply_add_digits_in_place<const DIGIT_BITNESS: usize>(
+        target: &mut [Self],
+        digits: &[Self],
+        multiplier: Self,
+    ) -> Self {
+        let digit_mask = DoublePrecisionOf::<Self>::digit_mask(DIGIT_BITNESS);
+        let multiplier = DoublePrecisionOf::<Self>::from(multiplier);
+        let mut accumulator = DoublePrecisionOf::<Self>::zero();
+        for (index, &digit) in digits.iter().enumerate() {
+            accumulator = accumulator
+                + DoublePrecisionOf::<Self>::from(target[index])
+                + DoublePrecisionOf::<Self>::from(digit) * multiplier;
+            target[index] = unsafe {
+                Self::try_from(accumulator & digit_mask).unwrap_unchecked()
+            };
+            accumulator >>= DIGIT_BITNESS;
+        }
+        for target_digit in target[digits.len()..].iter_mut() {
+            if accumulator.is_zero() {
+                break;
+            }
+            accumulator =
+                accumulator + DoublePrecisionOf::<Self>::from(*target_digit);
+            *target_digit = unsafe {
+                Self::try_from(accumulator & digit_mask).unwrap_unchecked()
+            };
+            accumulator >>= DIGIT_BITNESS;
+        }
+        unsafe { Self::try_from(accumulator).unwrap_unchecked() }
+    }
+
+    fn multiply_subtract_digits_in_place<const DIGIT_BITNESS: usize>(
+        target: &mut [Self],
+        digits: &[Self],
+        multiplier: Self,
+    ) -> Self {
+        let digit_mask = Self::digit_mask(DIGIT_BITNESS);
+        let double_digit_mask =
+            DoublePrecisionOf::<Self>::digit_mask(DIGIT_BITNESS);
+        let multiplier = DoublePrecisionOf::<Self>::from(multiplier);
+        let mut accumulator = DoublePrecisionOf::<Self>::zero();
+        for (index, &digit) in digits.iter().enumerate() {
+            accumulator = accumulator
+                + DoublePrecisionOf::<Self>::from(digit) * multiplier;
+            let difference = target[index].wrapping_sub(unsafe {
+                Self::try_from(accumulator & double_digit_mask)
+                    .unwrap_unchecked()
+            });
+            target[index] = difference & digit_mask;
+            accumulator >>= DIGIT_BITNESS;
+            accumulator = accumulator
+                + DoublePrecisionOf::<Self>::from(
+                    (difference >> DIGIT_BITNESS) & Self::one(),
+                );
+        }
+        for target_digit in target[digits.len()..].iter_mut() {
+            if accumulator.is_zero() {
+                break;
+            }
+            let difference = target_digit.wrapping_sub(unsafe {
+                Self::try_from(accumulator & double_digit_mask)
+                    .unwrap_unchecked()
+            });
+            *target_digit = difference & digit_mask;
+            accumulator >>= DIGIT_BITNESS;
+            accumulator = accumulator
+                + DoublePrecisionOf::<Self>::from(
+                    (difference >> DIGIT_BITNESS) & Self::one(),
+                );
+        }
+        unsafe { Self::try_from(accumulator).unwrap_unchecked() }
+    }
+}
+
+pub trait MultiplyAddComponentsInPlace: Sized {
+    fn multiply_add_components_in_place<const DIGIT_BITNESS: usize>(
+        sign: &mut Sign,
+        digits: &mut Digits<Self>,
+        first_sign: Sign,
+        first: &[Self],
+        second_sign: Sign,
+        second: &[Self],
+    );
+}
+
+impl<
+        Digit: ComplementInPlace
+            + Copy
+            + MultiplyAddDigitsInPlace
+            + MultiplyDigits
+            + SumComponentsInPlace
+            + Zero,
+    > MultiplyAddComponentsInPlace for Digit
+where
+    for<'a> &'a Digit: Zeroable,
+{
+    fn multiply_add_components_in_place<const DIGIT_BITNESS: usize>(
+        sign: &mut Sign,
+        digits: &mut Digits<Self>,
+        first_sign: Sign,
+        first: &[Self],
+        second_sign: Sign,
+        second: &[Self],
+    ) {
+        let product_sign = first_sign * second_sign;
+        if product_sign.is_zero() {
+            return;
+        }
+        if first.len().min(second.len()) > KARATSUBA_CUTOFF {
+            Self::sum_components_in_place::<DIGIT_BITNESS>(
+                sign,
+                digits,
+                product_sign,
+                &ScratchDigits::from(Self::multiply_digits::<DIGIT_BITNESS>(
+                    first, second,
+                )),
+            );
+            return;
+        }
+        let (longest, shortest) = if first.len() < second.len() {
+            (second, first)
+        } else {
+            (first, second)
+        };
+        let size = digits.len().max(longest.len() + shortest.len());
+        if sign.is_zero() || *sign == product_sign {
+            *sign = product_sign;
+            reserve_digits(digits, size + 1 - digits.len());
+            digits.resize(size + 1, Self::zero());
+            for (index, &digit) in shortest.iter().enumerate() {
+                let carry = Self::multiply_add_digits_in_place::<DIGIT_BITNESS>(
+                    &mut digits[index..],
+                    longest,
+                    digit,
+                );
+                debug_assert!(carry.is_zero());
+            }
+        } else {
+            reserve_digits(digits, size - digits.len());
+            digits.resize(size, Self::zero());
+            let mut is_negative = false;
+            for (index, &digit) in shortest.iter().enumerate() {
+                is_negative |= !Self::multiply_subtract_digits_in_place::<
+                    DIGIT_BITNESS,
+                >(
+                    &mut digits[index..], longest, digit
+                )
+                .is_zero();
+            }
+            if is_negative {
+                Self::complement_in_place::<DIGIT_BITNESS>(digits);
+                *sign = product_sign;
+            }
+        }
+        trim_leading_zeros(digits);
+        if digits.len() == 1 && digits[0].is_zero() {
+            *sign = Sign::zero();
+        }
     }
 }
 
@@ -1768,15 +2154,55 @@ pub(super) fn negate_bytes(digits: &mut [u8]) {
     }
 }
 
+struct BasesInfima<const TARGET_BITNESS: usize>;
+
+impl<const TARGET_BITNESS: usize> BasesInfima<TARGET_BITNESS> {
+    const EXPONENTS_AND_POWERS: [(usize, usize); MAX_ALPHABET_SIZE + 1] =
+        to_bases_infima(TARGET_BITNESS);
+}
+
+const fn to_bases_infima(
+    target_bitness: usize,
+) -> [(usize, usize); MAX_ALPHABET_SIZE + 1] {
+    let target_base = 1usize << target_bitness;
+    let mut result = [(0usize, 0usize); MAX_ALPHABET_SIZE + 1];
+    let mut base = 2usize;
+    while base <= MAX_ALPHABET_SIZE {
+        let mut exponent = 1usize;
+        let mut power = base;
+        while let Some(candidate) = power.checked_mul(base) {
+            if candidate > target_base {
+                break;
+            }
+            power = candidate;
+            exponent += 1;
+        }
+        result[base] = (exponent, power);
+        base += 1;
+    }
+    result
+}
+
+fn to_digits_count_upper_bound<const TARGET_BITNESS: usize>(
+    source_digits_count: usize,
+    source_base: usize,
+) -> usize {
+    let source_base_bit_length =
+        (usize::BITS - (source_base - 1).leading_zeros()) as usize;
+    (source_digits_count * source_base_bit_length + TARGET_BITNESS - 1)
+        / TARGET_BITNESS
+        + 1
+}
+
 pub(super) trait GreaterBinaryBaseFromNonBinaryDigits<Source>:
     Sized
 {
-    fn greater_binary_base_from_non_binary_digits<
+    fn try_greater_binary_base_from_non_binary_digits<
         const TARGET_BITNESS: usize,
     >(
         source: &[Source],
         source_base: usize,
-    ) -> Vec<Self>;
+    ) -> Result<Digits<Self>, CollectionAllocErr>;
 }
 
 impl<
@@ -1796,43 +2222,20 @@ where
         + TryFrom<usize>
         + Zero,
 {
-    fn greater_binary_base_from_non_binary_digits<
+    fn try_greater_binary_base_from_non_binary_digits<
         const TARGET_BITNESS: usize,
     >(
         source: &[Source],
         source_base: usize,
-    ) -> Vec<Self> {
-        static mut BASES_LOGS: [f64; 37] = [0.0; 37];
-        static mut INFIMUM_BASES_EXPONENTS: [usize; 37] = [0; 37];
-        static mut INFIMUM_BASES_POWERS: [usize; 37] = [0; 37];
+    ) -> Result<Digits<Self>, CollectionAllocErr> {
         let target_digit_mask =
             DoublePrecisionOf::<Self>::digit_mask(TARGET_BITNESS);
-        if unsafe { BASES_LOGS[source_base] } == 0.0 {
-            let bases_log = (source_base as f64).ln()
-                / ((1usize << TARGET_BITNESS) as f64).ln();
-            unsafe { BASES_LOGS[source_base] = bases_log };
-            let mut infimum_base_power = source_base;
-            let mut infimum_base_exponent: usize = 1;
-            loop {
-                let candidate: usize = infimum_base_power * source_base;
-                if candidate > 1usize << TARGET_BITNESS {
-                    break;
-                }
-                infimum_base_power = candidate;
-                infimum_base_exponent += 1;
-            }
-            unsafe { INFIMUM_BASES_POWERS[source_base] = infimum_base_power };
-            unsafe {
-                INFIMUM_BASES_EXPONENTS[source_base] = infimum_base_exponent;
-            };
-        }
-        let digits_count_upper_bound =
-            (source.len() as f64) * unsafe { BASES_LOGS[source_base] } + 1.0;
         let mut result =
-            Vec::<Self>::with_capacity(digits_count_upper_bound as usize);
-        let infimum_base_exponent =
-            unsafe { INFIMUM_BASES_EXPONENTS[source_base] };
-        let infimum_base_power = unsafe { INFIMUM_BASES_POWERS[source_base] };
+            try_digits_with_capacity(to_digits_count_upper_bound::<
+                TARGET_BITNESS,
+            >(source.len(), source_base))?;
+        let (infimum_base_exponent, infimum_base_power) =
+            BasesInfima::<TARGET_BITNESS>::EXPONENTS_AND_POWERS[source_base];
         let mut reversed_source = source.iter().rev();
         while let Some(&digit) = reversed_source.next() {
             let mut accumulator = DoublePrecisionOf::<Self>::from(digit);
@@ -1876,17 +2279,19 @@ where
         if result.is_empty() {
             result.push(Self::zero());
         }
-        result
+        Ok(result)
     }
 }
 
 pub(super) trait LesserBinaryBaseFromNonBinaryDigits<Source>:
     Sized
 {
-    fn lesser_binary_base_from_non_binary_digits<const TARGET_BITNESS: usize>(
+    fn try_lesser_binary_base_from_non_binary_digits<
+        const TARGET_BITNESS: usize,
+    >(
         source: &[Source],
         source_base: usize,
-    ) -> Vec<Self>;
+    ) -> Result<Digits<Self>, CollectionAllocErr>;
 }
 
 impl<
@@ -1904,24 +2309,18 @@ where
         + ShrAssign<usize>
         + TryFrom<usize>,
 {
-    fn lesser_binary_base_from_non_binary_digits<
+    fn try_lesser_binary_base_from_non_binary_digits<
         const TARGET_BITNESS: usize,
     >(
         source: &[Source],
         source_base: usize,
-    ) -> Vec<Self> {
-        static mut BASES_LOGS: [f64; 37] = [0.0; 37];
+    ) -> Result<Digits<Self>, CollectionAllocErr> {
         let target_digit_mask =
             DoublePrecisionOf::<Self>::digit_mask(TARGET_BITNESS);
-        if unsafe { BASES_LOGS[source_base] } == 0.0 {
-            let bases_log = (source_base as f64).ln()
-                / ((1usize << TARGET_BITNESS) as f64).ln();
-            unsafe { BASES_LOGS[source_base] = bases_log };
-        }
-        let digits_count_upper_bound =
-            (source.len() as f64) * unsafe { BASES_LOGS[source_base] } + 1.0;
         let mut result =
-            Vec::<Self>::with_capacity(digits_count_upper_bound as usize);
+            try_digits_with_capacity(to_digits_count_upper_bound::<
+                TARGET_BITNESS,
+            >(source.len(), source_base))?;
         let source_base = unsafe {
             DoublePrecisionOf::<Self>::try_from(source_base).unwrap_unchecked()
         };
@@ -1948,7 +2347,7 @@ where
         if result.is_empty() {
             result.push(Self::zero());
         }
-        result
+        Ok(result)
     }
 }
 
@@ -1973,7 +2372,7 @@ impl<
     }
 }
 
-pub(super) trait MaybeReduceDigits<Output>: Sized {
+pub trait MaybeReduceDigits<Output>: Sized {
     fn maybe_reduce_digits<const DIGIT_BITNESS: usize>(
         digits: &[Self],
     ) -> Option<Output>;
@@ -1983,6 +2382,9 @@ impl<
         Digit: Copy,
         Output: CheckedShl<u32, Output = Option<Output>>
             + BitOr<Output = Output>
+            + Copy
+            + PartialEq
+            + Shr<u32, Output = Output>
             + TryFrom<Digit>
             + Zero,
     > MaybeReduceDigits<Output> for Digit
@@ -1990,11 +2392,17 @@ impl<
     fn maybe_reduce_digits<const DIGIT_BITNESS: usize>(
         digits: &[Self],
     ) -> Option<Output> {
+        let shift = unsafe { u32::try_from(DIGIT_BITNESS).unwrap_unchecked() };
         let mut result = Output::zero();
         for &digit in digits.iter().rev() {
-            result = result.checked_shl(unsafe {
-                u32::try_from(DIGIT_BITNESS).unwrap_unchecked()
-            })? | Output::try_from(digit).ok()?;
+            if result != Output::zero() {
+                let shifted = result.checked_shl(shift)?;
+                if shifted >> shift != result {
+                    return None;
+                }
+                result = shifted;
+            }
+            result = result | Output::try_from(digit).ok()?;
         }
         Some(result)
     }
@@ -2008,9 +2416,8 @@ pub(super) trait ReduceDigitsToFloat<Output>: Sized {
 
 macro_rules! reduce_digits_to_float_impl {
     ($($float:ty)*) => ($(
-        impl<Digit: Copy> ReduceDigitsToFloat<$float> for Digit
-        where
-            $float: From<Digit>,
+        impl<Digit: Copy + ToFloat<$float>> ReduceDigitsToFloat<$float>
+            for Digit
         {
             fn reduce_digits_to_float<const DIGIT_BITNESS: usize>(
                 digits: &[Self],
@@ -2026,7 +2433,7 @@ macro_rules! reduce_digits_to_float_impl {
                     )
                 } as $float;
                 for &digit in digits.iter().rev() {
-                    result = result * scale + <$float>::from(digit);
+                    result = result * scale + digit.to_float();
                 }
                 result
             }
@@ -2041,7 +2448,7 @@ pub(super) trait PrimitiveShiftDigitsLeft: Sized {
         digits: &[Self],
         shift_quotient: usize,
         shift_remainder: Self,
-    ) -> Option<Vec<Self>>;
+    ) -> Option<Digits<Self>>;
 }
 
 impl<
@@ -2061,8 +2468,8 @@ where
         digits: &[Self],
         shift_quotient: usize,
         shift_remainder: Self,
-    ) -> Option<Vec<Self>> {
-        let mut result = Vec::<Self>::new();
+    ) -> Option<Digits<Self>> {
+        let mut result = Digits::<Self>::new();
         result
             .try_reserve_exact(
                 digits.len()
@@ -2093,11 +2500,11 @@ where
     }
 }
 
-pub(super) trait ShiftDigitsLeft: Sized {
+pub trait ShiftDigitsLeft: Sized {
     fn shift_digits_left<const DIGIT_BITNESS: usize>(
         base: &[Self],
         shift: &[Self],
-    ) -> Result<Vec<Self>, ShlError>;
+    ) -> Result<Digits<Self>, ShlError>;
 }
 
 impl<
@@ -2111,7 +2518,7 @@ impl<
     fn shift_digits_left<const DIGIT_BITNESS: usize>(
         base: &[Self],
         shift: &[Self],
-    ) -> Result<Vec<Self>, ShlError> {
+    ) -> Result<Digits<Self>, ShlError> {
         let (shift_quotient_digits, shift_remainder) =
             Self::div_rem_digits_by_digit::<DIGIT_BITNESS>(shift, unsafe {
                 Self::try_from(DIGIT_BITNESS).unwrap_unchecked()
@@ -2215,7 +2622,7 @@ pub(super) trait PrimitiveShiftDigitsRight: Sized {
         digits: &[Self],
         shift_quotient: usize,
         shift_remainder: Self,
-    ) -> Vec<Self>;
+    ) -> Digits<Self>;
 }
 
 impl<
@@ -2236,9 +2643,9 @@ where
         digits: &[Self],
         shift_quotient: usize,
         shift_remainder: Self,
-    ) -> Vec<Self> {
+    ) -> Digits<Self> {
         if digits.len() <= shift_quotient {
-            return vec![Self::zero()];
+            return smallvec![Self::zero()];
         }
         let result_digits_count = digits.len() - shift_quotient;
         let shift_remainder =
@@ -2246,7 +2653,7 @@ where
         let high_shift = DIGIT_BITNESS - shift_remainder;
         let low_mask = Self::digit_mask(high_shift);
         let high_mask = Self::digit_mask(DIGIT_BITNESS) ^ low_mask;
-        let mut result = vec![Self::zero(); result_digits_count];
+        let mut result = smallvec![Self::zero(); result_digits_count];
         let mut position = shift_quotient;
         for index in 0..result_digits_count {
             result[index] = (digits[position] >> shift_remainder) & low_mask;
@@ -2266,7 +2673,7 @@ pub(super) trait ShiftDigitsRight: Sized {
         base_sign: Sign,
         base: &[Self],
         shift: &[Self],
-    ) -> (Sign, Vec<Self>);
+    ) -> (Sign, Digits<Self>);
 }
 
 impl<
@@ -2286,7 +2693,7 @@ where
         base_sign: Sign,
         base: &[Self],
         shift: &[Self],
-    ) -> (Sign, Vec<Self>) {
+    ) -> (Sign, Digits<Self>) {
         let (shift_quotient_digits, shift_remainder) =
             Self::div_rem_digits_by_digit::<DIGIT_BITNESS>(shift, unsafe {
                 Self::try_from(DIGIT_BITNESS).unwrap_unchecked()
@@ -2296,9 +2703,9 @@ where
                 .unwrap_or(usize::MAX / size_of::<Self>());
         if shift_quotient >= usize::MAX / size_of::<Self>() {
             if base_sign.is_negative() {
-                (-Sign::one(), vec![Self::one(); 1])
+                (-Sign::one(), smallvec![Self::one(); 1])
             } else {
-                (Sign::zero(), vec![Self::zero(); 1])
+                (Sign::zero(), smallvec![Self::zero(); 1])
             }
         } else if base_sign.is_negative() {
             let (inverted_sign, inverted_digits) =
@@ -2323,56 +2730,75 @@ where
     }
 }
 
-fn split_digits<Digit: Clone>(
+fn try_split_digits<Digit: Copy>(
     digits: &[Digit],
     size: usize,
-) -> (Vec<Digit>, Vec<Digit>)
+) -> Result<(ScratchDigits<Digit>, ScratchDigits<Digit>), CollectionAllocErr>
 where
     for<'a> &'a Digit: Zeroable,
 {
     let (low, high) = digits.split_at(digits.len().min(size));
-    let (mut low, mut high) = (low.to_vec(), high.to_vec());
+    let (mut low, mut high) = (
+        ScratchDigits::from(try_digits_from_slice(low)?),
+        ScratchDigits::from(try_digits_from_slice(high)?),
+    );
     trim_leading_zeros(&mut high);
     trim_leading_zeros(&mut low);
-    (high, low)
+    Ok((high, low))
 }
 
-pub(super) trait SubtractComponents: Sized {
+pub trait SubtractComponents: Sized {
     fn subtract_components<const DIGIT_BITNESS: usize>(
         minuend_sign: Sign,
         minuend: &[Self],
         subtrahend_sign: Sign,
         subtrahend: &[Self],
-    ) -> (Sign, Vec<Self>);
+    ) -> (Sign, Digits<Self>) {
+        expect_allocated(Self::try_subtract_components::<DIGIT_BITNESS>(
+            minuend_sign,
+            minuend,
+            subtrahend_sign,
+            subtrahend,
+        ))
+    }
+
+    fn try_subtract_components<const DIGIT_BITNESS: usize>(
+        minuend_sign: Sign,
+        minuend: &[Self],
+        subtrahend_sign: Sign,
+        subtrahend: &[Self],
+    ) -> Result<(Sign, Digits<Self>), CollectionAllocErr>;
 }
 
 impl<Digit: SubtractDigits + SumDigits> SubtractComponents for Digit {
-    fn subtract_components<const DIGIT_BITNESS: usize>(
+    fn try_subtract_components<const DIGIT_BITNESS: usize>(
         minuend_sign: Sign,
         minuend: &[Self],
         subtrahend_sign: Sign,
         subtrahend: &[Self],
-    ) -> (Sign, Vec<Self>) {
+    ) -> Result<(Sign, Digits<Self>), CollectionAllocErr> {
         if minuend_sign.is_negative() {
             if subtrahend_sign.is_negative() {
-                Self::subtract_digits::<DIGIT_BITNESS>(
+                Self::try_subtract_digits::<DIGIT_BITNESS>(
                     subtrahend,
                     minuend,
                     Sign::one(),
                 )
             } else {
-                (
+                Ok((
                     -Sign::one(),
-                    Self::sum_digits::<DIGIT_BITNESS>(minuend, subtrahend),
-                )
+                    Self::try_sum_digits::<DIGIT_BITNESS>(
+                        minuend, subtrahend,
+                    )?,
+                ))
             }
         } else if subtrahend_sign.is_negative() {
-            (
+            Ok((
                 Sign::one(),
-                Self::sum_digits::<DIGIT_BITNESS>(minuend, subtrahend),
-            )
+                Self::try_sum_digits::<DIGIT_BITNESS>(minuend, subtrahend)?,
+            ))
         } else {
-            Self::subtract_digits::<DIGIT_BITNESS>(
+            Self::try_subtract_digits::<DIGIT_BITNESS>(
                 minuend,
                 subtrahend,
                 Sign::one(),
@@ -2386,7 +2812,17 @@ pub(super) trait SubtractDigits: Sized {
         minuend: &[Self],
         subtrahend: &[Self],
         sign: Sign,
-    ) -> (Sign, Vec<Self>);
+    ) -> (Sign, Digits<Self>) {
+        expect_allocated(Self::try_subtract_digits::<DIGIT_BITNESS>(
+            minuend, subtrahend, sign,
+        ))
+    }
+
+    fn try_subtract_digits<const DIGIT_BITNESS: usize>(
+        minuend: &[Self],
+        subtrahend: &[Self],
+        sign: Sign,
+    ) -> Result<(Sign, Digits<Self>), CollectionAllocErr>;
 }
 
 impl<
@@ -2403,11 +2839,11 @@ impl<
 where
     for<'a> &'a Digit: Zeroable,
 {
-    fn subtract_digits<const DIGIT_BITNESS: usize>(
+    fn try_subtract_digits<const DIGIT_BITNESS: usize>(
         minuend: &[Self],
         subtrahend: &[Self],
         mut sign: Sign,
-    ) -> (Sign, Vec<Self>) {
+    ) -> Result<(Sign, Digits<Self>), CollectionAllocErr> {
         let mut longest = &minuend;
         let mut shortest = &subtrahend;
         let mut longest_size = longest.len();
@@ -2427,7 +2863,7 @@ where
                     }
                 }
                 if index == 0 && longest[0] == shortest[0] {
-                    return (Sign::zero(), vec![Self::zero()]);
+                    return Ok((Sign::zero(), smallvec![Self::zero()]));
                 }
                 if longest[index] < shortest[index] {
                     (longest, shortest) = (shortest, longest);
@@ -2438,7 +2874,7 @@ where
             }
             Ordering::Greater => {}
         };
-        let mut result = Vec::<Self>::with_capacity(longest_size);
+        let mut result = try_digits_with_capacity(longest_size)?;
         let mut accumulator = Self::zero();
         let digit_mask = Self::digit_mask(DIGIT_BITNESS);
         for index in 0..shortest_size {
@@ -2456,7 +2892,7 @@ where
             accumulator &= Self::one();
         }
         trim_leading_zeros(&mut result);
-        (sign, result)
+        Ok((sign, result))
     }
 }
 
@@ -2507,42 +2943,121 @@ where
     }
 }
 
-pub(super) trait SumComponents: Sized {
+pub trait SumComponents: Sized {
     fn sum_components<const DIGIT_BITNESS: usize>(
         first_sign: Sign,
         first: &[Self],
         second_sign: Sign,
         second: &[Self],
-    ) -> (Sign, Vec<Self>);
+    ) -> (Sign, Digits<Self>) {
+        expect_allocated(Self::try_sum_components::<DIGIT_BITNESS>(
+            first_sign,
+            first,
+            second_sign,
+            second,
+        ))
+    }
+
+    fn try_sum_components<const DIGIT_BITNESS: usize>(
+        first_sign: Sign,
+        first: &[Self],
+        second_sign: Sign,
+        second: &[Self],
+    ) -> Result<(Sign, Digits<Self>), CollectionAllocErr>;
 }
 
 impl<Digit: SubtractDigits + SumDigits> SumComponents for Digit {
-    fn sum_components<const DIGIT_BITNESS: usize>(
+    fn try_sum_components<const DIGIT_BITNESS: usize>(
         first_sign: Sign,
         first: &[Self],
         second_sign: Sign,
         second: &[Self],
-    ) -> (Sign, Vec<Self>) {
+    ) -> Result<(Sign, Digits<Self>), CollectionAllocErr> {
         if first_sign.is_negative() {
             if second_sign.is_negative() {
-                (
+                Ok((
                     -Sign::one(),
-                    Self::sum_digits::<DIGIT_BITNESS>(first, second),
-                )
+                    Self::try_sum_digits::<DIGIT_BITNESS>(first, second)?,
+                ))
             } else {
-                Self::subtract_digits::<DIGIT_BITNESS>(
+                Self::try_subtract_digits::<DIGIT_BITNESS>(
                     second,
                     first,
                     Sign::one(),
                 )
             }
         } else if second_sign.is_negative() {
-            Self::subtract_digits::<DIGIT_BITNESS>(first, second, Sign::one())
+            Self::try_subtract_digits::<DIGIT_BITNESS>(
+                first,
+                second,
+                Sign::one(),
+            )
         } else {
-            (
+            Ok((
                 first_sign.max(second_sign),
-                Self::sum_digits::<DIGIT_BITNESS>(first, second),
-            )
+                Self::try_sum_digits::<DIGIT_BITNESS>(first, second)?,
+            ))
+        }
+    }
+}
+
+pub trait SumComponentsInPlace: Sized {
+    fn sum_components_in_place<const DIGIT_BITNESS: usize>(
+        first_sign: &mut Sign,
+        first: &mut Digits<Self>,
+        second_sign: Sign,
+        second: &[Self],
+    );
+}
+
+impl<
+        Digit: ComplementInPlace
+            + Copy
+            + SubtractDigitsInPlace
+            + SumDigitsInPlace
+            + Zero,
+    > SumComponentsInPlace for Digit
+where
+    for<'a> &'a Digit: Zeroable,
+{
+    fn sum_components_in_place<const DIGIT_BITNESS: usize>(
+        first_sign: &mut Sign,
+        first: &mut Digits<Self>,
+        second_sign: Sign,
+        second: &[Self],
+    ) {
+        if second_sign.is_zero() {
+            return;
+        }
+        if first_sign.is_zero() {
+            *first_sign = second_sign;
+            first.clear();
+            reserve_digits(first, second.len());
+            first.extend_from_slice(second);
+            return;
+        }
+        if first.len() < second.len() {
+            reserve_digits(first, second.len() - first.len());
+            first.resize(second.len(), Self::zero());
+        }
+        if *first_sign == second_sign {
+            let carry =
+                Self::sum_digits_in_place::<DIGIT_BITNESS>(first, second);
+            if !carry.is_zero() {
+                reserve_digits(first, 1);
+                first.push(carry);
+            }
+        } else {
+            let borrow =
+                Self::subtract_digits_in_place::<DIGIT_BITNESS>(first, second);
+            if !borrow.is_zero() {
+                Self::complement_in_place::<DIGIT_BITNESS>(first);
+                *first_sign = second_sign;
+            }
+            trim_leading_zeros(first);
+            if first.len() == 1 && first[0].is_zero() {
+                *first_sign = Sign::zero();
+            }
         }
     }
 }
@@ -2551,7 +3066,14 @@ pub(super) trait SumDigits: Sized {
     fn sum_digits<const DIGIT_BITNESS: usize>(
         first: &[Self],
         second: &[Self],
-    ) -> Vec<Self>;
+    ) -> Digits<Self> {
+        expect_allocated(Self::try_sum_digits::<DIGIT_BITNESS>(first, second))
+    }
+
+    fn try_sum_digits<const DIGIT_BITNESS: usize>(
+        first: &[Self],
+        second: &[Self],
+    ) -> Result<Digits<Self>, CollectionAllocErr>;
 }
 
 impl<
@@ -2566,16 +3088,16 @@ impl<
 where
     for<'a> &'a Digit: Zeroable,
 {
-    fn sum_digits<const DIGIT_BITNESS: usize>(
+    fn try_sum_digits<const DIGIT_BITNESS: usize>(
         first: &[Self],
         second: &[Self],
-    ) -> Vec<Self> {
+    ) -> Result<Digits<Self>, CollectionAllocErr> {
         let (longest, shortest) = if first.len() < second.len() {
             (&second, &first)
         } else {
             (&first, &second)
         };
-        let mut result = Vec::<Self>::with_capacity(longest.len() + 1);
+        let mut result = try_digits_with_capacity(longest.len() + 1)?;
         let mut accumulator: Self = Self::zero();
         let digit_mask = Self::digit_mask(DIGIT_BITNESS);
         for index in 0..shortest.len() {
@@ -2590,7 +3112,7 @@ where
         }
         result.push(accumulator);
         trim_leading_zeros(&mut result);
-        result
+        Ok(result)
     }
 }
 
@@ -2659,9 +3181,9 @@ where
 
 pub(super) trait GcdDigits: Sized {
     fn gcd_digits<const DIGIT_BITNESS: usize>(
-        first: Vec<Self>,
-        second: Vec<Self>,
-    ) -> (Sign, Vec<Self>);
+        first: Digits<Self>,
+        second: Digits<Self>,
+    ) -> (Sign, Digits<Self>);
 }
 
 impl<
@@ -2709,9 +3231,9 @@ where
         + Zero,
 {
     fn gcd_digits<const DIGIT_BITNESS: usize>(
-        first: Vec<Self>,
-        second: Vec<Self>,
-    ) -> (Sign, Vec<Self>) {
+        first: Digits<Self>,
+        second: Digits<Self>,
+    ) -> (Sign, Digits<Self>) {
         let (mut largest, mut smallest) =
             if digits_lesser_than(&first, &second) {
                 (second, first)
@@ -2797,17 +3319,19 @@ where
             }
             if iterations_count == 0 {
                 (largest, smallest) = if smallest_digits_count == 1 {
-                    let (_, remainder) = Self::div_rem_digits_by_digit::<
-                        DIGIT_BITNESS,
-                    >(
-                        &largest, smallest[0]
-                    );
-                    (smallest, vec![remainder])
+                    let (mut quotient, remainder) =
+                        Self::div_rem_digits_by_digit::<DIGIT_BITNESS>(
+                            &largest,
+                            smallest[0],
+                        );
+                    wipe_digits(&mut quotient);
+                    (smallest, smallvec![remainder])
                 } else {
-                    let (_, remainder) =
+                    let (mut quotient, remainder) =
                         Self::div_rem_by_two_or_more_digits::<DIGIT_BITNESS>(
                             &largest, &smallest,
                         );
+                    wipe_digits(&mut quotient);
                     (smallest, remainder)
                 };
                 continue;
@@ -2827,9 +3351,9 @@ where
             let mut next_smallest_accumulator =
                 OppositionOf::<DoublePrecisionOf<Self>>::zero();
             let mut next_largest_digits =
-                Vec::<Self>::with_capacity(largest_digits_count);
+                Digits::<Self>::with_capacity(largest_digits_count);
             let mut next_smallest_digits =
-                Vec::<Self>::with_capacity(largest_digits_count);
+                Digits::<Self>::with_capacity(largest_digits_count);
             for index in 0..smallest_digits_count {
                 next_largest_accumulator = next_largest_accumulator
                     + (first_coefficient
@@ -2888,7 +3412,7 @@ where
         let reduced_result = Self::reduce_digits::<DIGIT_BITNESS>(&largest)
             .gcd(Self::reduce_digits::<DIGIT_BITNESS>(&smallest));
         if reduced_result.is_zero() {
-            (Sign::zero(), vec![Self::zero(); 1])
+            (Sign::zero(), smallvec![Self::zero(); 1])
         } else {
             (
                 Sign::one(),
@@ -2900,7 +3424,123 @@ where
     }
 }
 
-pub(super) fn trim_leading_zeros<Digit>(digits: &mut Vec<Digit>)
+pub(super) fn digits_bit_length<
+    Digit: BitLength<Output = usize> + Copy,
+    const DIGIT_BITNESS: usize,
+>(
+    digits: &[Digit],
+) -> usize {
+    (digits.len() - 1)
+        .saturating_mul(DIGIT_BITNESS)
+        .saturating_add(digits[digits.len() - 1].bit_length())
+}
+
+pub(super) fn expect_allocated<T>(result: Result<T, CollectionAllocErr>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(CollectionAllocErr::AllocErr { layout }) => {
+            handle_alloc_error(layout)
+        }
+        Err(CollectionAllocErr::CapacityOverflow) => {
+            panic!("capacity overflow")
+        }
+    }
+}
+
+fn try_digits_with_capacity<Digit>(
+    capacity: usize,
+) -> Result<Digits<Digit>, CollectionAllocErr> {
+    let mut result = Digits::<Digit>::new();
+    result.try_reserve_exact(capacity)?;
+    Ok(result)
+}
+
+fn try_digits_from_slice<Digit: Copy>(
+    digits: &[Digit],
+) -> Result<Digits<Digit>, CollectionAllocErr> {
+    let mut result = try_digits_with_capacity(digits.len())?;
+    result.extend_from_slice(digits);
+    Ok(result)
+}
+
+/// Digits of an intermediate value,
+/// wiped on drop when `zeroize` feature is enabled.
+pub(super) struct ScratchDigits<Digit>(Digits<Digit>);
+
+impl<Digit> Deref for ScratchDigits<Digit> {
+    type Target = Digits<Digit>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<Digit> DerefMut for ScratchDigits<Digit> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<Digit> Drop for ScratchDigits<Digit> {
+    fn drop(&mut self) {
+        wipe_digits(&mut self.0);
+    }
+}
+
+impl<Digit> From<Digits<Digit>> for ScratchDigits<Digit> {
+    fn from(digits: Digits<Digit>) -> Self {
+        Self(digits)
+    }
+}
+
+/// Reserves capacity for at least `additional` more digits,
+/// with `zeroize` feature enabled the buffer being replaced is wiped.
+pub(super) fn reserve_digits<Digit: Copy>(
+    digits: &mut Digits<Digit>,
+    additional: usize,
+) {
+    if cfg!(feature = "zeroize")
+        && digits.capacity() - digits.len() < additional
+    {
+        let mut result = Digits::with_capacity(digits.len() + additional);
+        result.extend_from_slice(digits);
+        wipe_digits(digits);
+        *digits = result;
+    } else {
+        digits.reserve(additional);
+    }
+}
+
+/// Zeroes the whole buffer including spare capacity
+/// which can hold digits of previous values.
+#[cfg(feature = "zeroize")]
+pub(super) fn wipe_digits<Digit>(digits: &mut Digits<Digit>) {
+    use std::mem::MaybeUninit;
+    use zeroize::Zeroize;
+
+    unsafe {
+        std::slice::from_raw_parts_mut(
+            digits.as_mut_ptr() as *mut MaybeUninit<Digit>,
+            digits.capacity(),
+        )
+    }
+    .zeroize();
+}
+
+#[cfg(not(feature = "zeroize"))]
+#[inline(always)]
+pub(super) fn wipe_digits<Digit>(_digits: &mut Digits<Digit>) {}
+
+fn try_zeroed_digits<Digit: Clone + Zero>(
+    digits_count: usize,
+) -> Result<Digits<Digit>, CollectionAllocErr> {
+    let mut result = try_digits_with_capacity(digits_count)?;
+    result.resize(digits_count, Digit::zero());
+    Ok(result)
+}
+
+pub(super) fn trim_leading_zeros<Digit>(digits: &mut Digits<Digit>)
 where
     for<'a> &'a Digit: Zeroable,
 {
@@ -2916,57 +3556,33 @@ where
 pub(super) trait DigitsFromNonZeroValue<Source>: Sized {
     fn digits_from_non_zero_value<const DIGIT_BITNESS: usize>(
         value: Source,
-    ) -> Vec<Self>;
+    ) -> Digits<Self>;
 }
 
 impl<
         Digit: BitAnd<Output = Digit>
             + Copy
             + DigitMask
-            + HasSignBit
-            + Oppose
-            + ShrAssign<usize>
-            + TryFrom<Source>,
-        Source: BitAnd<Output = Source>
-            + Copy
-            + DigitMask
-            + HasSignBit
-            + Oppose
             + ShrAssign<usize>
-            + TryFrom<OppositionOf<Source>>,
+            + TryFrom<UnsignedAbsOf<Source>>,
+        Source: UnsignedAbs,
     > DigitsFromNonZeroValue<Source> for Digit
 where
     for<'a> &'a Digit: Zeroable,
-    for<'a> &'a Source: Zeroable,
-    OppositionOf<Source>: TryFrom<Source>,
+    for<'a> &'a UnsignedAbsOf<Source>: Zeroable,
+    UnsignedAbsOf<Source>: BitAnd<Output = UnsignedAbsOf<Source>>
+        + Copy
+        + DigitMask
+        + ShrAssign<usize>,
 {
     fn digits_from_non_zero_value<const DIGIT_BITNESS: usize>(
         value: Source,
-    ) -> Vec<Self> {
-        if size_of::<Source>() < size_of::<Self>()
-            || (size_of::<Source>() == size_of::<Self>()
-                && is_signed::<Source>()
-                && is_unsigned::<Self>())
-        {
-            let mut value = if is_signed::<Source>() {
-                let value = unsafe {
-                    OppositionOf::<Source>::try_from(value).unwrap_unchecked()
-                };
-                unsafe {
-                    Self::try_from(
-                        Source::try_from(if value.is_negative() {
-                            -value
-                        } else {
-                            value
-                        })
-                        .unwrap_unchecked(),
-                    )
-                    .unwrap_unchecked()
-                }
-            } else {
-                unsafe { Self::try_from(value).unwrap_unchecked() }
-            };
-            let mut digits = Vec::<Self>::new();
+    ) -> Digits<Self> {
+        let value = value.unsigned_abs();
+        if size_of::<UnsignedAbsOf<Source>>() <= size_of::<Self>() {
+            let mut value =
+                unsafe { Self::try_from(value).unwrap_unchecked() };
+            let mut digits = Digits::<Self>::new();
             let digit_mask = Self::digit_mask(DIGIT_BITNESS);
             while !value.is_zero() {
                 digits.push(value & digit_mask);
@@ -2974,20 +3590,10 @@ where
             }
             digits
         } else {
-            let mut value = if is_signed::<Source>() {
-                let value = unsafe {
-                    OppositionOf::<Source>::try_from(value).unwrap_unchecked()
-                };
-                if value.is_negative() {
-                    unsafe { Source::try_from(-value).unwrap_unchecked() }
-                } else {
-                    unsafe { Source::try_from(value).unwrap_unchecked() }
-                }
-            } else {
-                value
-            };
-            let mut digits = Vec::<Self>::new();
-            let digit_mask = Source::digit_mask(DIGIT_BITNESS);
+            let mut value = value;
+            let mut digits = Digits::<Self>::new();
+            let digit_mask =
+                UnsignedAbsOf::<Source>::digit_mask(DIGIT_BITNESS);
             while !value.is_zero() {
                 digits.push(unsafe {
                     Self::try_from(value & digit_mask).unwrap_unchecked()
@@ -1,8 +1,8 @@
+use std::cmp::Ordering;
 use std::convert::TryFrom;
 use std::fmt::{Display, Formatter};
-use std::ops::{Div, DivAssign};
 
-use traiter::numbers::{RemEuclid, Signed, Zeroable};
+use traiter::numbers::{Signed, Zeroable};
 
 use super::constants::MAX_REPRESENTABLE_BASE;
 use super::digits::BaseFromBinaryDigits;
@@ -14,38 +14,39 @@ where
     Self: ToBaseString,
 {
     fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
-        formatter.write_str(&self.to_base_string(10))
+        let string = self.to_base_string(10);
+        let (is_non_negative, digits) = split_sign(&string);
+        formatter.pad_integral(is_non_negative, "", digits)
     }
 }
 
-trait ToBaseString {
+pub trait ToBaseString {
     const DIGIT_VALUES_ASCII_CODES: [char; MAX_REPRESENTABLE_BASE as usize] = [
         '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd',
         'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r',
         's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
     ];
 
-    fn to_base_string(&self, base: usize) -> String;
+    fn to_base_string(&self, base: usize) -> String {
+        self.to_base_string_with_symbols(
+            &Self::DIGIT_VALUES_ASCII_CODES[..base],
+        )
+    }
+
+    fn to_base_string_with_symbols(&self, symbols: &[char]) -> String;
 }
 
 impl<
-        Digit: Copy
-            + Div<Output = Digit>
-            + DivAssign
-            + BaseFromBinaryDigits<Digit>
-            + RemEuclid<Output = Digit>
-            + TryFrom<usize>,
+        Digit: Copy + BaseFromBinaryDigits<Digit>,
         const DIGIT_BITNESS: usize,
     > ToBaseString for BigInt<Digit, DIGIT_BITNESS>
 where
     for<'a> &'a Digit: Zeroable,
     usize: TryFrom<Digit>,
 {
-    fn to_base_string(&self, base: usize) -> String {
-        let shift = if (1usize << DIGIT_BITNESS)
-            >= (MAX_REPRESENTABLE_BASE as usize)
-            || base < (1usize << DIGIT_BITNESS)
-        {
+    fn to_base_string_with_symbols(&self, symbols: &[char]) -> String {
+        let base = symbols.len();
+        let shift = if base <= (1usize << DIGIT_BITNESS) {
             unsafe { floor_log(1 << DIGIT_BITNESS, base).unwrap_unchecked() }
         } else {
             1usize
@@ -67,30 +68,23 @@ where
             .unwrap_or(0usize)
             + 1;
         let mut characters: String = String::with_capacity(characters_count);
-        let target_base = unsafe { Digit::try_from(base).unwrap_unchecked() };
-        for &(mut remainder) in digits.iter().take(digits.len() - 1) {
+        for &digit in digits.iter().take(digits.len() - 1) {
+            let mut remainder =
+                unsafe { usize::try_from(digit).unwrap_unchecked() };
             for _ in 0..shift {
-                characters.push(
-                    Self::DIGIT_VALUES_ASCII_CODES[unsafe {
-                        usize::try_from(remainder.rem_euclid(target_base))
-                            .unwrap_unchecked()
-                    }],
-                );
-                remainder /= target_base;
+                characters.push(symbols[remainder % base]);
+                remainder /= base;
             }
         }
-        let mut remainder = digits[digits.len() - 1];
-        while !remainder.is_zero() {
-            characters.push(
-                Self::DIGIT_VALUES_ASCII_CODES[unsafe {
-                    usize::try_from(remainder.rem_euclid(target_base))
-                        .unwrap_unchecked()
-                }],
-            );
-            remainder /= target_base;
+        let mut remainder = unsafe {
+            usize::try_from(digits[digits.len() - 1]).unwrap_unchecked()
+        };
+        while remainder != 0 {
+            characters.push(symbols[remainder % base]);
+            remainder /= base;
         }
         if self.is_zero() {
-            characters.push('0');
+            characters.push(symbols[0]);
         } else if self.is_negative() {
             characters.push('-');
         }
@@ -98,6 +92,93 @@ where
     }
 }
 
+pub(crate) fn group_digits(
+    digits: &str,
+    separator: char,
+    group_size: usize,
+) -> String {
+    let digits_count = digits.chars().count();
+    let mut result = String::with_capacity(
+        digits.len() + (digits_count.saturating_sub(1) / group_size),
+    );
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits_count - index) % group_size == 0 {
+            result.push(separator);
+        }
+        result.push(digit);
+    }
+    result
+}
+
+pub(super) fn split_sign(string: &str) -> (bool, &str) {
+    match string.strip_prefix('-') {
+        Some(digits) => (false, digits),
+        None => (true, string),
+    }
+}
+
+pub(super) fn to_exponential_string(
+    decimal_digits: &str,
+    precision: Option<usize>,
+    exponent_marker: char,
+) -> String {
+    let mut exponent = decimal_digits.len() - 1;
+    let mut significand_digits = decimal_digits.as_bytes().to_vec();
+    match precision {
+        Some(precision) => {
+            if significand_digits.len() > precision + 1 {
+                let rest = significand_digits.split_off(precision + 1);
+                let is_rounding_up = match rest[0].cmp(&b'5') {
+                    Ordering::Greater => true,
+                    Ordering::Less => false,
+                    Ordering::Equal => {
+                        rest[1..].iter().any(|&digit| digit != b'0')
+                            || (significand_digits[precision] - b'0') % 2 == 1
+                    }
+                };
+                if is_rounding_up {
+                    let mut position = precision + 1;
+                    while position > 0
+                        && significand_digits[position - 1] == b'9'
+                    {
+                        significand_digits[position - 1] = b'0';
+                        position -= 1;
+                    }
+                    if position == 0 {
+                        significand_digits.insert(0, b'1');
+                        significand_digits.pop();
+                        exponent += 1;
+                    } else {
+                        significand_digits[position - 1] += 1;
+                    }
+                }
+            } else {
+                significand_digits.resize(precision + 1, b'0');
+            }
+        }
+        None => {
+            while significand_digits.len() > 1
+                && significand_digits[significand_digits.len() - 1] == b'0'
+            {
+                significand_digits.pop();
+            }
+        }
+    }
+    let mut result = String::with_capacity(significand_digits.len() + 22);
+    result.push(char::from(significand_digits[0]));
+    if significand_digits.len() > 1 {
+        result.push('.');
+        result.extend(
+            significand_digits[1..]
+                .iter()
+                .map(|&digit| char::from(digit)),
+        );
+    }
+    result.push(exponent_marker);
+    result.push_str(&exponent.to_string());
+    result
+}
+
 const fn floor_log(value: usize, base: usize) -> Option<usize> {
     if value == 0usize {
         None
@@ -9,14 +9,14 @@ impl<Digit: CheckedDivComponents, const DIGIT_BITNESS: usize> DivAssign
     for BigInt<Digit, DIGIT_BITNESS>
 {
     fn div_assign(&mut self, divisor: Self) {
-        (self.sign, self.digits) =
-            Digit::checked_div_components::<DIGIT_BITNESS>(
-                self.sign,
-                &self.digits,
-                divisor.sign,
-                &divisor.digits,
-            )
-            .expect(UNDEFINED_DIVISION_ERROR_MESSAGE);
+        let (sign, digits) = Digit::checked_div_components::<DIGIT_BITNESS>(
+            self.sign,
+            &self.digits,
+            divisor.sign,
+            &divisor.digits,
+        )
+        .expect(UNDEFINED_DIVISION_ERROR_MESSAGE);
+        *self = Self { sign, digits };
     }
 }
 
@@ -24,13 +24,13 @@ impl<Digit: CheckedDivComponents, const DIGIT_BITNESS: usize> DivAssign<&Self>
     for BigInt<Digit, DIGIT_BITNESS>
 {
     fn div_assign(&mut self, divisor: &Self) {
-        (self.sign, self.digits) =
-            Digit::checked_div_components::<DIGIT_BITNESS>(
-                self.sign,
-                &self.digits,
-                divisor.sign,
-                &divisor.digits,
-            )
-            .expect(UNDEFINED_DIVISION_ERROR_MESSAGE);
+        let (sign, digits) = Digit::checked_div_components::<DIGIT_BITNESS>(
+            self.sign,
+            &self.digits,
+            divisor.sign,
+            &divisor.digits,
+        )
+        .expect(UNDEFINED_DIVISION_ERROR_MESSAGE);
+        *self = Self { sign, digits };
     }
 }
@@ -0,0 +1,32 @@
+use super::contracts::is_valid_digit_bitness;
+use super::digits::BinaryBaseFromBinaryDigits;
+use super::types::BigInt;
+use crate::traits::HasSignBit;
+
+impl<Digit: HasSignBit, const DIGIT_BITNESS: usize>
+    BigInt<Digit, DIGIT_BITNESS>
+{
+    pub fn from_big_int<
+        SourceDigit: HasSignBit,
+        const SOURCE_DIGIT_BITNESS: usize,
+    >(
+        value: &BigInt<SourceDigit, SOURCE_DIGIT_BITNESS>,
+    ) -> Self
+    where
+        Digit: BinaryBaseFromBinaryDigits<SourceDigit>,
+    {
+        debug_assert!(is_valid_digit_bitness::<Digit, DIGIT_BITNESS>());
+        debug_assert!(is_valid_digit_bitness::<
+            SourceDigit,
+            SOURCE_DIGIT_BITNESS,
+        >());
+        Self {
+            sign: value.sign,
+            digits: Digit::binary_base_from_binary_digits(
+                &value.digits,
+                SOURCE_DIGIT_BITNESS,
+                DIGIT_BITNESS,
+            ),
+        }
+    }
+}
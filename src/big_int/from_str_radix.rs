@@ -22,3 +22,43 @@ where
         }
     }
 }
+
+impl<Digit, const DIGIT_BITNESS: usize> BigInt<Digit, DIGIT_BITNESS>
+where
+    Self: TryFromString,
+{
+    pub fn from_str_radix_within(
+        string: &str,
+        radix: u32,
+        max_bit_length: usize,
+    ) -> Result<Self, TryFromStringError> {
+        if radix != 0
+            && (radix < u32::from(MIN_REPRESENTABLE_BASE)
+                || radix > u32::from(MAX_REPRESENTABLE_BASE))
+        {
+            Err(TryFromStringError::BaseOutOfBounds(radix))
+        } else {
+            Self::try_from_string_within(string, radix as u8, max_bit_length)
+        }
+    }
+
+    pub fn from_str_radix_with_separator(
+        string: &str,
+        radix: u32,
+        separator: char,
+    ) -> Result<Self, TryFromStringError> {
+        if radix != 0
+            && (radix < u32::from(MIN_REPRESENTABLE_BASE)
+                || radix > u32::from(MAX_REPRESENTABLE_BASE))
+        {
+            Err(TryFromStringError::BaseOutOfBounds(radix))
+        } else {
+            Self::try_from_separated_string_within(
+                string,
+                radix as u8,
+                separator,
+                usize::MAX,
+            )
+        }
+    }
+}
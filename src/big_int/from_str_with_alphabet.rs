@@ -0,0 +1,66 @@
+use std::str::Chars;
+
+use traiter::numbers::{One, Zeroable};
+
+use super::digits::{
+    to_digits_sign, trim_leading_zeros, BinaryBaseFromDigits, ScratchDigits,
+};
+use super::types::{Alphabet, BigInt, Digits, Sign, TryFromStringError};
+
+impl<Digit: BinaryBaseFromDigits<u8>, const DIGIT_BITNESS: usize>
+    BigInt<Digit, DIGIT_BITNESS>
+where
+    for<'a> &'a Digit: Zeroable,
+{
+    pub fn from_str_with_alphabet(
+        string: &str,
+        alphabet: &Alphabet,
+    ) -> Result<Self, TryFromStringError> {
+        let mut characters = string.chars();
+        let sign = match characters.clone().next() {
+            Some('-') => {
+                characters.next();
+                -Sign::one()
+            }
+            Some('+') => {
+                characters.next();
+                Sign::one()
+            }
+            _ => Sign::one(),
+        };
+        let digits = ScratchDigits::from(parse_symbols(characters, alphabet)?);
+        let digits = Digit::try_binary_base_from_digits::<DIGIT_BITNESS>(
+            &digits,
+            alphabet.base(),
+        )
+        .map_err(|_| TryFromStringError::OutOfMemory)?;
+        Ok(Self {
+            sign: sign * to_digits_sign(&digits),
+            digits,
+        })
+    }
+}
+
+fn parse_symbols(
+    characters: Chars,
+    alphabet: &Alphabet,
+) -> Result<Digits<u8>, TryFromStringError> {
+    let mut result = Digits::<u8>::new();
+    result
+        .try_reserve_exact(characters.as_str().len())
+        .map_err(|_| TryFromStringError::OutOfMemory)?;
+    for character in characters {
+        result.push(
+            alphabet
+                .to_value(character)
+                .ok_or(TryFromStringError::InvalidSymbol(character))?,
+        );
+    }
+    if result.is_empty() {
+        Err(TryFromStringError::NoDigits)
+    } else {
+        result.reverse();
+        trim_leading_zeros(&mut result);
+        Ok(result)
+    }
+}
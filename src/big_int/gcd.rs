@@ -9,8 +9,10 @@ impl<Digit: GcdDigits, const DIGIT_BITNESS: usize> Gcd
     type Output = Self;
 
     fn gcd(self, other: Self) -> Self::Output {
-        let (sign, digits) =
-            Digit::gcd_digits::<DIGIT_BITNESS>(self.digits, other.digits);
+        let (sign, digits) = Digit::gcd_digits::<DIGIT_BITNESS>(
+            self.into_digits(),
+            other.into_digits(),
+        );
         Self::Output { sign, digits }
     }
 }
@@ -22,7 +24,7 @@ impl<Digit: Clone + GcdDigits, const DIGIT_BITNESS: usize> Gcd<&Self>
 
     fn gcd(self, other: &Self) -> Self::Output {
         let (sign, digits) = Digit::gcd_digits::<DIGIT_BITNESS>(
-            self.digits,
+            self.into_digits(),
             other.digits.clone(),
         );
         Self::Output { sign, digits }
@@ -37,7 +39,7 @@ impl<Digit: Clone + GcdDigits, const DIGIT_BITNESS: usize>
     fn gcd(self, other: BigInt<Digit, DIGIT_BITNESS>) -> Self::Output {
         let (sign, digits) = Digit::gcd_digits::<DIGIT_BITNESS>(
             self.digits.clone(),
-            other.digits,
+            other.into_digits(),
         );
         Self::Output { sign, digits }
     }
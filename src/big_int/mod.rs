@@ -1,12 +1,21 @@
+pub(crate) use self::checked_pow::UncheckedPow;
 pub(crate) use self::constants::{
     MAX_REPRESENTABLE_BASE, MIN_REPRESENTABLE_BASE,
 };
 pub use self::contracts::is_valid_digit_bitness;
-pub use self::types::{BigInt, ShlError, ShrError, TryFromFloatError};
+pub(crate) use self::digits::MaybeReduceDigits;
+pub(crate) use self::display::{group_digits, ToBaseString};
+pub use self::types::{
+    Alphabet, AlphabetError, BigInt, CheckedPowRemEuclidError,
+    OutOfMemoryError, ShlError, ShrError, ToStringError, TryFromDigitsError,
+    TryFromFloatError, TryFromStringError, TryMulError, TryPowError,
+};
 
 mod abs;
 mod add;
 mod add_assign;
+mod add_mul_assign;
+mod binary;
 mod bit_and;
 mod bit_and_assign;
 mod bit_length;
@@ -18,6 +27,7 @@ mod checked_div;
 mod checked_div_euclid;
 mod checked_div_rem;
 mod checked_div_rem_euclid;
+mod checked_div_rounded;
 mod checked_pow;
 mod checked_pow_rem_euclid;
 mod checked_rem;
@@ -35,35 +45,56 @@ mod div_euclid;
 mod div_rem;
 mod div_rem_euclid;
 mod from;
+mod from_big_int;
 mod from_bytes;
 mod from_str_radix;
+mod from_str_with_alphabet;
 mod gcd;
 mod is_power_of_two;
+mod lower_exp;
+mod lower_hex;
 mod mul;
 mod mul_assign;
 mod neg;
 mod not;
+mod octal;
 mod one;
 mod ord;
+#[cfg(feature = "rayon")]
+mod par_product;
 mod parity;
 mod partial_eq;
 mod partial_ord;
 mod pow;
+mod product;
 mod rem;
 mod rem_assign;
 mod rem_euclid;
 mod shl;
 mod shr;
+mod sign_and_digits;
 mod signed;
 mod sub;
 mod sub_assign;
+mod sub_mul_assign;
+mod sum;
 mod to_bytes;
+mod to_str_radix;
+mod to_str_with_alphabet;
+mod try_add;
 mod try_div_as_float;
 mod try_from;
 mod try_from_string;
 mod try_into;
+mod try_mul;
+mod try_pow;
+mod try_sub;
 mod types;
 mod unchecked_to_int;
 mod unitary;
+mod upper_exp;
+mod upper_hex;
 mod zero;
 mod zeroable;
+#[cfg(feature = "zeroize")]
+mod zeroize;
@@ -1,27 +1,27 @@
 use std::ops::MulAssign;
 
-use super::digits::MultiplyDigits;
+use super::digits::MultiplyDigitsInPlace;
 use super::types::BigInt;
 
-impl<Digit: MultiplyDigits, const DIGIT_BITNESS: usize> MulAssign
+impl<Digit: MultiplyDigitsInPlace, const DIGIT_BITNESS: usize> MulAssign
     for BigInt<Digit, DIGIT_BITNESS>
 {
     fn mul_assign(&mut self, other: Self) {
         self.sign *= other.sign;
-        self.digits = Digit::multiply_digits::<DIGIT_BITNESS>(
-            &self.digits,
+        Digit::multiply_digits_in_place::<DIGIT_BITNESS>(
+            &mut self.digits,
             &other.digits,
         );
     }
 }
 
-impl<Digit: MultiplyDigits, const DIGIT_BITNESS: usize> MulAssign<&Self>
+impl<Digit: MultiplyDigitsInPlace, const DIGIT_BITNESS: usize> MulAssign<&Self>
     for BigInt<Digit, DIGIT_BITNESS>
 {
     fn mul_assign(&mut self, other: &Self) {
         self.sign *= other.sign;
-        self.digits = Digit::multiply_digits::<DIGIT_BITNESS>(
-            &self.digits,
+        Digit::multiply_digits_in_place::<DIGIT_BITNESS>(
+            &mut self.digits,
             &other.digits,
         );
     }
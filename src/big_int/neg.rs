@@ -18,10 +18,8 @@ impl<Digit: Clone, const DIGIT_BITNESS: usize> Neg
 impl<Digit, const DIGIT_BITNESS: usize> Neg for BigInt<Digit, DIGIT_BITNESS> {
     type Output = Self;
 
-    fn neg(self) -> Self::Output {
-        Self::Output {
-            sign: -self.sign,
-            digits: self.digits,
-        }
+    fn neg(mut self) -> Self::Output {
+        self.sign = -self.sign;
+        self
     }
 }
@@ -0,0 +1,15 @@
+use std::fmt::{Formatter, Octal};
+
+use super::display::{split_sign, ToBaseString};
+use super::types::BigInt;
+
+impl<Digit, const DIGIT_BITNESS: usize> Octal for BigInt<Digit, DIGIT_BITNESS>
+where
+    Self: ToBaseString,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        let string = self.to_base_string(8);
+        let (is_non_negative, digits) = split_sign(&string);
+        formatter.pad_integral(is_non_negative, "0o", digits)
+    }
+}
@@ -1,3 +1,4 @@
+use smallvec::smallvec;
 use traiter::numbers::One;
 
 use super::types::{BigInt, Sign};
@@ -8,7 +9,7 @@ impl<Digit: One, const DIGIT_BITNESS: usize> One
     fn one() -> Self {
         Self {
             sign: Sign::one(),
-            digits: vec![Digit::one()],
+            digits: smallvec![Digit::one()],
         }
     }
 }
@@ -0,0 +1,36 @@
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use traiter::numbers::One;
+
+use super::digits::MultiplyDigits;
+use super::types::BigInt;
+
+impl<
+        Digit: Clone + MultiplyDigits + One + Send + Sync,
+        const DIGIT_BITNESS: usize,
+    > BigInt<Digit, DIGIT_BITNESS>
+{
+    pub fn par_product<Values: IntoParallelIterator<Item = Self>>(
+        values: Values,
+    ) -> Self {
+        product_tree(&values.into_par_iter().collect::<Vec<_>>())
+    }
+}
+
+fn product_tree<
+    Digit: Clone + MultiplyDigits + One + Send + Sync,
+    const DIGIT_BITNESS: usize,
+>(
+    values: &[BigInt<Digit, DIGIT_BITNESS>],
+) -> BigInt<Digit, DIGIT_BITNESS> {
+    match values.len() {
+        0 => BigInt::one(),
+        1 => values[0].clone(),
+        2 => &values[0] * &values[1],
+        size => {
+            let (left, right) = values.split_at(size / 2);
+            let (left_product, right_product) =
+                rayon::join(|| product_tree(left), || product_tree(right));
+            left_product * right_product
+        }
+    }
+}
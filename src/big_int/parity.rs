@@ -21,10 +21,12 @@ where
     Digit: Parity,
 {
     fn is_even(self) -> bool {
-        unsafe { self.digits.into_iter().next().unwrap_unchecked() }.is_even()
+        unsafe { self.into_digits().into_iter().next().unwrap_unchecked() }
+            .is_even()
     }
 
     fn is_odd(self) -> bool {
-        unsafe { self.digits.into_iter().next().unwrap_unchecked() }.is_odd()
+        unsafe { self.into_digits().into_iter().next().unwrap_unchecked() }
+            .is_odd()
     }
 }
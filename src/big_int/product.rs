@@ -0,0 +1,24 @@
+use std::iter::Product;
+
+use traiter::numbers::One;
+
+use crate::traits::BalancedProduct;
+
+use super::digits::MultiplyDigits;
+use super::types::BigInt;
+
+impl<Digit: MultiplyDigits + One, const DIGIT_BITNESS: usize> Product
+    for BigInt<Digit, DIGIT_BITNESS>
+{
+    fn product<Values: Iterator<Item = Self>>(values: Values) -> Self {
+        Self::balanced_product(values)
+    }
+}
+
+impl<'a, Digit: Clone + MultiplyDigits + One, const DIGIT_BITNESS: usize>
+    Product<&'a Self> for BigInt<Digit, DIGIT_BITNESS>
+{
+    fn product<Values: Iterator<Item = &'a Self>>(values: Values) -> Self {
+        Self::balanced_product(values.cloned())
+    }
+}
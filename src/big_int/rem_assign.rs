@@ -9,14 +9,14 @@ impl<Digit: CheckedRemComponents, const DIGIT_BITNESS: usize> RemAssign
     for BigInt<Digit, DIGIT_BITNESS>
 {
     fn rem_assign(&mut self, divisor: Self) {
-        (self.sign, self.digits) =
-            Digit::checked_rem_components::<DIGIT_BITNESS>(
-                self.sign,
-                &self.digits,
-                divisor.sign,
-                &divisor.digits,
-            )
-            .expect(UNDEFINED_DIVISION_ERROR_MESSAGE);
+        let (sign, digits) = Digit::checked_rem_components::<DIGIT_BITNESS>(
+            self.sign,
+            &self.digits,
+            divisor.sign,
+            &divisor.digits,
+        )
+        .expect(UNDEFINED_DIVISION_ERROR_MESSAGE);
+        *self = Self { sign, digits };
     }
 }
 
@@ -24,13 +24,13 @@ impl<Digit: CheckedRemComponents, const DIGIT_BITNESS: usize> RemAssign<&Self>
     for BigInt<Digit, DIGIT_BITNESS>
 {
     fn rem_assign(&mut self, divisor: &Self) {
-        (self.sign, self.digits) =
-            Digit::checked_rem_components::<DIGIT_BITNESS>(
-                self.sign,
-                &self.digits,
-                divisor.sign,
-                &divisor.digits,
-            )
-            .expect(UNDEFINED_DIVISION_ERROR_MESSAGE);
+        let (sign, digits) = Digit::checked_rem_components::<DIGIT_BITNESS>(
+            self.sign,
+            &self.digits,
+            divisor.sign,
+            &divisor.digits,
+        )
+        .expect(UNDEFINED_DIVISION_ERROR_MESSAGE);
+        *self = Self { sign, digits };
     }
 }
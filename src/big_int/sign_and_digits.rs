@@ -0,0 +1,70 @@
+use traiter::numbers::{One, Sign, Signed, Zero, Zeroable};
+
+use crate::traits::HasSignBit;
+
+use super::contracts::is_valid_digit_bitness;
+use super::digits::DigitMask;
+use super::types::{self, BigInt, Digits, TryFromDigitsError};
+
+impl<Digit, const DIGIT_BITNESS: usize> BigInt<Digit, DIGIT_BITNESS> {
+    pub fn into_sign_and_digits(self) -> (Sign, Vec<Digit>) {
+        (self.sign.sign(), self.into_digits().into_vec())
+    }
+
+    /// # Safety
+    /// `digits` should be non-empty, without leading zeros,
+    /// with every digit less than `2^DIGIT_BITNESS`,
+    /// and `sign` should be zero if and only if `digits` represent zero.
+    pub unsafe fn from_sign_and_digits_unchecked(
+        sign: Sign,
+        digits: Vec<Digit>,
+    ) -> Self {
+        Self {
+            sign: to_raw_sign(sign),
+            digits: Digits::from_vec(digits),
+        }
+    }
+}
+
+impl<
+        Digit: Copy + DigitMask + HasSignBit + PartialOrd,
+        const DIGIT_BITNESS: usize,
+    > BigInt<Digit, DIGIT_BITNESS>
+where
+    for<'a> &'a Digit: Zeroable,
+{
+    pub fn try_from_sign_and_digits(
+        sign: Sign,
+        digits: Vec<Digit>,
+    ) -> Result<Self, TryFromDigitsError> {
+        debug_assert!(is_valid_digit_bitness::<Digit, DIGIT_BITNESS>());
+        if digits.is_empty() {
+            return Err(TryFromDigitsError::NoDigits);
+        }
+        if digits.len() > 1 && digits[digits.len() - 1].is_zero() {
+            return Err(TryFromDigitsError::LeadingZeros);
+        }
+        let digit_mask = Digit::digit_mask(DIGIT_BITNESS);
+        if let Some(position) =
+            digits.iter().position(|&digit| digit > digit_mask)
+        {
+            return Err(TryFromDigitsError::DigitOutOfRange(position));
+        }
+        if (digits.len() == 1 && digits[0].is_zero()) != (sign == Sign::Zero) {
+            return Err(TryFromDigitsError::SignMismatch);
+        }
+        Ok(Self {
+            sign: to_raw_sign(sign),
+            digits: Digits::from_vec(digits),
+        })
+    }
+}
+
+#[inline]
+fn to_raw_sign(sign: Sign) -> types::Sign {
+    match sign {
+        Sign::Negative => -types::Sign::one(),
+        Sign::Positive => types::Sign::one(),
+        Sign::Zero => types::Sign::zero(),
+    }
+}
@@ -0,0 +1,28 @@
+use std::iter::Sum;
+
+use traiter::numbers::Zero;
+
+use super::digits::SumComponentsInPlace;
+use super::types::BigInt;
+
+impl<Digit: SumComponentsInPlace + Zero, const DIGIT_BITNESS: usize> Sum
+    for BigInt<Digit, DIGIT_BITNESS>
+{
+    fn sum<Values: Iterator<Item = Self>>(values: Values) -> Self {
+        values.fold(Self::zero(), |mut result, value| {
+            result += value;
+            result
+        })
+    }
+}
+
+impl<'a, Digit: SumComponentsInPlace + Zero, const DIGIT_BITNESS: usize>
+    Sum<&'a Self> for BigInt<Digit, DIGIT_BITNESS>
+{
+    fn sum<Values: Iterator<Item = &'a Self>>(values: Values) -> Self {
+        values.fold(Self::zero(), |mut result, value| {
+            result += value;
+            result
+        })
+    }
+}
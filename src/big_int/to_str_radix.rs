@@ -0,0 +1,36 @@
+use std::num::NonZeroUsize;
+
+use super::constants::{MAX_REPRESENTABLE_BASE, MIN_REPRESENTABLE_BASE};
+use super::display::{group_digits, split_sign, ToBaseString};
+use super::types::{BigInt, ToStringError};
+
+impl<Digit, const DIGIT_BITNESS: usize> BigInt<Digit, DIGIT_BITNESS>
+where
+    Self: ToBaseString,
+{
+    pub fn to_str_radix(&self, radix: u32) -> Result<String, ToStringError> {
+        if radix < u32::from(MIN_REPRESENTABLE_BASE)
+            || radix > u32::from(MAX_REPRESENTABLE_BASE)
+        {
+            Err(ToStringError::BaseOutOfBounds(radix))
+        } else {
+            Ok(self.to_base_string(radix as usize))
+        }
+    }
+
+    pub fn to_str_radix_grouped(
+        &self,
+        radix: u32,
+        separator: char,
+        group_size: NonZeroUsize,
+    ) -> Result<String, ToStringError> {
+        let string = self.to_str_radix(radix)?;
+        let (is_non_negative, digits) = split_sign(&string);
+        let digits = group_digits(digits, separator, group_size.get());
+        Ok(if is_non_negative {
+            digits
+        } else {
+            format!("-{digits}")
+        })
+    }
+}
@@ -0,0 +1,11 @@
+use super::display::ToBaseString;
+use super::types::{Alphabet, BigInt};
+
+impl<Digit, const DIGIT_BITNESS: usize> BigInt<Digit, DIGIT_BITNESS>
+where
+    Self: ToBaseString,
+{
+    pub fn to_str_with_alphabet(&self, alphabet: &Alphabet) -> String {
+        self.to_base_string_with_symbols(&alphabet.symbols)
+    }
+}
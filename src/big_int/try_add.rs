@@ -0,0 +1,17 @@
+use super::digits::SumComponents;
+use super::types::{BigInt, OutOfMemoryError};
+
+impl<Digit: SumComponents, const DIGIT_BITNESS: usize>
+    BigInt<Digit, DIGIT_BITNESS>
+{
+    pub fn try_add(&self, other: &Self) -> Result<Self, OutOfMemoryError> {
+        let (sign, digits) = Digit::try_sum_components::<DIGIT_BITNESS>(
+            self.sign,
+            &self.digits,
+            other.sign,
+            &other.digits,
+        )
+        .map_err(|_| OutOfMemoryError)?;
+        Ok(Self { sign, digits })
+    }
+}
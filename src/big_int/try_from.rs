@@ -1,8 +1,13 @@
 use std::convert::TryFrom;
+use std::ops::{Neg, Shl};
 
+#[cfg(feature = "half")]
+use half::{bf16, f16};
+use smallvec::smallvec;
 use traiter::numbers::{FractExp, LoadExp, One, Zero};
 
-use crate::traits::UncheckedToInt;
+use crate::binary128::Binary128;
+use crate::traits::{ToFloat, UncheckedToInt};
 
 use super::try_from_string::TryFromString;
 use super::types::{BigInt, Sign, TryFromFloatError, TryFromStringError};
@@ -10,12 +15,12 @@ use super::types::{BigInt, Sign, TryFromFloatError, TryFromStringError};
 macro_rules! try_from_float_impl {
     ($($float:ty)*) => ($(
         impl<
-                Digit: Copy + Zero,
+                Digit: Copy + ToFloat<$float> + Zero,
                 const DIGIT_BITNESS: usize,
             > TryFrom<$float> for BigInt<Digit, DIGIT_BITNESS>
         where
             Self: Zero,
-            $float: From<Digit> + UncheckedToInt<Digit>,
+            $float: UncheckedToInt<Digit>,
         {
             type Error = TryFromFloatError;
 
@@ -36,7 +41,7 @@ macro_rules! try_from_float_impl {
                         value = -value;
                     }
                     let (fraction, exponent) = value.fract_exp();
-                    let mut digits = vec![
+                    let mut digits = smallvec![
                         Digit::zero();
                         ((exponent as usize) - 1) / DIGIT_BITNESS + 1
                     ];
@@ -46,7 +51,7 @@ macro_rules! try_from_float_impl {
                         let digit =
                             unsafe { <$float>::unchecked_to_int(fraction) };
                         digits[index] = digit;
-                        fraction -= <$float>::from(digit);
+                        fraction -= digit.to_float();
                         fraction = fraction.load_exp(DIGIT_BITNESS as i32);
                     }
                     Ok(Self { sign, digits })
@@ -58,6 +63,62 @@ macro_rules! try_from_float_impl {
 
 try_from_float_impl!(f32 f64);
 
+macro_rules! try_from_float_bits_impl {
+    ($($float:ty => $bits:ty)*) => ($(
+        impl<Digit, const DIGIT_BITNESS: usize> TryFrom<$float>
+            for BigInt<Digit, DIGIT_BITNESS>
+        where
+            Self: From<$bits>
+                + Neg<Output = Self>
+                + Shl<usize, Output = Self>
+                + Zero,
+        {
+            type Error = TryFromFloatError;
+
+            fn try_from(value: $float) -> Result<Self, Self::Error> {
+                const MANTISSA_DIGITS: isize =
+                    <$float>::MANTISSA_DIGITS as isize;
+                const MIN_EXP: isize = <$float>::MIN_EXP as isize;
+                if value.is_infinite() {
+                    return Err(TryFromFloatError::Infinity);
+                } else if value.is_nan() {
+                    return Err(TryFromFloatError::NaN);
+                }
+                let bits = value.to_bits();
+                let biased_exponent = ((bits & !(1 << (<$bits>::BITS - 1)))
+                    >> (MANTISSA_DIGITS - 1))
+                    as isize;
+                if biased_exponent == 0 {
+                    return Ok(Self::zero());
+                }
+                let significand = (bits
+                    & ((1 << (MANTISSA_DIGITS - 1)) - 1))
+                    | (1 << (MANTISSA_DIGITS - 1));
+                let exponent =
+                    biased_exponent - 1 + MIN_EXP - MANTISSA_DIGITS;
+                let modulus = if exponent < 0 {
+                    Self::from(
+                        significand
+                            .checked_shr(exponent.unsigned_abs() as u32)
+                            .unwrap_or(0),
+                    )
+                } else {
+                    Self::from(significand) << exponent.unsigned_abs()
+                };
+                Ok(if value.is_sign_negative() {
+                    -modulus
+                } else {
+                    modulus
+                })
+            }
+        }
+    )*)
+}
+
+try_from_float_bits_impl!(Binary128 => u128);
+#[cfg(feature = "half")]
+try_from_float_bits_impl!(bf16 => u16 f16 => u16);
+
 impl<Digit, const DIGIT_BITNESS: usize> TryFrom<&str>
     for BigInt<Digit, DIGIT_BITNESS>
 where
@@ -1,24 +1,49 @@
 use std::iter::Peekable;
 use std::str::Chars;
 
-use traiter::numbers::{One, Zeroable};
+use traiter::numbers::{BitLength, One, Zeroable};
 
 use crate::traits::HasSignBit;
 
 use super::constants::{MAX_REPRESENTABLE_BASE, MIN_REPRESENTABLE_BASE};
 use super::contracts::is_valid_digit_bitness;
 use super::digits::{
-    to_digits_sign, trim_leading_zeros, BinaryBaseFromDigits,
+    digits_bit_length, to_digits_sign, trim_leading_zeros,
+    BinaryBaseFromDigits, ScratchDigits,
 };
-use super::types::{BigInt, Sign, TryFromStringError};
+use super::types::{BigInt, Digits, Sign, TryFromStringError};
 
 pub trait TryFromString: Sized {
     fn try_from_string(
         string: &str,
         base: u8,
+    ) -> Result<Self, TryFromStringError> {
+        Self::try_from_string_within(string, base, usize::MAX)
+    }
+
+    fn try_from_string_within(
+        string: &str,
+        base: u8,
+        max_bit_length: usize,
+    ) -> Result<Self, TryFromStringError> {
+        Self::try_from_separated_string_within(
+            string,
+            base,
+            DEFAULT_SEPARATOR,
+            max_bit_length,
+        )
+    }
+
+    fn try_from_separated_string_within(
+        string: &str,
+        base: u8,
+        separator: char,
+        max_bit_length: usize,
     ) -> Result<Self, TryFromStringError>;
 }
 
+pub(super) const DEFAULT_SEPARATOR: char = '_';
+
 const ASCII_CODES_DIGIT_VALUES: [u8; 256] = [
     37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
     37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
@@ -37,15 +62,20 @@ const ASCII_CODES_DIGIT_VALUES: [u8; 256] = [
 ];
 
 impl<
-        Digit: BinaryBaseFromDigits<u8> + HasSignBit,
+        Digit: BinaryBaseFromDigits<u8>
+            + BitLength<Output = usize>
+            + Copy
+            + HasSignBit,
         const DIGIT_BITNESS: usize,
     > TryFromString for BigInt<Digit, DIGIT_BITNESS>
 where
     for<'a> &'a Digit: Zeroable,
 {
-    fn try_from_string(
+    fn try_from_separated_string_within(
         string: &str,
         mut base: u8,
+        separator: char,
+        max_bit_length: usize,
     ) -> Result<Self, TryFromStringError> {
         debug_assert!(is_valid_digit_bitness::<Digit, DIGIT_BITNESS>());
         debug_assert!(
@@ -58,19 +88,36 @@ where
         if base == 0 {
             base = guess_base(&mut characters);
         };
-        skip_prefix(&mut characters, base);
-        parse_digits(characters, base).map(|digits| {
-            let digits = Digit::binary_base_from_digits::<DIGIT_BITNESS>(
-                &digits,
-                base as usize,
-            );
-            Self {
+        let is_prefix_skipped = skip_prefix(&mut characters, base);
+        let digits = ScratchDigits::from(parse_digits(
+            characters,
+            base,
+            separator,
+            is_prefix_skipped,
+        )?);
+        if digits.len() > 1
+            && (digits.len() - 1).saturating_mul(base.ilog2() as usize)
+                >= max_bit_length
+        {
+            return Err(TryFromStringError::LimitExceeded);
+        }
+        let digits = Digit::try_binary_base_from_digits::<DIGIT_BITNESS>(
+            &digits,
+            base as usize,
+        )
+        .map_err(|_| TryFromStringError::OutOfMemory)?;
+        if digits_bit_length::<Digit, DIGIT_BITNESS>(&digits) > max_bit_length
+        {
+            Err(TryFromStringError::LimitExceeded)
+        } else {
+            Ok(Self {
                 sign: sign * to_digits_sign(&digits),
                 digits,
-            }
-        })
+            })
+        }
     }
 }
+
 #[inline]
 fn guess_base(characters: &mut Peekable<Chars>) -> u8 {
     if characters.peek() != Some(&'0') {
@@ -89,22 +136,45 @@ fn guess_base(characters: &mut Peekable<Chars>) -> u8 {
 fn parse_digits(
     characters: Peekable<Chars>,
     base: u8,
-) -> Result<Vec<u8>, TryFromStringError> {
+    separator: char,
+    mut is_separator_allowed: bool,
+) -> Result<Digits<u8>, TryFromStringError> {
     let mut result = {
         let (_, maybe_characters_count) = characters.size_hint();
         debug_assert!(maybe_characters_count.is_some());
         let characters_count =
             unsafe { maybe_characters_count.unwrap_unchecked() };
-        Vec::<u8>::with_capacity(characters_count)
+        let mut result = Digits::<u8>::new();
+        result
+            .try_reserve_exact(characters_count)
+            .map_err(|_| TryFromStringError::OutOfMemory)?;
+        result
     };
+    let mut is_separator_pending = false;
     for character in characters {
-        let digit = ASCII_CODES_DIGIT_VALUES[character as usize];
+        if character == separator {
+            if !is_separator_allowed {
+                return Err(TryFromStringError::InvalidDigit(character, base));
+            }
+            is_separator_allowed = false;
+            is_separator_pending = true;
+            continue;
+        }
+        let digit = if character.is_ascii() {
+            ASCII_CODES_DIGIT_VALUES[character as usize]
+        } else {
+            base
+        };
         if digit >= base {
             return Err(TryFromStringError::InvalidDigit(character, base));
         }
         result.push(digit);
+        is_separator_allowed = true;
+        is_separator_pending = false;
     }
-    if result.is_empty() {
+    if is_separator_pending {
+        Err(TryFromStringError::InvalidDigit(separator, base))
+    } else if result.is_empty() {
         Err(TryFromStringError::NoDigits)
     } else {
         result.reverse();
@@ -126,25 +196,18 @@ fn parse_sign(characters: &mut Peekable<Chars>) -> i8 {
     }
 }
 
-fn skip_prefix(characters: &mut Peekable<Chars>, base: u8) {
-    if characters.peek() == Some(&'0') {
-        match characters.clone().nth(1) {
-            Some('b' | 'B') => {
-                if base == 2 {
-                    characters.nth(1);
-                }
-            }
-            Some('o' | 'O') => {
-                if base == 8 {
-                    characters.nth(1);
-                }
-            }
-            Some('x' | 'X') => {
-                if base == 16 {
-                    characters.nth(1);
-                }
-            }
-            _ => {}
-        };
-    };
+fn skip_prefix(characters: &mut Peekable<Chars>, base: u8) -> bool {
+    if characters.peek() == Some(&'0')
+        && matches!(
+            (characters.clone().nth(1), base),
+            (Some('b' | 'B'), 2)
+                | (Some('o' | 'O'), 8)
+                | (Some('x' | 'X'), 16)
+        )
+    {
+        characters.nth(1);
+        true
+    } else {
+        false
+    }
 }
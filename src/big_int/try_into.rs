@@ -1,6 +1,11 @@
 use std::convert::TryFrom;
 
-use traiter::numbers::{LoadExp, Signed};
+#[cfg(feature = "half")]
+use half::{bf16, f16};
+use traiter::numbers::{LoadExp, One, Signed};
+
+use crate::binary128::Binary128;
+use crate::fraction::{Fraction, ToFloatError};
 
 use super::digits::{FractExpDigits, MaybeReduceDigits};
 use super::types::{
@@ -54,10 +59,60 @@ macro_rules! float_try_from_big_int_impl {
 
 float_try_from_big_int_impl!(f32 f64);
 
+macro_rules! float_bits_try_from_big_int_impl {
+    ($($float:ty)*) => ($(
+        impl<Digit, const DIGIT_BITNESS: usize>
+            TryFrom<BigInt<Digit, DIGIT_BITNESS>> for $float
+        where
+            BigInt<Digit, DIGIT_BITNESS>: One,
+            $float: TryFrom<
+                Fraction<BigInt<Digit, DIGIT_BITNESS>>,
+                Error = ToFloatError,
+            >,
+        {
+            type Error = TryIntoFloatError;
+
+            fn try_from(
+                value: BigInt<Digit, DIGIT_BITNESS>,
+            ) -> Result<Self, Self::Error> {
+                <$float as TryFrom<Fraction<BigInt<Digit, DIGIT_BITNESS>>>>::try_from(
+                    Fraction::from(value),
+                )
+                    .map_err(|_| TryIntoFloatError::TooLarge)
+            }
+        }
+
+        impl<Digit, const DIGIT_BITNESS: usize>
+            TryFrom<&BigInt<Digit, DIGIT_BITNESS>> for $float
+        where
+            BigInt<Digit, DIGIT_BITNESS>: Clone + One,
+            $float: TryFrom<
+                Fraction<BigInt<Digit, DIGIT_BITNESS>>,
+                Error = ToFloatError,
+            >,
+        {
+            type Error = TryIntoFloatError;
+
+            fn try_from(
+                value: &BigInt<Digit, DIGIT_BITNESS>,
+            ) -> Result<Self, Self::Error> {
+                <$float as TryFrom<Fraction<BigInt<Digit, DIGIT_BITNESS>>>>::try_from(
+                    Fraction::from(value.clone()),
+                )
+                    .map_err(|_| TryIntoFloatError::TooLarge)
+            }
+        }
+    )*)
+}
+
+float_bits_try_from_big_int_impl!(Binary128);
+#[cfg(feature = "half")]
+float_bits_try_from_big_int_impl!(bf16 f16);
+
 macro_rules! signed_integer_try_from_big_int_impl {
-    ($($integer:ty)*) => ($(
+    ($($integer:ty => $unsigned:ty)*) => ($(
         impl<
-                Digit: MaybeReduceDigits<$integer>,
+                Digit: MaybeReduceDigits<$unsigned>,
                 const DIGIT_BITNESS: usize,
             > TryFrom<BigInt<Digit, DIGIT_BITNESS>> for $integer
         where
@@ -68,20 +123,13 @@ macro_rules! signed_integer_try_from_big_int_impl {
             fn try_from(
                 value: BigInt<Digit, DIGIT_BITNESS>,
             ) -> Result<Self, Self::Error> {
-                let result =
-                    Digit::maybe_reduce_digits::<DIGIT_BITNESS>(&value.digits)
-                        .ok_or(TryIntoSignedIntegerError::TooLarge);
-                if value.is_negative() {
-                    result.map(|value| -value)
-                } else {
-                    result
-                }
+                <$integer>::try_from(&value)
             }
         }
 
         impl<
                 'a,
-                Digit: MaybeReduceDigits<$integer>,
+                Digit: MaybeReduceDigits<$unsigned>,
                 const DIGIT_BITNESS: usize,
             > TryFrom<&'a BigInt<Digit, DIGIT_BITNESS>> for $integer
         where
@@ -92,20 +140,28 @@ macro_rules! signed_integer_try_from_big_int_impl {
             fn try_from(
                 value: &'a BigInt<Digit, DIGIT_BITNESS>,
             ) -> Result<Self, Self::Error> {
-                let result =
+                let modulus =
                     Digit::maybe_reduce_digits::<DIGIT_BITNESS>(&value.digits)
-                        .ok_or(TryIntoSignedIntegerError::TooLarge);
+                        .ok_or(TryIntoSignedIntegerError::TooLarge)?;
                 if value.is_negative() {
-                    result.map(|value| -value)
+                    // the minimum has magnitude exceeding the maximum by one
+                    if modulus > <$integer>::MIN.unsigned_abs() {
+                        Err(TryIntoSignedIntegerError::TooLarge)
+                    } else {
+                        Ok((modulus as $integer).wrapping_neg())
+                    }
                 } else {
-                    result
+                    <$integer>::try_from(modulus)
+                        .map_err(|_| TryIntoSignedIntegerError::TooLarge)
                 }
             }
         }
     )*)
 }
 
-signed_integer_try_from_big_int_impl!(i8 i16 i32 i64 i128 isize);
+signed_integer_try_from_big_int_impl!(
+    i8 => u8 i16 => u16 i32 => u32 i64 => u64 i128 => u128 isize => usize
+);
 
 macro_rules! unsigned_integer_try_from_big_int_impl {
     ($($integer:ty)*) => ($(
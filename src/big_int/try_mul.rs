@@ -0,0 +1,52 @@
+use traiter::numbers::BitLength;
+
+use super::digits::{digits_bit_length, MultiplyDigits};
+use super::types::{BigInt, OutOfMemoryError, TryMulError};
+
+impl<Digit: MultiplyDigits, const DIGIT_BITNESS: usize>
+    BigInt<Digit, DIGIT_BITNESS>
+{
+    pub fn try_mul(&self, other: &Self) -> Result<Self, OutOfMemoryError> {
+        Ok(Self {
+            sign: self.sign * other.sign,
+            digits: Digit::try_multiply_digits::<DIGIT_BITNESS>(
+                &self.digits,
+                &other.digits,
+            )
+            .map_err(|_| OutOfMemoryError)?,
+        })
+    }
+}
+
+impl<
+        Digit: BitLength<Output = usize> + Copy + MultiplyDigits,
+        const DIGIT_BITNESS: usize,
+    > BigInt<Digit, DIGIT_BITNESS>
+{
+    pub fn try_mul_within(
+        &self,
+        other: &Self,
+        max_bit_length: usize,
+    ) -> Result<Self, TryMulError> {
+        let first_bit_length =
+            digits_bit_length::<Digit, DIGIT_BITNESS>(&self.digits);
+        let second_bit_length =
+            digits_bit_length::<Digit, DIGIT_BITNESS>(&other.digits);
+        if first_bit_length != 0
+            && second_bit_length != 0
+            && first_bit_length.saturating_add(second_bit_length - 1)
+                > max_bit_length
+        {
+            return Err(TryMulError::LimitExceeded);
+        }
+        let result =
+            self.try_mul(other).map_err(|_| TryMulError::OutOfMemory)?;
+        if digits_bit_length::<Digit, DIGIT_BITNESS>(&result.digits)
+            > max_bit_length
+        {
+            Err(TryMulError::LimitExceeded)
+        } else {
+            Ok(result)
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use traiter::numbers::{BitLength, Signed};
+
+use super::checked_pow::UncheckedPow;
+use super::digits::{digits_bit_length, MaybeReduceDigits};
+use super::types::{BigInt, TryPowError};
+
+impl<Digit, const DIGIT_BITNESS: usize> BigInt<Digit, DIGIT_BITNESS>
+where
+    for<'a> &'a Self: Signed,
+    Self: UncheckedPow,
+{
+    pub fn try_pow(&self, exponent: &Self) -> Result<Self, TryPowError> {
+        if exponent.is_negative() {
+            Err(TryPowError::NegativeExponent)
+        } else {
+            self.try_unchecked_pow(exponent)
+                .map_err(|_| TryPowError::OutOfMemory)
+        }
+    }
+}
+
+impl<
+        Digit: BitLength<Output = usize> + Copy + MaybeReduceDigits<usize>,
+        const DIGIT_BITNESS: usize,
+    > BigInt<Digit, DIGIT_BITNESS>
+where
+    for<'a> &'a Self: Signed,
+    Self: UncheckedPow,
+{
+    pub fn try_pow_within(
+        &self,
+        exponent: &Self,
+        max_bit_length: usize,
+    ) -> Result<Self, TryPowError> {
+        if exponent.is_negative() {
+            return Err(TryPowError::NegativeExponent);
+        }
+        let base_bit_length =
+            digits_bit_length::<Digit, DIGIT_BITNESS>(&self.digits);
+        if base_bit_length > 1 {
+            let exponent_value =
+                Digit::maybe_reduce_digits::<DIGIT_BITNESS>(&exponent.digits)
+                    .ok_or(TryPowError::LimitExceeded)?;
+            if (base_bit_length - 1).saturating_mul(exponent_value)
+                >= max_bit_length
+            {
+                return Err(TryPowError::LimitExceeded);
+            }
+        }
+        let result = self
+            .try_unchecked_pow(exponent)
+            .map_err(|_| TryPowError::OutOfMemory)?;
+        if digits_bit_length::<Digit, DIGIT_BITNESS>(&result.digits)
+            > max_bit_length
+        {
+            Err(TryPowError::LimitExceeded)
+        } else {
+            Ok(result)
+        }
+    }
+}
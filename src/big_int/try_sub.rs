@@ -0,0 +1,20 @@
+use super::digits::SubtractComponents;
+use super::types::{BigInt, OutOfMemoryError};
+
+impl<Digit: SubtractComponents, const DIGIT_BITNESS: usize>
+    BigInt<Digit, DIGIT_BITNESS>
+{
+    pub fn try_sub(
+        &self,
+        subtrahend: &Self,
+    ) -> Result<Self, OutOfMemoryError> {
+        let (sign, digits) = Digit::try_subtract_components::<DIGIT_BITNESS>(
+            self.sign,
+            &self.digits,
+            subtrahend.sign,
+            &subtrahend.digits,
+        )
+        .map_err(|_| OutOfMemoryError)?;
+        Ok(Self { sign, digits })
+    }
+}
@@ -1,20 +1,130 @@
 use std::fmt::{Debug, Display, Formatter};
 
-use super::constants::{MAX_REPRESENTABLE_BASE, MIN_REPRESENTABLE_BASE};
+use smallvec::SmallVec;
 
+use super::constants::{
+    MAX_ALPHABET_SIZE, MAX_REPRESENTABLE_BASE, MIN_ALPHABET_SIZE,
+    MIN_REPRESENTABLE_BASE,
+};
+
+pub(super) type Digits<Digit> = SmallVec<[Digit; 2]>;
 pub(super) type Sign = i8;
 pub(super) type WindowDigit = u8;
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct BigInt<Digit, const DIGIT_BITNESS: usize> {
     pub(super) sign: Sign,
-    pub(super) digits: Vec<Digit>,
+    pub(super) digits: Digits<Digit>,
 }
 
 impl<Digit, const DIGIT_BITNESS: usize> BigInt<Digit, DIGIT_BITNESS> {
-    pub(crate) fn digits(&self) -> &[Digit] {
+    pub fn digits(&self) -> &[Digit] {
         &self.digits
     }
+
+    pub(super) fn into_digits(mut self) -> Digits<Digit> {
+        std::mem::take(&mut self.digits)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Alphabet {
+    pub(super) symbols: Vec<char>,
+    pub(super) sorted_symbols_values: Vec<(char, u8)>,
+}
+
+impl Alphabet {
+    pub fn new(symbols: &str) -> Result<Self, AlphabetError> {
+        let symbols = symbols.chars().collect::<Vec<_>>();
+        if symbols.len() < MIN_ALPHABET_SIZE {
+            return Err(AlphabetError::TooFewSymbols(symbols.len()));
+        } else if symbols.len() > MAX_ALPHABET_SIZE {
+            return Err(AlphabetError::TooManySymbols(symbols.len()));
+        } else if let Some(&symbol) = symbols
+            .iter()
+            .find(|&&symbol| symbol == '+' || symbol == '-')
+        {
+            return Err(AlphabetError::SignSymbol(symbol));
+        }
+        let mut sorted_symbols_values = symbols
+            .iter()
+            .enumerate()
+            .map(|(value, &symbol)| (symbol, value as u8))
+            .collect::<Vec<_>>();
+        sorted_symbols_values.sort_unstable();
+        for window in sorted_symbols_values.windows(2) {
+            if window[0].0 == window[1].0 {
+                return Err(AlphabetError::DuplicateSymbol(window[0].0));
+            }
+        }
+        Ok(Self {
+            symbols,
+            sorted_symbols_values,
+        })
+    }
+
+    pub fn base(&self) -> usize {
+        self.symbols.len()
+    }
+
+    pub fn symbols(&self) -> &[char] {
+        &self.symbols
+    }
+
+    pub(super) fn to_value(&self, symbol: char) -> Option<u8> {
+        self.sorted_symbols_values
+            .binary_search_by_key(&symbol, |&(symbol, _)| symbol)
+            .ok()
+            .map(|index| self.sorted_symbols_values[index].1)
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum AlphabetError {
+    DuplicateSymbol(char),
+    SignSymbol(char),
+    TooFewSymbols(usize),
+    TooManySymbols(usize),
+}
+
+impl AlphabetError {
+    fn description(self) -> String {
+        match self {
+            AlphabetError::DuplicateSymbol(symbol) => {
+                format!("Alphabet symbols should be unique, but found duplicate: {symbol:?}.")
+            }
+            AlphabetError::SignSymbol(symbol) => {
+                format!(
+                    "Alphabet symbols should be distinct from sign symbols, \
+                     but found: {symbol:?}."
+                )
+            }
+            AlphabetError::TooFewSymbols(size) => {
+                format!(
+                    "Alphabet should have at least {MIN_ALPHABET_SIZE} symbols, \
+                     but found: {size}."
+                )
+            }
+            AlphabetError::TooManySymbols(size) => {
+                format!(
+                    "Alphabet should have at most {MAX_ALPHABET_SIZE} symbols, \
+                     but found: {size}."
+                )
+            }
+        }
+    }
+}
+
+impl Debug for AlphabetError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(&self.description())
+    }
+}
+
+impl Display for AlphabetError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.description(), formatter)
+    }
 }
 
 #[derive(Clone, Copy, Eq, PartialEq)]
@@ -79,8 +189,30 @@ impl Display for CheckedPowRemEuclidError {
     }
 }
 
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct OutOfMemoryError;
+
+impl OutOfMemoryError {
+    fn description(self) -> &'static str {
+        "Not enough memory for result."
+    }
+}
+
+impl Debug for OutOfMemoryError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.description())
+    }
+}
+
+impl Display for OutOfMemoryError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.description(), formatter)
+    }
+}
+
 #[derive(Clone, Copy, Eq, PartialEq)]
 pub enum ShlError {
+    LimitExceeded,
     NegativeShift,
     OutOfMemory,
     TooLarge,
@@ -89,6 +221,9 @@ pub enum ShlError {
 impl ShlError {
     fn description(self) -> &'static str {
         match self {
+            ShlError::LimitExceeded => {
+                "Shift result bit length exceeds the limit."
+            }
             ShlError::NegativeShift => "Shift by negative step is undefined.",
             ShlError::OutOfMemory => "Not enough memory for shift result.",
             ShlError::TooLarge => "Too large shift step.",
@@ -133,6 +268,73 @@ impl Display for ShrError {
     }
 }
 
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum ToStringError {
+    BaseOutOfBounds(u32),
+}
+
+impl ToStringError {
+    fn description(self) -> String {
+        match self {
+            ToStringError::BaseOutOfBounds(base) => {
+                format!(
+                    "Base should be in range from {MIN_REPRESENTABLE_BASE} \
+                     to {MAX_REPRESENTABLE_BASE}, but found: {base}."
+                )
+            }
+        }
+    }
+}
+
+impl Debug for ToStringError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(&self.description())
+    }
+}
+
+impl Display for ToStringError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.description(), formatter)
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum TryFromDigitsError {
+    DigitOutOfRange(usize),
+    LeadingZeros,
+    NoDigits,
+    SignMismatch,
+}
+
+impl TryFromDigitsError {
+    fn description(self) -> String {
+        match self {
+            TryFromDigitsError::DigitOutOfRange(position) => {
+                format!("Digit at position {position} exceeds digit bitness.")
+            }
+            TryFromDigitsError::LeadingZeros => {
+                String::from("Digits should not have leading zeros.")
+            }
+            TryFromDigitsError::NoDigits => String::from("No digits found."),
+            TryFromDigitsError::SignMismatch => String::from(
+                "Sign should be zero if and only if digits represent zero.",
+            ),
+        }
+    }
+}
+
+impl Debug for TryFromDigitsError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(&self.description())
+    }
+}
+
+impl Display for TryFromDigitsError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.description(), formatter)
+    }
+}
+
 #[derive(Clone, Copy, Eq, PartialEq)]
 pub enum TryFromFloatError {
     Infinity,
@@ -166,7 +368,10 @@ impl Display for TryFromFloatError {
 pub enum TryFromStringError {
     BaseOutOfBounds(u32),
     InvalidDigit(char, u8),
+    InvalidSymbol(char),
+    LimitExceeded,
     NoDigits,
+    OutOfMemory,
 }
 
 impl TryFromStringError {
@@ -181,7 +386,16 @@ impl TryFromStringError {
             TryFromStringError::InvalidDigit(character, base) => {
                 format!("Invalid digit in base {base}: {character:?}.")
             }
+            TryFromStringError::InvalidSymbol(character) => {
+                format!("Invalid symbol: {character:?}.")
+            }
+            TryFromStringError::LimitExceeded => {
+                String::from("Parsed value bit length exceeds the limit.")
+            }
             TryFromStringError::NoDigits => String::from("No digits found."),
+            TryFromStringError::OutOfMemory => {
+                String::from("Not enough memory for digits.")
+            }
         }
     }
 }
@@ -198,6 +412,68 @@ impl Display for TryFromStringError {
     }
 }
 
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum TryMulError {
+    LimitExceeded,
+    OutOfMemory,
+}
+
+impl TryMulError {
+    fn description(self) -> &'static str {
+        match self {
+            TryMulError::LimitExceeded => {
+                "Product bit length exceeds the limit."
+            }
+            TryMulError::OutOfMemory => "Not enough memory for product.",
+        }
+    }
+}
+
+impl Debug for TryMulError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.description())
+    }
+}
+
+impl Display for TryMulError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.description(), formatter)
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum TryPowError {
+    LimitExceeded,
+    NegativeExponent,
+    OutOfMemory,
+}
+
+impl TryPowError {
+    fn description(self) -> &'static str {
+        match self {
+            TryPowError::LimitExceeded => {
+                "Power bit length exceeds the limit."
+            }
+            TryPowError::NegativeExponent => {
+                "Exponent should be non-negative."
+            }
+            TryPowError::OutOfMemory => "Not enough memory for power result.",
+        }
+    }
+}
+
+impl Debug for TryPowError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.description())
+    }
+}
+
+impl Display for TryPowError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.description(), formatter)
+    }
+}
+
 #[derive(Clone, Copy, Eq, PartialEq)]
 pub enum TryIntoFloatError {
     TooLarge,
@@ -1,13 +1,14 @@
 use std::convert::TryFrom;
 
-use crate::traits::UncheckedToInt;
+use crate::traits::{ToFloat, UncheckedToInt};
 
 use super::types::BigInt;
 
 impl<Digit, const DIGIT_BITNESS: usize>
     UncheckedToInt<BigInt<Digit, DIGIT_BITNESS>> for f32
 where
-    f32: From<Digit> + UncheckedToInt<Digit>,
+    Digit: ToFloat<f32>,
+    f32: UncheckedToInt<Digit>,
     BigInt<Digit, DIGIT_BITNESS>: TryFrom<f32>,
 {
     unsafe fn unchecked_to_int(self) -> BigInt<Digit, DIGIT_BITNESS> {
@@ -18,7 +19,8 @@ where
 impl<Digit, const DIGIT_BITNESS: usize>
     UncheckedToInt<BigInt<Digit, DIGIT_BITNESS>> for f64
 where
-    f64: From<Digit> + UncheckedToInt<Digit>,
+    Digit: ToFloat<f64>,
+    f64: UncheckedToInt<Digit>,
     BigInt<Digit, DIGIT_BITNESS>: TryFrom<f64>,
 {
     unsafe fn unchecked_to_int(self) -> BigInt<Digit, DIGIT_BITNESS> {
@@ -22,7 +22,9 @@ where
     fn is_one(self) -> bool {
         self.sign.is_positive()
             && self.digits.len() == 1
-            && unsafe { self.digits.into_iter().next().unwrap_unchecked() }
-                .is_one()
+            && unsafe {
+                self.into_digits().into_iter().next().unwrap_unchecked()
+            }
+            .is_one()
     }
 }
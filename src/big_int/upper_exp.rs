@@ -0,0 +1,20 @@
+use std::fmt::{Formatter, UpperExp};
+
+use super::display::{split_sign, to_exponential_string, ToBaseString};
+use super::types::BigInt;
+
+impl<Digit, const DIGIT_BITNESS: usize> UpperExp
+    for BigInt<Digit, DIGIT_BITNESS>
+where
+    Self: ToBaseString,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        let string = self.to_base_string(10);
+        let (is_non_negative, digits) = split_sign(&string);
+        formatter.pad_integral(
+            is_non_negative,
+            "",
+            &to_exponential_string(digits, formatter.precision(), 'E'),
+        )
+    }
+}
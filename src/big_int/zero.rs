@@ -1,3 +1,4 @@
+use smallvec::smallvec;
 use traiter::numbers::Zero;
 
 use super::types::{BigInt, Sign};
@@ -8,7 +9,7 @@ impl<Digit: Zero, const DIGIT_BITNESS: usize> Zero
     fn zero() -> Self {
         Self {
             sign: Sign::zero(),
-            digits: vec![Digit::zero()],
+            digits: smallvec![Digit::zero()],
         }
     }
 }
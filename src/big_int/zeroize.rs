@@ -0,0 +1,29 @@
+use traiter::numbers::Zero;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use super::digits::wipe_digits;
+use super::types::{BigInt, Sign};
+
+impl<Digit: Zero, const DIGIT_BITNESS: usize> Zeroize
+    for BigInt<Digit, DIGIT_BITNESS>
+{
+    fn zeroize(&mut self) {
+        self.sign = Sign::zero();
+        // wiping the whole buffer including spare capacity
+        // which can hold digits of the value before truncation
+        wipe_digits(&mut self.digits);
+        self.digits.clear();
+        self.digits.push(Digit::zero());
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Drop for BigInt<Digit, DIGIT_BITNESS> {
+    fn drop(&mut self) {
+        wipe_digits(&mut self.digits);
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> ZeroizeOnDrop
+    for BigInt<Digit, DIGIT_BITNESS>
+{
+}
@@ -0,0 +1,3 @@
+pub use self::types::Binary128;
+
+mod types;
@@ -0,0 +1,34 @@
+#[derive(Clone, Copy, Debug)]
+pub struct Binary128 {
+    pub(super) bits: u128,
+}
+
+impl Binary128 {
+    pub const INFINITY: Self = Self::from_bits(0x7fff << 112);
+    pub const MANTISSA_DIGITS: u32 = 113;
+    pub const MAX: Self = Self::from_bits((0x7fff << 112) - 1);
+    pub const MAX_EXP: i32 = 16384;
+    pub const MIN_EXP: i32 = -16381;
+    pub const NAN: Self = Self::from_bits(0x7fff8 << 108);
+    pub const NEG_INFINITY: Self = Self::from_bits(0xffff << 112);
+
+    pub const fn from_bits(bits: u128) -> Self {
+        Self { bits }
+    }
+
+    pub const fn is_infinite(self) -> bool {
+        self.bits & !(1 << 127) == Self::INFINITY.bits
+    }
+
+    pub const fn is_nan(self) -> bool {
+        self.bits & !(1 << 127) > Self::INFINITY.bits
+    }
+
+    pub const fn is_sign_negative(self) -> bool {
+        self.bits >> 127 == 1
+    }
+
+    pub const fn to_bits(self) -> u128 {
+        self.bits
+    }
+}
@@ -1,2 +1,4 @@
+pub(crate) const INEXACT_ROUNDING_ERROR_MESSAGE: &str =
+    "Rounding is necessary, but rounding mode forbids it.";
 pub(crate) const UNDEFINED_DIVISION_ERROR_MESSAGE: &str =
     "Division by zero is undefined.";
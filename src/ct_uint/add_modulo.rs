@@ -0,0 +1,18 @@
+use subtle::{ConstantTimeEq, CtOption};
+use traiter::numbers::Zero;
+
+use super::types::CtUint;
+
+impl<const LIMBS: usize> CtUint<LIMBS> {
+    pub fn add_modulo(&self, other: &Self, modulus: &Self) -> CtOption<Self> {
+        let (sum, carry) = self.overflowing_add(other);
+        CtOption::new(
+            Self::reduce(
+                &sum,
+                &Self::from(u64::from(carry.unwrap_u8())),
+                modulus,
+            ),
+            !modulus.ct_eq(&Self::zero()),
+        )
+    }
+}
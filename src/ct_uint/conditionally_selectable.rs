@@ -0,0 +1,18 @@
+use subtle::{Choice, ConditionallySelectable};
+use traiter::numbers::Zero;
+
+use super::types::CtUint;
+
+impl<const LIMBS: usize> ConditionallySelectable for CtUint<LIMBS> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut result = Self::zero();
+        for index in 0..LIMBS {
+            result.limbs[index] = u64::conditional_select(
+                &a.limbs[index],
+                &b.limbs[index],
+                choice,
+            );
+        }
+        result
+    }
+}
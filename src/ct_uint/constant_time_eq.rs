@@ -0,0 +1,9 @@
+use subtle::{Choice, ConstantTimeEq};
+
+use super::types::CtUint;
+
+impl<const LIMBS: usize> ConstantTimeEq for CtUint<LIMBS> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.limbs.ct_eq(&other.limbs)
+    }
+}
@@ -0,0 +1,10 @@
+use subtle::{Choice, ConstantTimeGreater};
+
+use super::types::CtUint;
+
+impl<const LIMBS: usize> ConstantTimeGreater for CtUint<LIMBS> {
+    fn ct_gt(&self, other: &Self) -> Choice {
+        let (_, borrow) = other.overflowing_sub(self);
+        borrow
+    }
+}
@@ -0,0 +1,5 @@
+use subtle::ConstantTimeLess;
+
+use super::types::CtUint;
+
+impl<const LIMBS: usize> ConstantTimeLess for CtUint<LIMBS> {}
@@ -0,0 +1,11 @@
+use traiter::numbers::Zero;
+
+use super::types::{CtUint, Limb};
+
+impl<const LIMBS: usize> From<Limb> for CtUint<LIMBS> {
+    fn from(value: Limb) -> Self {
+        let mut result = Self::zero();
+        result.limbs[0] = value;
+        result
+    }
+}
@@ -0,0 +1,167 @@
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use traiter::numbers::{One, Zero};
+
+use super::types::{CtUint, Limb, LIMB_BITNESS};
+
+impl<const LIMBS: usize> CtUint<LIMBS> {
+    /// Computes inverse modulo arbitrary (not necessarily prime) modulus
+    /// with binary extended Euclidean algorithm
+    /// running fixed number of iterations,
+    /// result is none if the value is not coprime with the modulus.
+    pub fn invert_modulo(&self, modulus: &Self) -> CtOption<Self> {
+        let residue = Self::reduce(self, &Self::zero(), modulus);
+        // maintained invariants are
+        // `first_coefficient * residue - first_cofactor * modulus == first`
+        // and
+        // `second_cofactor * modulus - second_coefficient * residue == second`
+        // with coefficients less than modulus
+        // and cofactors not greater than residue,
+        // halving relies on either residue or modulus being odd,
+        // otherwise they have common divisor 2 and are not coprime
+        let (mut first, mut second) = (residue, *modulus);
+        let (mut first_coefficient, mut first_cofactor) =
+            (Self::one(), Self::zero());
+        let (mut second_coefficient, mut second_cofactor) =
+            (Self::zero(), Self::one());
+        for _ in 0..2 * Self::BITS {
+            let are_both_odd = first.is_odd() & second.is_odd();
+            let (first_difference, borrow) = first.overflowing_sub(&second);
+            let (second_difference, _) = second.overflowing_sub(&first);
+            let is_first_greater =
+                !borrow & !first_difference.ct_eq(&Self::zero());
+            let is_first_decreased = are_both_odd & is_first_greater;
+            let is_second_decreased = are_both_odd & !is_first_greater;
+            first.conditional_assign(&first_difference, is_first_decreased);
+            second.conditional_assign(&second_difference, is_second_decreased);
+            let (first_coefficients_sum, first_cofactors_sum) =
+                Self::add_coefficients(
+                    (&first_coefficient, &first_cofactor),
+                    (&second_coefficient, &second_cofactor),
+                    &residue,
+                    modulus,
+                );
+            let (second_coefficients_sum, second_cofactors_sum) =
+                Self::add_coefficients(
+                    (&second_coefficient, &second_cofactor),
+                    (&first_coefficient, &first_cofactor),
+                    &residue,
+                    modulus,
+                );
+            first_coefficient.conditional_assign(
+                &first_coefficients_sum,
+                is_first_decreased,
+            );
+            first_cofactor
+                .conditional_assign(&first_cofactors_sum, is_first_decreased);
+            second_coefficient.conditional_assign(
+                &second_coefficients_sum,
+                is_second_decreased,
+            );
+            second_cofactor.conditional_assign(
+                &second_cofactors_sum,
+                is_second_decreased,
+            );
+            let is_first_halved = !first.is_odd();
+            Self::halve_with_coefficients(
+                &mut first,
+                (&mut first_coefficient, &mut first_cofactor),
+                &residue,
+                modulus,
+                is_first_halved,
+            );
+            Self::halve_with_coefficients(
+                &mut second,
+                (&mut second_coefficient, &mut second_cofactor),
+                &residue,
+                modulus,
+                !is_first_halved,
+            );
+        }
+        CtOption::new(
+            first_coefficient,
+            first.ct_eq(&Self::one())
+                & (residue.is_odd() | modulus.is_odd())
+                & !modulus.ct_eq(&Self::zero()),
+        )
+    }
+
+    fn add_coefficients(
+        (coefficient, cofactor): (&Self, &Self),
+        (other_coefficient, other_cofactor): (&Self, &Self),
+        residue: &Self,
+        modulus: &Self,
+    ) -> (Self, Self) {
+        let (coefficients_sum, carry) =
+            coefficient.overflowing_add(other_coefficient);
+        let (reduced_coefficients_sum, borrow) =
+            coefficients_sum.overflowing_sub(modulus);
+        let is_reduced = carry | !borrow;
+        let (cofactors_sum, _) = cofactor.overflowing_add(other_cofactor);
+        let (reduced_cofactors_sum, _) =
+            cofactors_sum.overflowing_sub(residue);
+        (
+            Self::conditional_select(
+                &coefficients_sum,
+                &reduced_coefficients_sum,
+                is_reduced,
+            ),
+            Self::conditional_select(
+                &cofactors_sum,
+                &reduced_cofactors_sum,
+                is_reduced,
+            ),
+        )
+    }
+
+    fn halve_with_coefficients(
+        value: &mut Self,
+        (coefficient, cofactor): (&mut Self, &mut Self),
+        residue: &Self,
+        modulus: &Self,
+        choice: Choice,
+    ) {
+        let is_adjusted = coefficient.is_odd() | cofactor.is_odd();
+        let (adjusted_coefficient, coefficient_carry) =
+            coefficient.overflowing_add(modulus);
+        let (adjusted_cofactor, cofactor_carry) =
+            cofactor.overflowing_add(residue);
+        value.conditional_assign(&value.shr_one_with(0), choice);
+        coefficient.conditional_assign(
+            &Self::conditional_select(
+                coefficient,
+                &adjusted_coefficient,
+                is_adjusted,
+            )
+            .shr_one_with(Limb::from(
+                (coefficient_carry & is_adjusted).unwrap_u8(),
+            )),
+            choice,
+        );
+        cofactor.conditional_assign(
+            &Self::conditional_select(
+                cofactor,
+                &adjusted_cofactor,
+                is_adjusted,
+            )
+            .shr_one_with(Limb::from(
+                (cofactor_carry & is_adjusted).unwrap_u8(),
+            )),
+            choice,
+        );
+    }
+
+    fn is_odd(&self) -> Choice {
+        Choice::from((self.limbs[0] & 1) as u8)
+    }
+
+    fn shr_one_with(&self, bit: Limb) -> Self {
+        let mut result = Self::zero();
+        let mut carry = bit;
+        for index in (0..LIMBS).rev() {
+            result.limbs[index] =
+                (self.limbs[index] >> 1) | (carry << (LIMB_BITNESS - 1));
+            carry = self.limbs[index] & 1;
+        }
+        result
+    }
+}
@@ -0,0 +1,20 @@
+pub use self::types::CtUint;
+
+mod add_modulo;
+mod conditionally_selectable;
+mod constant_time_eq;
+mod constant_time_greater;
+mod constant_time_less;
+mod from;
+mod invert_modulo;
+mod mul_modulo;
+mod one;
+mod overflowing_add;
+mod overflowing_sub;
+mod pow_modulo;
+mod reduce;
+mod rem_modulo;
+mod sub_modulo;
+mod types;
+mod widening_mul;
+mod zero;
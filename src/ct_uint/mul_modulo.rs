@@ -0,0 +1,14 @@
+use subtle::{ConstantTimeEq, CtOption};
+use traiter::numbers::Zero;
+
+use super::types::CtUint;
+
+impl<const LIMBS: usize> CtUint<LIMBS> {
+    pub fn mul_modulo(&self, other: &Self, modulus: &Self) -> CtOption<Self> {
+        let (low, high) = self.widening_mul(other);
+        CtOption::new(
+            Self::reduce(&low, &high, modulus),
+            !modulus.ct_eq(&Self::zero()),
+        )
+    }
+}
@@ -0,0 +1,11 @@
+use traiter::numbers::One;
+
+use super::types::CtUint;
+
+impl<const LIMBS: usize> One for CtUint<LIMBS> {
+    fn one() -> Self {
+        let mut limbs = [0; LIMBS];
+        limbs[0] = 1;
+        Self { limbs }
+    }
+}
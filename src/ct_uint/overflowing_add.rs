@@ -0,0 +1,19 @@
+use subtle::Choice;
+use traiter::numbers::Zero;
+
+use super::types::{CtUint, DoubleLimb, Limb, LIMB_BITNESS};
+
+impl<const LIMBS: usize> CtUint<LIMBS> {
+    pub fn overflowing_add(&self, other: &Self) -> (Self, Choice) {
+        let mut result = Self::zero();
+        let mut carry: Limb = 0;
+        for index in 0..LIMBS {
+            let sum = DoubleLimb::from(self.limbs[index])
+                + DoubleLimb::from(other.limbs[index])
+                + DoubleLimb::from(carry);
+            result.limbs[index] = sum as Limb;
+            carry = (sum >> LIMB_BITNESS) as Limb;
+        }
+        (result, Choice::from(carry as u8))
+    }
+}
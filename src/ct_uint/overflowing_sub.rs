@@ -0,0 +1,19 @@
+use subtle::Choice;
+use traiter::numbers::Zero;
+
+use super::types::{CtUint, DoubleLimb, Limb, LIMB_BITNESS};
+
+impl<const LIMBS: usize> CtUint<LIMBS> {
+    pub fn overflowing_sub(&self, subtrahend: &Self) -> (Self, Choice) {
+        let mut result = Self::zero();
+        let mut borrow: Limb = 0;
+        for index in 0..LIMBS {
+            let difference = DoubleLimb::from(self.limbs[index])
+                .wrapping_sub(DoubleLimb::from(subtrahend.limbs[index]))
+                .wrapping_sub(DoubleLimb::from(borrow));
+            result.limbs[index] = difference as Limb;
+            borrow = ((difference >> LIMB_BITNESS) as Limb) & 1;
+        }
+        (result, Choice::from(borrow as u8))
+    }
+}
@@ -0,0 +1,34 @@
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use traiter::numbers::{One, Zero};
+
+use super::types::CtUint;
+
+impl<const LIMBS: usize> CtUint<LIMBS> {
+    pub fn pow_modulo(
+        &self,
+        exponent: &Self,
+        modulus: &Self,
+    ) -> CtOption<Self> {
+        CtOption::new(
+            Self::reduce(self, &Self::zero(), modulus)
+                .pow_reduced(exponent, modulus),
+            !modulus.ct_eq(&Self::zero()),
+        )
+    }
+
+    pub(super) fn pow_reduced(&self, exponent: &Self, modulus: &Self) -> Self {
+        let mut result = Self::reduce(&Self::one(), &Self::zero(), modulus);
+        for index in (0..Self::BITS).rev() {
+            let (low, high) = result.widening_mul(&result);
+            result = Self::reduce(&low, &high, modulus);
+            let (low, high) = result.widening_mul(self);
+            let product = Self::reduce(&low, &high, modulus);
+            result = Self::conditional_select(
+                &result,
+                &product,
+                Choice::from(exponent.bit(index) as u8),
+            );
+        }
+        result
+    }
+}
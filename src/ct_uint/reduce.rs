@@ -0,0 +1,35 @@
+use subtle::{Choice, ConditionallySelectable};
+use traiter::numbers::Zero;
+
+use super::types::{CtUint, Limb, LIMB_BITNESS};
+
+impl<const LIMBS: usize> CtUint<LIMBS> {
+    pub(super) fn reduce(low: &Self, high: &Self, modulus: &Self) -> Self {
+        let mut result = Self::zero();
+        for index in (0..2 * Self::BITS).rev() {
+            let bit = if index < Self::BITS {
+                low.bit(index)
+            } else {
+                high.bit(index - Self::BITS)
+            };
+            let (shifted, carry) = result.shl_one_with(bit);
+            let (difference, borrow) = shifted.overflowing_sub(modulus);
+            result = Self::conditional_select(
+                &shifted,
+                &difference,
+                carry | !borrow,
+            );
+        }
+        result
+    }
+
+    fn shl_one_with(&self, bit: Limb) -> (Self, Choice) {
+        let mut result = Self::zero();
+        let mut carry = bit;
+        for index in 0..LIMBS {
+            result.limbs[index] = (self.limbs[index] << 1) | carry;
+            carry = self.limbs[index] >> (LIMB_BITNESS - 1);
+        }
+        (result, Choice::from(carry as u8))
+    }
+}
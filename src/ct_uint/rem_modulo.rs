@@ -0,0 +1,13 @@
+use subtle::{ConstantTimeEq, CtOption};
+use traiter::numbers::Zero;
+
+use super::types::CtUint;
+
+impl<const LIMBS: usize> CtUint<LIMBS> {
+    pub fn rem_modulo(&self, modulus: &Self) -> CtOption<Self> {
+        CtOption::new(
+            Self::reduce(self, &Self::zero(), modulus),
+            !modulus.ct_eq(&Self::zero()),
+        )
+    }
+}
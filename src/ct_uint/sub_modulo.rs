@@ -0,0 +1,25 @@
+use subtle::{ConditionallySelectable, ConstantTimeEq, CtOption};
+use traiter::numbers::Zero;
+
+use super::types::CtUint;
+
+impl<const LIMBS: usize> CtUint<LIMBS> {
+    pub fn sub_modulo(
+        &self,
+        subtrahend: &Self,
+        modulus: &Self,
+    ) -> CtOption<Self> {
+        let minuend = Self::reduce(self, &Self::zero(), modulus);
+        let subtrahend = Self::reduce(subtrahend, &Self::zero(), modulus);
+        let (difference, borrow) = minuend.overflowing_sub(&subtrahend);
+        let (corrected_difference, _) = difference.overflowing_add(modulus);
+        CtOption::new(
+            Self::conditional_select(
+                &difference,
+                &corrected_difference,
+                borrow,
+            ),
+            !modulus.ct_eq(&Self::zero()),
+        )
+    }
+}
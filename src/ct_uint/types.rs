@@ -0,0 +1,25 @@
+pub(super) type Limb = u64;
+pub(super) type DoubleLimb = u128;
+
+pub(super) const LIMB_BITNESS: usize = Limb::BITS as usize;
+
+#[derive(Clone, Copy, Debug)]
+pub struct CtUint<const LIMBS: usize> {
+    pub(super) limbs: [Limb; LIMBS],
+}
+
+impl<const LIMBS: usize> CtUint<LIMBS> {
+    pub const BITS: usize = LIMBS * LIMB_BITNESS;
+
+    pub fn from_limbs(limbs: [Limb; LIMBS]) -> Self {
+        Self { limbs }
+    }
+
+    pub fn limbs(&self) -> &[Limb; LIMBS] {
+        &self.limbs
+    }
+
+    pub(super) fn bit(&self, index: usize) -> Limb {
+        (self.limbs[index / LIMB_BITNESS] >> (index % LIMB_BITNESS)) & 1
+    }
+}
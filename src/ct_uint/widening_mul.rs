@@ -0,0 +1,25 @@
+use traiter::numbers::Zero;
+
+use super::types::{CtUint, DoubleLimb, Limb, LIMB_BITNESS};
+
+impl<const LIMBS: usize> CtUint<LIMBS> {
+    pub fn widening_mul(&self, other: &Self) -> (Self, Self) {
+        let mut product = [Self::zero(); 2];
+        for first_index in 0..LIMBS {
+            let mut carry: Limb = 0;
+            for second_index in 0..LIMBS {
+                let index = first_index + second_index;
+                let limb = &mut product[index / LIMBS].limbs[index % LIMBS];
+                let accumulator = DoubleLimb::from(self.limbs[first_index])
+                    * DoubleLimb::from(other.limbs[second_index])
+                    + DoubleLimb::from(*limb)
+                    + DoubleLimb::from(carry);
+                *limb = accumulator as Limb;
+                carry = (accumulator >> LIMB_BITNESS) as Limb;
+            }
+            product[1].limbs[first_index] = carry;
+        }
+        let [low, high] = product;
+        (low, high)
+    }
+}
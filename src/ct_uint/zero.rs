@@ -0,0 +1,9 @@
+use traiter::numbers::Zero;
+
+use super::types::CtUint;
+
+impl<const LIMBS: usize> Zero for CtUint<LIMBS> {
+    fn zero() -> Self {
+        Self { limbs: [0; LIMBS] }
+    }
+}
@@ -0,0 +1,400 @@
+use traiter::numbers::{
+    Abs, CheckedDivRemEuclid, One, Parity, Pow, Signed, Zero, Zeroable,
+};
+
+use crate::big_int::group_digits;
+use crate::BigInt;
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub(crate) enum Alignment {
+    Center,
+    Left,
+    Right,
+    SignAware,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub(crate) enum SignOption {
+    Always,
+    OnlyNegative,
+    SpaceForNonNegative,
+}
+
+pub(crate) struct FormatSpec {
+    pub(crate) fill: Option<char>,
+    pub(crate) alignment: Option<Alignment>,
+    pub(crate) sign: Option<SignOption>,
+    pub(crate) coerce_negative_zero: bool,
+    pub(crate) alternate: bool,
+    pub(crate) zero_padding: bool,
+    pub(crate) has_redundant_zeros: bool,
+    pub(crate) width: usize,
+    pub(crate) grouping: Option<char>,
+    pub(crate) precision: Option<usize>,
+    pub(crate) kind: Option<char>,
+}
+
+impl FormatSpec {
+    pub(crate) fn parse(
+        specifier: &str,
+        type_name: &str,
+    ) -> Result<Self, String> {
+        let invalid_specifier_error = || {
+            format!(
+                "Invalid format specifier '{specifier}' \
+                 for object of type '{type_name}'"
+            )
+        };
+        let characters = specifier.chars().collect::<Vec<_>>();
+        let mut position = 0;
+        let mut fill = None;
+        let mut alignment = None;
+        if characters.len() > 1 {
+            if let Some(value) = to_alignment(characters[1]) {
+                fill = Some(characters[0]);
+                alignment = Some(value);
+                position = 2;
+            }
+        }
+        if alignment.is_none() && !characters.is_empty() {
+            if let Some(value) = to_alignment(characters[0]) {
+                alignment = Some(value);
+                position = 1;
+            }
+        }
+        let sign = match characters.get(position) {
+            Some('+') => Some(SignOption::Always),
+            Some(' ') => Some(SignOption::SpaceForNonNegative),
+            Some('-') => Some(SignOption::OnlyNegative),
+            _ => None,
+        };
+        if sign.is_some() {
+            position += 1;
+        }
+        let coerce_negative_zero = characters.get(position) == Some(&'z');
+        if coerce_negative_zero {
+            position += 1;
+        }
+        let alternate = characters.get(position) == Some(&'#');
+        if alternate {
+            position += 1;
+        }
+        let zero_padding = characters.get(position) == Some(&'0')
+            && characters
+                .get(position + 1)
+                .map_or(false, char::is_ascii_digit);
+        if zero_padding {
+            position += 1;
+        }
+        let (width, next_position) = parse_number(&characters, position)
+            .map_err(|_| {
+                String::from("Too many decimal digits in format string")
+            })?;
+        let mut has_redundant_zeros =
+            next_position > position + 1 && characters[position] == '0';
+        position = next_position;
+        let grouping = match characters.get(position) {
+            Some(&character @ (',' | '_')) => {
+                position += 1;
+                Some(character)
+            }
+            _ => None,
+        };
+        if matches!(characters.get(position), Some(',' | '_')) {
+            return Err(String::from("Cannot specify both ',' and '_'."));
+        }
+        let precision = if characters.get(position) == Some(&'.') {
+            let (precision, next_position) =
+                parse_number(&characters, position + 1).map_err(|_| {
+                    String::from("Too many decimal digits in format string")
+                })?;
+            if next_position == position + 1 {
+                return Err(String::from(
+                    "Format specifier missing precision",
+                ));
+            }
+            has_redundant_zeros |= next_position > position + 2
+                && characters[position + 1] == '0';
+            position = next_position;
+            Some(precision)
+        } else {
+            None
+        };
+        let kind = characters.get(position).copied();
+        if kind.is_some() {
+            position += 1;
+        }
+        if position != characters.len() {
+            return Err(invalid_specifier_error());
+        }
+        Ok(Self {
+            fill,
+            alignment,
+            sign,
+            coerce_negative_zero,
+            alternate,
+            zero_padding,
+            has_redundant_zeros,
+            width,
+            grouping,
+            precision,
+            kind,
+        })
+    }
+
+    pub(crate) fn to_sign(&self, is_negative: bool) -> &'static str {
+        if is_negative {
+            "-"
+        } else {
+            match self.sign {
+                Some(SignOption::Always) => "+",
+                Some(SignOption::SpaceForNonNegative) => " ",
+                Some(SignOption::OnlyNegative) | None => "",
+            }
+        }
+    }
+
+    fn to_alignment(&self) -> Alignment {
+        self.alignment.unwrap_or(if self.zero_padding {
+            Alignment::SignAware
+        } else {
+            Alignment::Right
+        })
+    }
+
+    fn to_fill(&self) -> char {
+        self.fill
+            .unwrap_or(if self.zero_padding { '0' } else { ' ' })
+    }
+
+    pub(crate) fn is_zero_padded(&self) -> bool {
+        self.to_fill() == '0' && self.to_alignment() == Alignment::SignAware
+    }
+
+    pub(crate) fn group_integral_digits(
+        &self,
+        digits: &str,
+        group_size: usize,
+        prefix_length: usize,
+    ) -> String {
+        match self.grouping {
+            Some(separator) => {
+                if self.is_zero_padded() {
+                    let minimum_length =
+                        self.width.saturating_sub(prefix_length);
+                    let mut padded_digits = String::from(digits);
+                    loop {
+                        let result = group_digits(
+                            &padded_digits,
+                            separator,
+                            group_size,
+                        );
+                        if result.chars().count() >= minimum_length {
+                            break result;
+                        }
+                        padded_digits.insert(0, '0');
+                    }
+                } else {
+                    group_digits(digits, separator, group_size)
+                }
+            }
+            None => String::from(digits),
+        }
+    }
+
+    pub(crate) fn to_rational_parts(
+        &self,
+        numerator: &BigInt,
+        denominator: &BigInt,
+        kind: char,
+    ) -> (bool, String, String) {
+        let precision = self.precision.unwrap_or(6);
+        let (is_negative, significand, point_position, exponent) =
+            if matches!(kind, 'f' | 'F' | '%') {
+                let exponent =
+                    -(precision as isize) - if kind == '%' { 2 } else { 0 };
+                let (is_negative, significand) = round_to_exponent(
+                    numerator,
+                    denominator,
+                    exponent,
+                    self.coerce_negative_zero,
+                );
+                (is_negative, significand, precision, None)
+            } else {
+                let figures = if matches!(kind, 'g' | 'G') {
+                    precision.max(1)
+                } else {
+                    precision + 1
+                };
+                let (is_negative, significand, exponent) =
+                    round_to_figures(numerator, denominator, figures);
+                if matches!(kind, 'e' | 'E')
+                    || exponent > 0
+                    || exponent + (figures as isize) <= -4
+                {
+                    (
+                        is_negative,
+                        significand,
+                        figures - 1,
+                        Some(exponent + (figures as isize) - 1),
+                    )
+                } else {
+                    (is_negative, significand, exponent.unsigned_abs(), None)
+                }
+            };
+        let suffix = if kind == '%' {
+            String::from("%")
+        } else if let Some(exponent) = exponent {
+            format!(
+                "{}{:+03}",
+                if matches!(kind, 'E' | 'F' | 'G') {
+                    'E'
+                } else {
+                    'e'
+                },
+                exponent
+            )
+        } else {
+            String::new()
+        };
+        let digits = format!(
+            "{:0>width$}",
+            significand.to_string(),
+            width = point_position + 1
+        );
+        let (leading, fractional) =
+            digits.split_at(digits.len() - point_position);
+        let fractional = if matches!(kind, 'g' | 'G') && !self.alternate {
+            fractional.trim_end_matches('0')
+        } else {
+            fractional
+        };
+        let separator = if self.alternate || !fractional.is_empty() {
+            "."
+        } else {
+            ""
+        };
+        (
+            is_negative,
+            String::from(leading),
+            format!("{separator}{fractional}{suffix}"),
+        )
+    }
+
+    pub(crate) fn pad(&self, prefix: &str, body: &str) -> String {
+        let length = prefix.chars().count() + body.chars().count();
+        let padding_length = self.width.saturating_sub(length);
+        let fill = self.to_fill();
+        let padding = |count: usize| {
+            std::iter::repeat(fill).take(count).collect::<String>()
+        };
+        match self.to_alignment() {
+            Alignment::Center => {
+                let left_padding_length = padding_length / 2;
+                format!(
+                    "{}{prefix}{body}{}",
+                    padding(left_padding_length),
+                    padding(padding_length - left_padding_length)
+                )
+            }
+            Alignment::Left => {
+                format!("{prefix}{body}{}", padding(padding_length))
+            }
+            Alignment::Right => {
+                format!("{}{prefix}{body}", padding(padding_length))
+            }
+            Alignment::SignAware => {
+                format!("{prefix}{}{body}", padding(padding_length))
+            }
+        }
+    }
+}
+
+fn parse_number(
+    characters: &[char],
+    mut position: usize,
+) -> Result<(usize, usize), ()> {
+    let mut result = 0usize;
+    while let Some(digit) = characters
+        .get(position)
+        .and_then(|character| character.to_digit(10))
+    {
+        result = result
+            .checked_mul(10)
+            .and_then(|result| result.checked_add(digit as usize))
+            .ok_or(())?;
+        position += 1;
+    }
+    Ok((result, position))
+}
+
+fn round_to_exponent(
+    numerator: &BigInt,
+    denominator: &BigInt,
+    exponent: isize,
+    coerce_negative_zero: bool,
+) -> (bool, BigInt) {
+    let (numerator, denominator) = if exponent >= 0 {
+        (
+            numerator.clone(),
+            denominator * to_power_of_ten(exponent.unsigned_abs()),
+        )
+    } else {
+        (
+            numerator * to_power_of_ten(exponent.unsigned_abs()),
+            denominator.clone(),
+        )
+    };
+    let (mut quotient, remainder) = unsafe {
+        (&numerator + (&denominator >> &BigInt::one()))
+            .checked_div_rem_euclid(&denominator)
+            .unwrap_unchecked()
+    };
+    if remainder.is_zero() && (&denominator).is_even() && (&quotient).is_odd()
+    {
+        quotient -= BigInt::one();
+    }
+    let is_negative = if coerce_negative_zero {
+        (&quotient).is_negative()
+    } else {
+        (&numerator).is_negative()
+    };
+    (is_negative, quotient.abs())
+}
+
+fn round_to_figures(
+    numerator: &BigInt,
+    denominator: &BigInt,
+    figures: usize,
+) -> (bool, BigInt, isize) {
+    if numerator.is_zero() {
+        return (false, BigInt::zero(), 1 - (figures as isize));
+    }
+    let numerator_string = numerator.abs().to_string();
+    let denominator_string = denominator.to_string();
+    let mut exponent = (numerator_string.len() as isize)
+        - (denominator_string.len() as isize)
+        + isize::from(denominator_string <= numerator_string)
+        - (figures as isize);
+    let (is_negative, mut significand) =
+        round_to_exponent(numerator, denominator, exponent, false);
+    if significand.to_string().len() == figures + 1 {
+        significand /= BigInt::from(10u8);
+        exponent += 1;
+    }
+    (is_negative, significand, exponent)
+}
+
+fn to_power_of_ten(exponent: usize) -> BigInt {
+    BigInt::from(10u8).pow(BigInt::from(exponent))
+}
+
+fn to_alignment(character: char) -> Option<Alignment> {
+    match character {
+        '<' => Some(Alignment::Left),
+        '>' => Some(Alignment::Right),
+        '=' => Some(Alignment::SignAware),
+        '^' => Some(Alignment::Center),
+        _ => None,
+    }
+}
@@ -0,0 +1,52 @@
+use std::ops::{Add, Neg, Shl};
+
+use traiter::numbers::{
+    Abs, CheckedDivRemEuclid, One, Parity, Signed, Zeroable,
+};
+
+use crate::big_int::BigInt;
+use crate::rounding_mode::RoundingMode;
+
+use super::types::Fraction;
+
+impl<Digit, const DIGIT_BITNESS: usize> Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Abs<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + CheckedDivRemEuclid<
+            Output = Option<(
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            )>,
+        > + Parity
+        + Signed
+        + Zeroable,
+    BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Neg<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + One
+        + Ord
+        + Shl<usize, Output = BigInt<Digit, DIGIT_BITNESS>>,
+{
+    pub fn checked_round(
+        &self,
+        mode: RoundingMode,
+    ) -> Option<BigInt<Digit, DIGIT_BITNESS>> {
+        let is_negative = (&self.numerator).is_negative();
+        let (quotient, remainder) = unsafe {
+            (&(&self.numerator).abs())
+                .checked_div_rem_euclid(&self.denominator)
+                .unwrap_unchecked()
+        };
+        let is_exact = (&remainder).is_zero();
+        let magnitude = if mode.rounds_magnitude_up(
+            is_negative,
+            (&quotient).is_odd(),
+            is_exact,
+            (remainder << 1).cmp(&self.denominator),
+        )? {
+            quotient + BigInt::one()
+        } else {
+            quotient
+        };
+        Some(if is_negative { -magnitude } else { magnitude })
+    }
+}
@@ -0,0 +1,54 @@
+use std::mem::replace;
+use std::ops::{Add, Mul};
+
+use traiter::numbers::{CheckedDivRemEuclid, One, Zero};
+
+use crate::big_int::BigInt;
+
+use super::types::{Convergents, Fraction};
+
+impl<Digit, const DIGIT_BITNESS: usize> Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    BigInt<Digit, DIGIT_BITNESS>: Clone + One + Zero,
+{
+    pub fn convergents(&self) -> Convergents<BigInt<Digit, DIGIT_BITNESS>> {
+        Convergents {
+            partial_quotients: self.partial_quotients(),
+            previous_numerator: BigInt::zero(),
+            previous_denominator: BigInt::one(),
+            numerator: BigInt::one(),
+            denominator: BigInt::zero(),
+        }
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Iterator
+    for Convergents<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + CheckedDivRemEuclid<
+            Output = Option<(
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            )>,
+        > + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    BigInt<Digit, DIGIT_BITNESS>: Clone,
+{
+    type Item = Fraction<BigInt<Digit, DIGIT_BITNESS>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let quotient = self.partial_quotients.next()?;
+        let numerator =
+            &(&quotient * &self.numerator) + &self.previous_numerator;
+        let denominator =
+            &(&quotient * &self.denominator) + &self.previous_denominator;
+        self.previous_numerator =
+            replace(&mut self.numerator, numerator.clone());
+        self.previous_denominator =
+            replace(&mut self.denominator, denominator.clone());
+        Some(Fraction {
+            numerator,
+            denominator,
+        })
+    }
+}
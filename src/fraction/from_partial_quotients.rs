@@ -0,0 +1,48 @@
+use std::mem::replace;
+use std::ops::{Add, Mul};
+
+use traiter::numbers::{One, Zero, Zeroable};
+
+use crate::big_int::BigInt;
+
+use super::types::{Fraction, NormalizeModuli, NormalizeSign};
+
+impl<Digit, const DIGIT_BITNESS: usize> Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Zeroable,
+    BigInt<Digit, DIGIT_BITNESS>: NormalizeModuli<
+            Output = (
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            ),
+        > + NormalizeSign<
+            Output = (
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            ),
+        > + One
+        + Zero,
+{
+    pub fn from_partial_quotients<
+        Quotients: IntoIterator<Item = BigInt<Digit, DIGIT_BITNESS>>,
+    >(
+        quotients: Quotients,
+    ) -> Option<Self> {
+        let mut quotients = quotients.into_iter();
+        let first = quotients.next()?;
+        let (mut previous_numerator, mut previous_denominator) =
+            (BigInt::one(), BigInt::zero());
+        let (mut numerator, mut denominator) = (first, BigInt::one());
+        for quotient in quotients {
+            let next_numerator =
+                &(&quotient * &numerator) + &previous_numerator;
+            let next_denominator =
+                &(&quotient * &denominator) + &previous_denominator;
+            previous_numerator = replace(&mut numerator, next_numerator);
+            previous_denominator = replace(&mut denominator, next_denominator);
+        }
+        Self::new(numerator, denominator)
+    }
+}
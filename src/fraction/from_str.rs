@@ -0,0 +1,227 @@
+use std::iter::Peekable;
+use std::ops::{Mul, Neg, Sub};
+use std::str::{Chars, FromStr};
+
+use traiter::numbers::{FromStrRadix, One, Signed, Zero, Zeroable};
+
+use crate::big_int::{BigInt, TryFromStringError, TryPowError, UncheckedPow};
+
+use super::types::{
+    Fraction, FromStringConstructionError, NormalizeModuli, NormalizeSign,
+};
+
+impl<Digit, const DIGIT_BITNESS: usize> FromStr
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Signed + Zeroable,
+    BigInt<Digit, DIGIT_BITNESS>: From<u8>
+        + From<usize>
+        + FromStrRadix<Error = TryFromStringError>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Neg<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + NormalizeModuli<
+            Output = (
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            ),
+        > + NormalizeSign<
+            Output = (
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            ),
+        > + One
+        + Sub<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + UncheckedPow
+        + Zero,
+{
+    type Err = FromStringConstructionError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut characters = string.trim().chars().peekable();
+        let is_negative = parse_sign(&mut characters);
+        let integral_digits = parse_digits(&mut characters)?;
+        let (mut numerator, denominator) = if characters
+            .next_if_eq(&'/')
+            .is_some()
+        {
+            if integral_digits.is_empty() {
+                return Err(FromStringConstructionError::InvalidSymbol('/'));
+            }
+            let denominator_digits = parse_digits(&mut characters)?;
+            if denominator_digits.is_empty() {
+                return Err(to_unexpected_symbol_error(&mut characters));
+            }
+            (
+                to_big_int(&integral_digits)?,
+                to_big_int(&denominator_digits)?,
+            )
+        } else {
+            let (fractional_digits, repeating_digits) =
+                if characters.next_if_eq(&'.').is_some() {
+                    let fractional_digits = parse_digits(&mut characters)?;
+                    let repeating_digits =
+                        if characters.next_if_eq(&'(').is_some() {
+                            let repeating_digits =
+                                parse_digits(&mut characters)?;
+                            if repeating_digits.is_empty()
+                                || characters.next_if_eq(&')').is_none()
+                            {
+                                return Err(to_unexpected_symbol_error(
+                                    &mut characters,
+                                ));
+                            }
+                            repeating_digits
+                        } else {
+                            String::new()
+                        };
+                    (fractional_digits, repeating_digits)
+                } else {
+                    (String::new(), String::new())
+                };
+            if integral_digits.is_empty()
+                && fractional_digits.is_empty()
+                && repeating_digits.is_empty()
+            {
+                return Err(to_unexpected_symbol_error(&mut characters));
+            }
+            let (is_exponent_negative, exponent) = if characters
+                .next_if(|character| matches!(character, 'e' | 'E'))
+                .is_some()
+            {
+                let is_exponent_negative = parse_sign(&mut characters);
+                let exponent_digits = parse_digits(&mut characters)?;
+                if exponent_digits.is_empty() {
+                    return Err(to_unexpected_symbol_error(&mut characters));
+                }
+                (
+                    is_exponent_negative,
+                    exponent_digits
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|&exponent| exponent <= MAX_EXPONENT)
+                        .ok_or(
+                            FromStringConstructionError::ExponentOutOfBounds,
+                        )?,
+                )
+            } else {
+                (false, 0)
+            };
+            let non_repeating_digits =
+                integral_digits + fractional_digits.as_str();
+            let mut numerator = to_big_int(&non_repeating_digits)?;
+            let mut denominator = try_to_power_of_ten::<Digit, DIGIT_BITNESS>(
+                fractional_digits.len(),
+            )?;
+            if !repeating_digits.is_empty() {
+                numerator = to_big_int(
+                    &(non_repeating_digits + repeating_digits.as_str()),
+                )? - numerator;
+                denominator = denominator
+                    * (try_to_power_of_ten(repeating_digits.len())?
+                        - BigInt::one());
+            }
+            if is_exponent_negative {
+                denominator = denominator * try_to_power_of_ten(exponent)?;
+            } else {
+                numerator = numerator * try_to_power_of_ten(exponent)?;
+            }
+            (numerator, denominator)
+        };
+        if let Some(character) = characters.next() {
+            return Err(FromStringConstructionError::InvalidSymbol(character));
+        }
+        if is_negative {
+            numerator = -numerator;
+        }
+        Self::new(numerator, denominator)
+            .ok_or(FromStringConstructionError::ZeroDenominator)
+    }
+}
+
+const DIGITS_SEPARATOR: char = '_';
+/// Largest absolute value of decimal exponent,
+/// greater ones are reported before computing any power
+/// instead of exhausting time and memory.
+const MAX_EXPONENT: usize = 1 << 20;
+
+#[inline]
+fn parse_digits(
+    characters: &mut Peekable<Chars>,
+) -> Result<String, FromStringConstructionError> {
+    let mut result = String::new();
+    while let Some(character) = characters.next_if(char::is_ascii_digit) {
+        result.push(character);
+        if characters.next_if_eq(&DIGITS_SEPARATOR).is_some()
+            && !characters.peek().map_or(false, char::is_ascii_digit)
+        {
+            return Err(to_unexpected_symbol_error(characters));
+        }
+    }
+    Ok(result)
+}
+
+#[inline]
+fn parse_sign(characters: &mut Peekable<Chars>) -> bool {
+    characters.next_if_eq(&'+').is_none()
+        && characters.next_if_eq(&'-').is_some()
+}
+
+#[inline]
+fn to_big_int<Digit, const DIGIT_BITNESS: usize>(
+    digits: &str,
+) -> Result<BigInt<Digit, DIGIT_BITNESS>, FromStringConstructionError>
+where
+    BigInt<Digit, DIGIT_BITNESS>:
+        FromStrRadix<Error = TryFromStringError> + Zero,
+{
+    if digits.is_empty() {
+        Ok(BigInt::zero())
+    } else {
+        BigInt::from_str_radix(digits, 10).map_err(|error| match error {
+            TryFromStringError::InvalidDigit(character, _)
+            | TryFromStringError::InvalidSymbol(character) => {
+                FromStringConstructionError::InvalidSymbol(character)
+            }
+            TryFromStringError::NoDigits => {
+                FromStringConstructionError::UnexpectedEnd
+            }
+            TryFromStringError::OutOfMemory => {
+                FromStringConstructionError::OutOfMemory
+            }
+            TryFromStringError::BaseOutOfBounds(_)
+            | TryFromStringError::LimitExceeded => {
+                unreachable!("Decimal digits are parsed without limit.")
+            }
+        })
+    }
+}
+
+#[inline]
+fn try_to_power_of_ten<Digit, const DIGIT_BITNESS: usize>(
+    exponent: usize,
+) -> Result<BigInt<Digit, DIGIT_BITNESS>, FromStringConstructionError>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Signed,
+    BigInt<Digit, DIGIT_BITNESS>: From<u8> + From<usize> + UncheckedPow,
+{
+    BigInt::from(10u8)
+        .try_pow(&BigInt::from(exponent))
+        .map_err(|error| match error {
+            TryPowError::OutOfMemory => {
+                FromStringConstructionError::OutOfMemory
+            }
+            TryPowError::LimitExceeded | TryPowError::NegativeExponent => {
+                unreachable!("Exponent is non-negative and not limited.")
+            }
+        })
+}
+
+#[inline]
+fn to_unexpected_symbol_error(
+    characters: &mut Peekable<Chars>,
+) -> FromStringConstructionError {
+    characters.peek().copied().map_or(
+        FromStringConstructionError::UnexpectedEnd,
+        FromStringConstructionError::InvalidSymbol,
+    )
+}
@@ -0,0 +1,83 @@
+use std::mem::replace;
+use std::ops::{Add, Mul, Shl, Sub};
+
+use traiter::numbers::{
+    CheckedDivEuclid, CheckedDivRemEuclid, One, Signed, Zero,
+};
+
+use crate::big_int::BigInt;
+
+use super::types::Fraction;
+
+impl<Digit, const DIGIT_BITNESS: usize> Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + CheckedDivEuclid<Output = Option<BigInt<Digit, DIGIT_BITNESS>>>
+        + CheckedDivRemEuclid<
+            Output = Option<(
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            )>,
+        > + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Signed
+        + Sub<Output = BigInt<Digit, DIGIT_BITNESS>>,
+    BigInt<Digit, DIGIT_BITNESS>: Clone
+        + One
+        + Ord
+        + Shl<usize, Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Zero,
+{
+    pub fn limit_denominator(
+        &self,
+        max_denominator: &BigInt<Digit, DIGIT_BITNESS>,
+    ) -> Option<Self> {
+        if !max_denominator.is_positive() {
+            return None;
+        }
+        if self.denominator <= *max_denominator {
+            return Some(self.clone());
+        }
+        let (mut previous_numerator, mut previous_denominator) =
+            (BigInt::zero(), BigInt::one());
+        let (mut numerator, mut denominator) = (BigInt::one(), BigInt::zero());
+        let (mut dividend, mut divisor) =
+            (self.numerator.clone(), self.denominator.clone());
+        loop {
+            let (quotient, remainder) = unsafe {
+                (&dividend)
+                    .checked_div_rem_euclid(&divisor)
+                    .unwrap_unchecked()
+            };
+            let next_denominator =
+                &previous_denominator + &(&quotient * &denominator);
+            if next_denominator > *max_denominator {
+                break;
+            }
+            let next_numerator =
+                &previous_numerator + &(&quotient * &numerator);
+            previous_numerator = replace(&mut numerator, next_numerator);
+            previous_denominator = replace(&mut denominator, next_denominator);
+            dividend = replace(&mut divisor, remainder);
+        }
+        let step = unsafe {
+            (&(max_denominator - &previous_denominator))
+                .checked_div_euclid(&denominator)
+                .unwrap_unchecked()
+        };
+        let bound_denominator =
+            &previous_denominator + &(&step * &denominator);
+        Some(
+            if &(divisor << 1usize) * &bound_denominator <= self.denominator {
+                Self {
+                    numerator,
+                    denominator,
+                }
+            } else {
+                Self {
+                    numerator: &previous_numerator + &(&step * &numerator),
+                    denominator: bound_denominator,
+                }
+            },
+        )
+    }
+}
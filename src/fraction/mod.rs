@@ -1,4 +1,7 @@
-pub use types::{Fraction, FromFloatConstructionError};
+pub use types::{
+    Convergents, Fraction, FromFloatConstructionError,
+    FromStringConstructionError, PartialQuotients, ToFloatError,
+};
 
 mod abs;
 mod add;
@@ -10,6 +13,8 @@ mod checked_div_rem_euclid;
 mod checked_pow;
 mod checked_rem;
 mod checked_rem_euclid;
+mod checked_round;
+mod convergents;
 mod debug;
 mod display;
 mod div;
@@ -18,7 +23,10 @@ mod div_euclid;
 mod eq;
 mod floor;
 mod from;
+mod from_partial_quotients;
+mod from_str;
 mod hash;
+mod limit_denominator;
 mod mul;
 mod mul_assign;
 mod neg;
@@ -26,18 +34,32 @@ mod one;
 mod ord;
 mod partial_eq;
 mod partial_ord;
+mod partial_quotients;
 mod pow;
+mod product;
 mod rem;
 mod rem_assign;
 mod rem_euclid;
 mod round;
+mod round_to;
 mod signed;
+mod simplest_between;
+mod simplest_from_float;
+mod simplest_within;
 mod sub;
 mod sub_assign;
+mod sum;
+mod to_decimal_string;
+mod to_fixed_string;
+mod to_float_rounded;
+mod to_positional_string;
 mod trunc;
 mod try_from;
 mod try_into;
+mod try_to_float_rounded;
 mod types;
 mod unitary;
 mod zero;
 mod zeroable;
+#[cfg(feature = "zeroize")]
+mod zeroize;
@@ -0,0 +1,41 @@
+use std::mem::replace;
+
+use traiter::numbers::CheckedDivRemEuclid;
+
+use crate::big_int::BigInt;
+
+use super::types::{Fraction, PartialQuotients};
+
+impl<Digit, const DIGIT_BITNESS: usize> Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    BigInt<Digit, DIGIT_BITNESS>: Clone,
+{
+    pub fn partial_quotients(
+        &self,
+    ) -> PartialQuotients<BigInt<Digit, DIGIT_BITNESS>> {
+        PartialQuotients {
+            dividend: self.numerator.clone(),
+            divisor: self.denominator.clone(),
+        }
+    }
+}
+
+impl<Digit, const DIGIT_BITNESS: usize> Iterator
+    for PartialQuotients<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: CheckedDivRemEuclid<
+        Output = Option<(
+            BigInt<Digit, DIGIT_BITNESS>,
+            BigInt<Digit, DIGIT_BITNESS>,
+        )>,
+    >,
+{
+    type Item = BigInt<Digit, DIGIT_BITNESS>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (quotient, remainder) =
+            (&self.dividend).checked_div_rem_euclid(&self.divisor)?;
+        self.dividend = replace(&mut self.divisor, remainder);
+        Some(quotient)
+    }
+}
@@ -0,0 +1,30 @@
+use std::iter::Product;
+use std::ops::Mul;
+
+use traiter::numbers::One;
+
+use crate::big_int::BigInt;
+use crate::traits::BalancedProduct;
+
+use super::types::Fraction;
+
+impl<Digit, const DIGIT_BITNESS: usize> Product
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    Self: Mul<Output = Self> + One,
+{
+    fn product<Values: Iterator<Item = Self>>(values: Values) -> Self {
+        Self::balanced_product(values)
+    }
+}
+
+impl<'a, Digit, const DIGIT_BITNESS: usize> Product<&'a Self>
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    BigInt<Digit, DIGIT_BITNESS>: Clone,
+    Self: Mul<Output = Self> + One,
+{
+    fn product<Values: Iterator<Item = &'a Self>>(values: Values) -> Self {
+        Self::balanced_product(values.cloned())
+    }
+}
@@ -1,54 +1,40 @@
 use std::cmp::Ordering;
-use std::ops::{Add, Shl};
+use std::ops::{Add, Neg, Shl};
 
 use traiter::numbers::{
-    CheckedDivRemEuclid, One, Parity, Round, Signed, TieBreaking,
+    Abs, CheckedDivRemEuclid, One, Parity, Round, Signed, TieBreaking,
+    Zeroable,
 };
 
 use crate::big_int::BigInt;
+use crate::rounding_mode::RoundingMode;
 
 use super::types::Fraction;
 
-impl<'a, Digit, const DIGIT_BITNESS: usize> Round
-    for &'a Fraction<BigInt<Digit, DIGIT_BITNESS>>
+impl<Digit, const DIGIT_BITNESS: usize> Round
+    for &Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
-    &'a BigInt<Digit, DIGIT_BITNESS>: CheckedDivRemEuclid<
-        Output = Option<(
-            BigInt<Digit, DIGIT_BITNESS>,
-            BigInt<Digit, DIGIT_BITNESS>,
-        )>,
-    >,
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Abs<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + CheckedDivRemEuclid<
+            Output = Option<(
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            )>,
+        > + Parity
+        + Signed
+        + Zeroable,
     BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Neg<Output = BigInt<Digit, DIGIT_BITNESS>>
         + One
         + Ord
         + Shl<usize, Output = BigInt<Digit, DIGIT_BITNESS>>,
-    for<'b> &'b BigInt<Digit, DIGIT_BITNESS>: Parity + Signed,
 {
     type Output = BigInt<Digit, DIGIT_BITNESS>;
 
     fn round(self, tie_breaking: TieBreaking) -> Self::Output {
-        let (quotient, remainder) = unsafe {
-            self.numerator
-                .checked_div_rem_euclid(&self.denominator)
+        unsafe {
+            self.checked_round(RoundingMode::from(tie_breaking))
                 .unwrap_unchecked()
-        };
-        match (remainder << 1).cmp(&self.denominator) {
-            Ordering::Equal => {
-                if match tie_breaking {
-                    TieBreaking::AwayFromZero => !(&quotient).is_negative(),
-                    TieBreaking::ToEven => quotient.is_odd(),
-                    TieBreaking::ToOdd => quotient.is_even(),
-                    TieBreaking::TowardZero => (&quotient).is_negative(),
-                } {
-                    quotient + BigInt::<Digit, DIGIT_BITNESS>::one()
-                } else {
-                    quotient
-                }
-            }
-            Ordering::Greater => {
-                quotient + BigInt::<Digit, DIGIT_BITNESS>::one()
-            }
-            Ordering::Less => quotient,
         }
     }
 }
@@ -56,44 +42,12 @@ where
 impl<Digit, const DIGIT_BITNESS: usize> Round
     for Fraction<BigInt<Digit, DIGIT_BITNESS>>
 where
-    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Parity + Signed,
-    for<'a> BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
-        + CheckedDivRemEuclid<
-            &'a BigInt<Digit, DIGIT_BITNESS>,
-            Output = Option<(
-                BigInt<Digit, DIGIT_BITNESS>,
-                BigInt<Digit, DIGIT_BITNESS>,
-            )>,
-        > + One
-        + Ord
-        + Shl<usize, Output = BigInt<Digit, DIGIT_BITNESS>>,
+    for<'a> &'a Self: Round<Output = BigInt<Digit, DIGIT_BITNESS>>,
 {
     type Output = BigInt<Digit, DIGIT_BITNESS>;
 
     fn round(self, tie_breaking: TieBreaking) -> Self::Output {
-        let (quotient, remainder) = unsafe {
-            self.numerator
-                .checked_div_rem_euclid(&self.denominator)
-                .unwrap_unchecked()
-        };
-        match (remainder << 1).cmp(&self.denominator) {
-            Ordering::Equal => {
-                if match tie_breaking {
-                    TieBreaking::AwayFromZero => !(&quotient).is_negative(),
-                    TieBreaking::ToEven => quotient.is_odd(),
-                    TieBreaking::ToOdd => quotient.is_even(),
-                    TieBreaking::TowardZero => (&quotient).is_negative(),
-                } {
-                    quotient + BigInt::<Digit, DIGIT_BITNESS>::one()
-                } else {
-                    quotient
-                }
-            }
-            Ordering::Greater => {
-                quotient + BigInt::<Digit, DIGIT_BITNESS>::one()
-            }
-            Ordering::Less => quotient,
-        }
+        (&self).round(tie_breaking)
     }
 }
 
@@ -0,0 +1,68 @@
+use std::ops::{Add, Div, Mul, Neg, Shl};
+
+use traiter::numbers::{
+    Abs, CheckedDivRemEuclid, One, Parity, Pow, Signed, Zeroable,
+};
+
+use crate::big_int::BigInt;
+use crate::rounding_mode::RoundingMode;
+
+use super::types::Fraction;
+
+impl<Digit, const DIGIT_BITNESS: usize> Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Abs<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + CheckedDivRemEuclid<
+            Output = Option<(
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            )>,
+        > + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Parity
+        + Signed
+        + Zeroable,
+    BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Clone
+        + From<u32>
+        + From<usize>
+        + Neg<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + One
+        + Ord
+        + Pow<
+            BigInt<Digit, DIGIT_BITNESS>,
+            Output = BigInt<Digit, DIGIT_BITNESS>,
+        > + Shl<usize, Output = BigInt<Digit, DIGIT_BITNESS>>,
+    Self: Div<BigInt<Digit, DIGIT_BITNESS>, Output = Self>
+        + From<BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<BigInt<Digit, DIGIT_BITNESS>, Output = Self>,
+{
+    pub fn round_to(
+        &self,
+        ndigits: isize,
+        base: u32,
+        mode: RoundingMode,
+    ) -> Option<Self> {
+        if base < 2 {
+            return None;
+        }
+        let scale =
+            BigInt::from(base).pow(BigInt::from(ndigits.unsigned_abs()));
+        Some(if ndigits < 0 {
+            Self::from(
+                Self {
+                    numerator: self.numerator.clone(),
+                    denominator: &self.denominator * &scale,
+                }
+                .checked_round(mode)?,
+            ) * scale
+        } else {
+            Self::from(
+                Self {
+                    numerator: &self.numerator * &scale,
+                    denominator: self.denominator.clone(),
+                }
+                .checked_round(mode)?,
+            ) / scale
+        })
+    }
+}
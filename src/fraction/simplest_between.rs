@@ -0,0 +1,119 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use traiter::numbers::{CheckedDivRemEuclid, One, Signed, Zero, Zeroable};
+
+use crate::big_int::BigInt;
+
+use super::types::Fraction;
+
+impl<Digit, const DIGIT_BITNESS: usize> Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + CheckedDivRemEuclid<
+            Output = Option<(
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            )>,
+        > + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Neg<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Signed
+        + Sub<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Zeroable,
+    BigInt<Digit, DIGIT_BITNESS>: Clone + One + Ord + Zero,
+    Self: Ord,
+{
+    pub fn simplest_between(first: &Self, second: &Self) -> Self {
+        let (lower, upper) = if first <= second {
+            (first, second)
+        } else {
+            (second, first)
+        };
+        if !(&lower.numerator).is_positive()
+            && !(&upper.numerator).is_negative()
+        {
+            Self {
+                numerator: BigInt::zero(),
+                denominator: BigInt::one(),
+            }
+        } else if (&upper.numerator).is_negative() {
+            let (numerator, denominator) = to_simplest_positive_between(
+                -&upper.numerator,
+                upper.denominator.clone(),
+                -&lower.numerator,
+                lower.denominator.clone(),
+            );
+            Self {
+                numerator: -&numerator,
+                denominator,
+            }
+        } else {
+            let (numerator, denominator) = to_simplest_positive_between(
+                lower.numerator.clone(),
+                lower.denominator.clone(),
+                upper.numerator.clone(),
+                upper.denominator.clone(),
+            );
+            Self {
+                numerator,
+                denominator,
+            }
+        }
+    }
+}
+
+fn to_simplest_positive_between<Digit, const DIGIT_BITNESS: usize>(
+    mut lower_numerator: BigInt<Digit, DIGIT_BITNESS>,
+    mut lower_denominator: BigInt<Digit, DIGIT_BITNESS>,
+    mut upper_numerator: BigInt<Digit, DIGIT_BITNESS>,
+    mut upper_denominator: BigInt<Digit, DIGIT_BITNESS>,
+) -> (BigInt<Digit, DIGIT_BITNESS>, BigInt<Digit, DIGIT_BITNESS>)
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + CheckedDivRemEuclid<
+            Output = Option<(
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            )>,
+        > + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Sub<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Zeroable,
+    BigInt<Digit, DIGIT_BITNESS>: One + Ord + Zero,
+{
+    let mut quotients = Vec::new();
+    loop {
+        let (quotient, remainder) = unsafe {
+            (&lower_numerator)
+                .checked_div_rem_euclid(&lower_denominator)
+                .unwrap_unchecked()
+        };
+        if (&remainder).is_zero() {
+            quotients.push(quotient);
+            break;
+        }
+        let ceil = &quotient + &BigInt::one();
+        if &ceil * &upper_denominator <= upper_numerator {
+            quotients.push(ceil);
+            break;
+        }
+        let upper_remainder =
+            &upper_numerator - &(&quotient * &upper_denominator);
+        quotients.push(quotient);
+        (
+            lower_numerator,
+            lower_denominator,
+            upper_numerator,
+            upper_denominator,
+        ) = (
+            upper_denominator,
+            upper_remainder,
+            lower_denominator,
+            remainder,
+        );
+    }
+    let (mut numerator, mut denominator) = (BigInt::one(), BigInt::zero());
+    for quotient in quotients.iter().rev() {
+        (numerator, denominator) =
+            (&(quotient * &numerator) + &denominator, numerator);
+    }
+    (numerator, denominator)
+}
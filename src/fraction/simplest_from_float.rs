@@ -0,0 +1,111 @@
+use std::convert::TryFrom;
+use std::ops::{Add, Mul, Neg, Shl, Sub};
+
+use traiter::numbers::{CheckedDivRemEuclid, One, Signed, Zero, Zeroable};
+
+use crate::big_int::BigInt;
+
+use super::types::{Fraction, FromFloatConstructionError};
+
+macro_rules! simplest_from_float_impl {
+    ($($float:ty => $bits:ty, $method:ident;)*) => ($(
+        impl<Digit, const DIGIT_BITNESS: usize>
+            Fraction<BigInt<Digit, DIGIT_BITNESS>>
+        where
+            for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+                + CheckedDivRemEuclid<
+                    Output = Option<(
+                        BigInt<Digit, DIGIT_BITNESS>,
+                        BigInt<Digit, DIGIT_BITNESS>,
+                    )>,
+                > + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+                + Neg<Output = BigInt<Digit, DIGIT_BITNESS>>
+                + Signed
+                + Sub<Output = BigInt<Digit, DIGIT_BITNESS>>
+                + Zeroable,
+            BigInt<Digit, DIGIT_BITNESS>: Clone
+                + One
+                + Ord
+                + Shl<usize, Output = BigInt<Digit, DIGIT_BITNESS>>
+                + Zero,
+            for<'a> &'a Self: Add<Output = Self> + Sub<Output = Self>,
+            Self: Ord + TryFrom<$float, Error = FromFloatConstructionError>,
+        {
+            pub fn $method(
+                value: $float,
+            ) -> Result<Self, FromFloatConstructionError> {
+                const MANTISSA_DIGITS: isize =
+                    <$float>::MANTISSA_DIGITS as isize;
+                const MIN_EXP: isize = <$float>::MIN_EXP as isize;
+                let exact = Self::try_from(value)?;
+                let bits = value.to_bits() & !(1 << (<$bits>::BITS - 1));
+                if bits == 0 {
+                    return Ok(exact);
+                }
+                let biased_exponent = (bits >> (MANTISSA_DIGITS - 1)) as isize;
+                let exponent =
+                    biased_exponent.max(1) - 1 + MIN_EXP - MANTISSA_DIGITS;
+                let to_power_of_two = |exponent: isize| {
+                    if exponent < 0 {
+                        Self {
+                            numerator: BigInt::one(),
+                            denominator: BigInt::one()
+                                << exponent.unsigned_abs(),
+                        }
+                    } else {
+                        Self {
+                            numerator: BigInt::one()
+                                << exponent.unsigned_abs(),
+                            denominator: BigInt::one(),
+                        }
+                    }
+                };
+                let modulus = Self {
+                    numerator: if value.is_sign_negative() {
+                        -&exact.numerator
+                    } else {
+                        exact.numerator
+                    },
+                    denominator: exact.denominator,
+                };
+                let upper_gap = to_power_of_two(exponent - 1);
+                let lower_gap = if bits & ((1 << (MANTISSA_DIGITS - 1)) - 1)
+                    == 0
+                    && biased_exponent > 1
+                {
+                    to_power_of_two(exponent - 2)
+                } else {
+                    upper_gap.clone()
+                };
+                let (lower, upper) = if bits & 1 == 0 {
+                    (&modulus - &lower_gap, &modulus + &upper_gap)
+                } else {
+                    let epsilon = Self {
+                        numerator: BigInt::one(),
+                        denominator: (&modulus.denominator
+                            * &modulus.denominator)
+                            << 2usize,
+                    };
+                    (
+                        &(&modulus - &lower_gap) + &epsilon,
+                        &(&modulus + &upper_gap) - &epsilon,
+                    )
+                };
+                let result = Self::simplest_between(&lower, &upper);
+                Ok(if value.is_sign_negative() {
+                    Self {
+                        numerator: -&result.numerator,
+                        denominator: result.denominator,
+                    }
+                } else {
+                    result
+                })
+            }
+        }
+    )*)
+}
+
+simplest_from_float_impl!(
+    f32 => u32, simplest_from_f32;
+    f64 => u64, simplest_from_f64;
+);
@@ -0,0 +1,36 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use traiter::numbers::{CheckedDivRemEuclid, One, Signed, Zero, Zeroable};
+
+use crate::big_int::BigInt;
+
+use super::types::Fraction;
+
+impl<Digit, const DIGIT_BITNESS: usize> Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + CheckedDivRemEuclid<
+            Output = Option<(
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            )>,
+        > + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Neg<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Signed
+        + Sub<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Zeroable,
+    BigInt<Digit, DIGIT_BITNESS>: Clone + One + Ord + Zero,
+    for<'a> &'a Self: Add<Output = Self> + Signed + Sub<Output = Self>,
+    Self: Ord,
+{
+    pub fn simplest_within(&self, tolerance: &Self) -> Option<Self> {
+        if tolerance.is_negative() {
+            None
+        } else {
+            Some(Self::simplest_between(
+                &(self - tolerance),
+                &(self + tolerance),
+            ))
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::iter::Sum;
+use std::ops::{AddAssign, Mul, MulAssign};
+
+use traiter::numbers::{CheckedDiv, Gcd, One, Unitary, Zero};
+
+use crate::big_int::BigInt;
+
+use super::types::{Fraction, NormalizeModuli};
+
+impl<Digit, const DIGIT_BITNESS: usize> Sum
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: CheckedDiv<Output = Option<BigInt<Digit, DIGIT_BITNESS>>>
+        + Gcd<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Unitary,
+    for<'a> BigInt<Digit, DIGIT_BITNESS>: AddAssign
+        + MulAssign<&'a BigInt<Digit, DIGIT_BITNESS>>
+        + NormalizeModuli<
+            Output = (
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            ),
+        > + One
+        + Zero,
+{
+    fn sum<Values: Iterator<Item = Self>>(values: Values) -> Self {
+        let (mut numerator, mut denominator) = (BigInt::zero(), BigInt::one());
+        for value in values {
+            add_to_common_denominator(
+                &mut numerator,
+                &mut denominator,
+                &value.numerator,
+                &value.denominator,
+            );
+        }
+        let (numerator, denominator) = numerator.normalize_moduli(denominator);
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+}
+
+impl<'a, Digit, const DIGIT_BITNESS: usize> Sum<&'a Self>
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'b> &'b BigInt<Digit, DIGIT_BITNESS>: CheckedDiv<Output = Option<BigInt<Digit, DIGIT_BITNESS>>>
+        + Gcd<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Unitary,
+    for<'b> BigInt<Digit, DIGIT_BITNESS>: AddAssign
+        + MulAssign<&'b BigInt<Digit, DIGIT_BITNESS>>
+        + NormalizeModuli<
+            Output = (
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            ),
+        > + One
+        + Zero,
+{
+    fn sum<Values: Iterator<Item = &'a Self>>(values: Values) -> Self {
+        let (mut numerator, mut denominator) = (BigInt::zero(), BigInt::one());
+        for value in values {
+            add_to_common_denominator(
+                &mut numerator,
+                &mut denominator,
+                &value.numerator,
+                &value.denominator,
+            );
+        }
+        let (numerator, denominator) = numerator.normalize_moduli(denominator);
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+}
+
+fn add_to_common_denominator<Digit, const DIGIT_BITNESS: usize>(
+    numerator: &mut BigInt<Digit, DIGIT_BITNESS>,
+    denominator: &mut BigInt<Digit, DIGIT_BITNESS>,
+    value_numerator: &BigInt<Digit, DIGIT_BITNESS>,
+    value_denominator: &BigInt<Digit, DIGIT_BITNESS>,
+) where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: CheckedDiv<Output = Option<BigInt<Digit, DIGIT_BITNESS>>>
+        + Gcd<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Unitary,
+    for<'a> BigInt<Digit, DIGIT_BITNESS>:
+        AddAssign + MulAssign<&'a BigInt<Digit, DIGIT_BITNESS>>,
+{
+    let gcd = (&*denominator).gcd(value_denominator);
+    if gcd.is_one() {
+        *numerator *= value_denominator;
+        *numerator += value_numerator * &*denominator;
+        *denominator *= value_denominator;
+    } else {
+        let denominator_cofactor =
+            unsafe { (&*denominator).checked_div(&gcd).unwrap_unchecked() };
+        let value_denominator_cofactor =
+            unsafe { value_denominator.checked_div(&gcd).unwrap_unchecked() };
+        *numerator *= &value_denominator_cofactor;
+        *numerator += value_numerator * &denominator_cofactor;
+        *denominator *= &value_denominator_cofactor;
+    }
+}
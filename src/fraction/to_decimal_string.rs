@@ -0,0 +1,94 @@
+use std::fmt::Display;
+use std::ops::Mul;
+
+use traiter::numbers::{
+    Abs, CheckedDivRemEuclid, Pow, Signed, Unitary, Zeroable,
+};
+
+use crate::big_int::BigInt;
+
+use super::types::Fraction;
+
+impl<Digit, const DIGIT_BITNESS: usize> Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Abs<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + CheckedDivRemEuclid<
+            Output = Option<(
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            )>,
+        > + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Signed
+        + Unitary
+        + Zeroable,
+    BigInt<Digit, DIGIT_BITNESS>: Clone
+        + Display
+        + From<u8>
+        + From<usize>
+        + Pow<
+            BigInt<Digit, DIGIT_BITNESS>,
+            Output = BigInt<Digit, DIGIT_BITNESS>,
+        >,
+{
+    pub fn to_decimal_string(&self) -> Option<String> {
+        let (denominator, twos_count) =
+            to_without_factor(self.denominator.clone(), 2);
+        let (denominator, fives_count) = to_without_factor(denominator, 5);
+        if !(&denominator).is_one() {
+            return None;
+        }
+        let digits_count = twos_count.max(fives_count);
+        let scaled_numerator = &(&(&self.numerator).abs()
+            * &BigInt::from(2u8).pow(BigInt::from(digits_count - twos_count)))
+            * &BigInt::from(5u8).pow(BigInt::from(digits_count - fives_count));
+        let digits = format!(
+            "{:0>width$}",
+            scaled_numerator.to_string(),
+            width = digits_count + 1
+        );
+        let (integral_digits, fractional_digits) =
+            digits.split_at(digits.len() - digits_count);
+        Some(format!(
+            "{}{}{}{}",
+            if (&self.numerator).is_negative() {
+                "-"
+            } else {
+                ""
+            },
+            integral_digits,
+            if fractional_digits.is_empty() {
+                ""
+            } else {
+                "."
+            },
+            fractional_digits
+        ))
+    }
+}
+
+fn to_without_factor<Digit, const DIGIT_BITNESS: usize>(
+    mut value: BigInt<Digit, DIGIT_BITNESS>,
+    factor: u8,
+) -> (BigInt<Digit, DIGIT_BITNESS>, usize)
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: CheckedDivRemEuclid<
+            Output = Option<(
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            )>,
+        > + Zeroable,
+    BigInt<Digit, DIGIT_BITNESS>: From<u8>,
+{
+    let factor = BigInt::from(factor);
+    let mut count = 0;
+    while let Some((quotient, remainder)) =
+        (&value).checked_div_rem_euclid(&factor)
+    {
+        if !(&remainder).is_zero() {
+            break;
+        }
+        value = quotient;
+        count += 1;
+    }
+    (value, count)
+}
@@ -0,0 +1,51 @@
+use std::fmt::Display;
+use std::ops::Mul;
+
+use traiter::numbers::{Abs, Pow, Round, Signed, TieBreaking};
+
+use crate::big_int::BigInt;
+
+use super::types::Fraction;
+
+impl<Digit, const DIGIT_BITNESS: usize> Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Abs<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Signed,
+    BigInt<Digit, DIGIT_BITNESS>: Clone
+        + Display
+        + From<u8>
+        + From<usize>
+        + Pow<
+            BigInt<Digit, DIGIT_BITNESS>,
+            Output = BigInt<Digit, DIGIT_BITNESS>,
+        >,
+    Self: Round<Output = BigInt<Digit, DIGIT_BITNESS>>,
+{
+    pub fn to_fixed_string(&self, ndigits: usize) -> String {
+        let scale = BigInt::from(10u8).pow(BigInt::from(ndigits));
+        let scaled_value = Self {
+            numerator: &self.numerator * &scale,
+            denominator: self.denominator.clone(),
+        }
+        .round(TieBreaking::ToEven);
+        let digits = format!(
+            "{:0>width$}",
+            (&scaled_value).abs().to_string(),
+            width = ndigits + 1
+        );
+        let (integral_digits, fractional_digits) =
+            digits.split_at(digits.len() - ndigits);
+        format!(
+            "{}{}{}{}",
+            if (&scaled_value).is_negative() {
+                "-"
+            } else {
+                ""
+            },
+            integral_digits,
+            if ndigits > 0 { "." } else { "" },
+            fractional_digits
+        )
+    }
+}
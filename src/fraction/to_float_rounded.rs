@@ -0,0 +1,70 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::ops::{Add, Shl};
+
+#[cfg(feature = "half")]
+use half::{bf16, f16};
+use traiter::numbers::{
+    Abs, BitLength, CheckedDivRemEuclid, One, Parity, Signed, Zeroable,
+};
+
+use crate::big_int::BigInt;
+use crate::binary128::Binary128;
+use crate::constants::INEXACT_ROUNDING_ERROR_MESSAGE;
+use crate::rounding_mode::RoundingMode;
+
+use super::types::Fraction;
+
+macro_rules! to_float_rounded_impl {
+    ($($float:ty => $bits:ty, $method:ident, $try_method:ident;)*) => ($(
+        impl<Digit, const DIGIT_BITNESS: usize>
+            Fraction<BigInt<Digit, DIGIT_BITNESS>>
+        where
+            for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Abs<Output = BigInt<Digit, DIGIT_BITNESS>>
+                + BitLength<Output = BigInt<Digit, DIGIT_BITNESS>>
+                + CheckedDivRemEuclid<
+                    Output = Option<(
+                        BigInt<Digit, DIGIT_BITNESS>,
+                        BigInt<Digit, DIGIT_BITNESS>,
+                    )>,
+                > + Signed
+                + Zeroable,
+            BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+                + Clone
+                + One
+                + Ord
+                + Parity
+                + Shl<usize, Output = BigInt<Digit, DIGIT_BITNESS>>,
+            $bits: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+            usize: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+        {
+            /// Saturates to infinity on overflow.
+            ///
+            /// # Panics
+            /// In `Unnecessary` mode if the value
+            /// is not exactly representable, overflowing values included.
+            pub fn $method(&self, mode: RoundingMode) -> ($float, Ordering) {
+                self.$try_method(mode).unwrap_or_else(|_| {
+                    if mode == RoundingMode::Unnecessary {
+                        panic!("{}", INEXACT_ROUNDING_ERROR_MESSAGE);
+                    } else if (&self.numerator).is_negative() {
+                        (<$float>::NEG_INFINITY, Ordering::Less)
+                    } else {
+                        (<$float>::INFINITY, Ordering::Greater)
+                    }
+                })
+            }
+        }
+    )*)
+}
+
+to_float_rounded_impl!(
+    Binary128 => u128, to_binary128_rounded, try_to_binary128_rounded;
+    f32 => u32, to_f32_rounded, try_to_f32_rounded;
+    f64 => u64, to_f64_rounded, try_to_f64_rounded;
+);
+#[cfg(feature = "half")]
+to_float_rounded_impl!(
+    bf16 => u16, to_bf16_rounded, try_to_bf16_rounded;
+    f16 => u16, to_f16_rounded, try_to_f16_rounded;
+);
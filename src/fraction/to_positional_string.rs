@@ -0,0 +1,202 @@
+use std::convert::TryFrom;
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+use traiter::numbers::{
+    Abs, CheckedDivRemEuclid, CheckedPowRemEuclid, Gcd, One, Pow, Signed,
+    Unitary, Zeroable,
+};
+
+use crate::big_int::{
+    BigInt, CheckedPowRemEuclidError, MaybeReduceDigits, ToBaseString,
+    ToStringError,
+};
+
+use super::types::Fraction;
+
+impl<Digit: MaybeReduceDigits<usize>, const DIGIT_BITNESS: usize>
+    Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Abs<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + CheckedDivRemEuclid<
+            Output = Option<(
+                BigInt<Digit, DIGIT_BITNESS>,
+                BigInt<Digit, DIGIT_BITNESS>,
+            )>,
+        > + Gcd<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Signed
+        + Unitary
+        + Zeroable,
+    BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + CheckedPowRemEuclid<
+            BigInt<Digit, DIGIT_BITNESS>,
+            BigInt<Digit, DIGIT_BITNESS>,
+            Output = Result<
+                BigInt<Digit, DIGIT_BITNESS>,
+                CheckedPowRemEuclidError,
+            >,
+        > + Clone
+        + Div<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + From<u32>
+        + From<usize>
+        + Mul<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + One
+        + Ord
+        + Pow<
+            BigInt<Digit, DIGIT_BITNESS>,
+            Output = BigInt<Digit, DIGIT_BITNESS>,
+        > + Rem<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + Sub<Output = BigInt<Digit, DIGIT_BITNESS>>
+        + ToBaseString,
+{
+    pub fn to_positional_string(
+        &self,
+        base: u32,
+    ) -> Result<String, ToStringError> {
+        let radix = BigInt::<Digit, DIGIT_BITNESS>::from(base);
+        let (integral_part, remainder) = unsafe {
+            (&(&self.numerator).abs())
+                .checked_div_rem_euclid(&self.denominator)
+                .unwrap_unchecked()
+        };
+        let mut result = if (&self.numerator).is_negative() {
+            String::from("-")
+        } else {
+            String::new()
+        };
+        result.push_str(&integral_part.to_str_radix(base)?);
+        let mut repeating_denominator = self.denominator.clone();
+        let mut non_repeating_digits_count = 0usize;
+        loop {
+            let gcd = (&repeating_denominator).gcd(&radix);
+            if (&gcd).is_one() {
+                break;
+            }
+            repeating_denominator = repeating_denominator / gcd;
+            non_repeating_digits_count += 1;
+        }
+        let period = if (&repeating_denominator).is_one() {
+            0usize
+        } else {
+            usize::try_from(Self::to_multiplicative_order(
+                &radix,
+                &repeating_denominator,
+            ))
+            .expect("Period should not exceed string capacity.")
+        };
+        if non_repeating_digits_count == 0 && period == 0 {
+            return Ok(result);
+        }
+        result.push('.');
+        let (non_repeating_part, repeating_numerator) = unsafe {
+            (&(remainder
+                * radix.clone().pow(BigInt::from(non_repeating_digits_count))))
+                .checked_div_rem_euclid(&self.denominator)
+                .unwrap_unchecked()
+        };
+        if non_repeating_digits_count > 0 {
+            push_zero_padded(
+                &mut result,
+                &non_repeating_part.to_str_radix(base)?,
+                non_repeating_digits_count,
+            );
+        }
+        if period > 0 {
+            let repeating_part = repeating_numerator
+                / (self.denominator.clone() / repeating_denominator.clone())
+                * (radix.pow(BigInt::from(period)) - BigInt::one())
+                / repeating_denominator;
+            result.push('(');
+            push_zero_padded(
+                &mut result,
+                &repeating_part.to_str_radix(base)?,
+                period,
+            );
+            result.push(')');
+        }
+        Ok(result)
+    }
+
+    /// Returns the smallest positive exponent `e` with
+    /// `base ** e % modulus == 1` for coprime `base` and `modulus > 1`.
+    ///
+    /// The order divides Euler's totient of the modulus,
+    /// so it is found by stripping prime factors from the totient
+    /// while the reduced exponent still gives the unit power.
+    fn to_multiplicative_order(
+        base: &BigInt<Digit, DIGIT_BITNESS>,
+        modulus: &BigInt<Digit, DIGIT_BITNESS>,
+    ) -> BigInt<Digit, DIGIT_BITNESS> {
+        let mut totient = BigInt::one();
+        let mut totient_prime_factors = Vec::new();
+        for (prime, multiplicity) in Self::to_prime_factors(modulus.clone()) {
+            let prime_decrement = prime.clone() - BigInt::one();
+            totient = totient
+                * prime_decrement.clone()
+                * prime.clone().pow(BigInt::from(multiplicity - 1));
+            if multiplicity > 1 {
+                totient_prime_factors.push(prime);
+            }
+            totient_prime_factors.extend(
+                Self::to_prime_factors(prime_decrement)
+                    .into_iter()
+                    .map(|(factor, _)| factor),
+            );
+        }
+        totient_prime_factors.sort();
+        totient_prime_factors.dedup();
+        let mut result = totient;
+        for factor in totient_prime_factors {
+            while (&(result.clone() % factor.clone())).is_zero() {
+                let candidate = result.clone() / factor.clone();
+                let power = unsafe {
+                    base.clone()
+                        .checked_pow_rem_euclid(
+                            candidate.clone(),
+                            modulus.clone(),
+                        )
+                        .unwrap_unchecked()
+                };
+                if !(&power).is_one() {
+                    break;
+                }
+                result = candidate;
+            }
+        }
+        result
+    }
+
+    /// Returns prime factors of a positive value with their multiplicities
+    /// in ascending order using trial division.
+    fn to_prime_factors(
+        mut value: BigInt<Digit, DIGIT_BITNESS>,
+    ) -> Vec<(BigInt<Digit, DIGIT_BITNESS>, usize)> {
+        let mut result = Vec::new();
+        let mut divisor = BigInt::from(2u32);
+        let mut step = BigInt::one();
+        while divisor.clone() * divisor.clone() <= value {
+            let mut multiplicity = 0usize;
+            while (&(value.clone() % divisor.clone())).is_zero() {
+                value = value / divisor.clone();
+                multiplicity += 1;
+            }
+            if multiplicity > 0 {
+                result.push((divisor.clone(), multiplicity));
+            }
+            divisor = divisor + step;
+            step = BigInt::from(2u32);
+        }
+        if !(&value).is_one() {
+            result.push((value, 1));
+        }
+        result
+    }
+}
+
+/// Formatting width is limited to `u16::MAX`,
+/// so digits are padded by hand.
+fn push_zero_padded(result: &mut String, digits: &str, width: usize) {
+    result.extend(
+        std::iter::repeat('0').take(width.saturating_sub(digits.len())),
+    );
+    result.push_str(digits);
+}
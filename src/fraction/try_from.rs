@@ -1,17 +1,25 @@
 use std::convert::TryFrom;
+use std::ops::{Neg, Shl};
+use std::str::FromStr;
 
-use traiter::numbers::{CheckedShl, FractExp, One};
+#[cfg(feature = "half")]
+use half::{bf16, f16};
+use traiter::numbers::{CheckedShl, FractExp, One, Zero};
 
 use crate::big_int::{BigInt, ShlError};
+use crate::binary128::Binary128;
 use crate::contracts::is_signed;
-use crate::traits::UncheckedToInt;
+use crate::traits::{ToFloat, UncheckedToInt};
 
-use super::types::{Fraction, FromFloatConstructionError, NormalizeModuli};
+use super::types::{
+    Fraction, FromFloatConstructionError, FromStringConstructionError,
+    NormalizeModuli,
+};
 
 macro_rules! big_int_fraction_try_from_float_impl {
     ($($float:ty)*) => ($(
         impl<
-                Digit: Copy + TryFrom<usize>,
+                Digit: Copy + ToFloat<$float> + TryFrom<usize>,
                 const DIGIT_BITNESS: usize,
             > TryFrom<$float> for Fraction<BigInt<Digit, DIGIT_BITNESS>>
         where
@@ -19,7 +27,7 @@ macro_rules! big_int_fraction_try_from_float_impl {
                 u32,
                 Output = Result<BigInt<Digit, DIGIT_BITNESS>, ShlError>,
             > + One + TryFrom<$float>,
-            $float: From<Digit> + UncheckedToInt<Digit>,
+            $float: UncheckedToInt<Digit>,
         {
             type Error = FromFloatConstructionError;
 
@@ -65,6 +73,90 @@ macro_rules! big_int_fraction_try_from_float_impl {
 
 big_int_fraction_try_from_float_impl!(f32 f64);
 
+macro_rules! big_int_fraction_try_from_float_bits_impl {
+    ($($float:ty => $bits:ty)*) => ($(
+        impl<Digit, const DIGIT_BITNESS: usize> TryFrom<$float>
+            for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+        where
+            BigInt<Digit, DIGIT_BITNESS>: From<$bits>
+                + Neg<Output = BigInt<Digit, DIGIT_BITNESS>>
+                + One
+                + Shl<usize, Output = BigInt<Digit, DIGIT_BITNESS>>
+                + Zero,
+        {
+            type Error = FromFloatConstructionError;
+
+            fn try_from(value: $float) -> Result<Self, Self::Error> {
+                const MANTISSA_DIGITS: isize =
+                    <$float>::MANTISSA_DIGITS as isize;
+                const MIN_EXP: isize = <$float>::MIN_EXP as isize;
+                if value.is_infinite() {
+                    return Err(FromFloatConstructionError::Infinity);
+                } else if value.is_nan() {
+                    return Err(FromFloatConstructionError::NaN);
+                }
+                let bits = value.to_bits();
+                let fraction_bits =
+                    bits & ((1 << (MANTISSA_DIGITS - 1)) - 1);
+                let biased_exponent = ((bits & !(1 << (<$bits>::BITS - 1)))
+                    >> (MANTISSA_DIGITS - 1))
+                    as isize;
+                let (significand, exponent) = if biased_exponent == 0 {
+                    (fraction_bits, MIN_EXP - MANTISSA_DIGITS)
+                } else {
+                    (
+                        fraction_bits | (1 << (MANTISSA_DIGITS - 1)),
+                        biased_exponent - 1 + MIN_EXP - MANTISSA_DIGITS,
+                    )
+                };
+                if significand == 0 {
+                    return Ok(Self {
+                        numerator: BigInt::zero(),
+                        denominator: BigInt::one(),
+                    });
+                }
+                let trailing_zeros_count = significand.trailing_zeros();
+                let exponent = exponent + (trailing_zeros_count as isize);
+                let numerator =
+                    BigInt::from(significand >> trailing_zeros_count);
+                let numerator = if value.is_sign_negative() {
+                    -numerator
+                } else {
+                    numerator
+                };
+                Ok(if exponent < 0 {
+                    Self {
+                        numerator,
+                        denominator: BigInt::one()
+                            << exponent.unsigned_abs(),
+                    }
+                } else {
+                    Self {
+                        numerator: numerator << exponent.unsigned_abs(),
+                        denominator: BigInt::one(),
+                    }
+                })
+            }
+        }
+    )*)
+}
+
+big_int_fraction_try_from_float_bits_impl!(Binary128 => u128);
+#[cfg(feature = "half")]
+big_int_fraction_try_from_float_bits_impl!(bf16 => u16 f16 => u16);
+
+impl<Digit, const DIGIT_BITNESS: usize> TryFrom<&str>
+    for Fraction<BigInt<Digit, DIGIT_BITNESS>>
+where
+    Self: FromStr<Err = FromStringConstructionError>,
+{
+    type Error = FromStringConstructionError;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        string.parse()
+    }
+}
+
 macro_rules! try_integer_fraction_from_float_impl {
     ($float:ty => $($integer:ty)*) => ($(
         impl TryFrom<$float> for Fraction<$integer> {
@@ -1,9 +1,18 @@
 use std::convert::TryFrom;
+use std::ops::{Add, Shl};
+
+#[cfg(feature = "half")]
+use half::{bf16, f16};
+use traiter::numbers::{
+    Abs, BitLength, CheckedDivRemEuclid, One, Parity, Signed, Zeroable,
+};
 
 use crate::big_int::BigInt;
+use crate::binary128::Binary128;
+use crate::rounding_mode::RoundingMode;
 use crate::traits::TryDivAsFloat;
 
-use super::types::Fraction;
+use super::types::{Fraction, ToFloatError};
 
 macro_rules! try_float_from_big_int_fraction_impl {
     ($($float:ty)*) => ($(
@@ -45,6 +54,83 @@ macro_rules! try_float_from_big_int_fraction_impl {
 
 try_float_from_big_int_fraction_impl!(f32 f64);
 
+macro_rules! try_float_bits_from_big_int_fraction_impl {
+    ($($float:ty => $bits:ty, $method:ident;)*) => ($(
+        impl<Digit, const DIGIT_BITNESS: usize>
+            TryFrom<Fraction<BigInt<Digit, DIGIT_BITNESS>>> for $float
+        where
+            for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Abs<Output = BigInt<Digit, DIGIT_BITNESS>>
+                + BitLength<Output = BigInt<Digit, DIGIT_BITNESS>>
+                + CheckedDivRemEuclid<
+                    Output = Option<(
+                        BigInt<Digit, DIGIT_BITNESS>,
+                        BigInt<Digit, DIGIT_BITNESS>,
+                    )>,
+                > + Signed
+                + Zeroable,
+            BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+                + Clone
+                + One
+                + Ord
+                + Parity
+                + Shl<usize, Output = BigInt<Digit, DIGIT_BITNESS>>,
+            $bits: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+            usize: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+        {
+            type Error = ToFloatError;
+
+            fn try_from(
+                value: Fraction<BigInt<Digit, DIGIT_BITNESS>>,
+            ) -> Result<$float, Self::Error> {
+                value
+                    .$method(RoundingMode::HalfEven)
+                    .map(|(result, _)| result)
+            }
+        }
+
+        impl<Digit, const DIGIT_BITNESS: usize>
+            TryFrom<&Fraction<BigInt<Digit, DIGIT_BITNESS>>> for $float
+        where
+            for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Abs<Output = BigInt<Digit, DIGIT_BITNESS>>
+                + BitLength<Output = BigInt<Digit, DIGIT_BITNESS>>
+                + CheckedDivRemEuclid<
+                    Output = Option<(
+                        BigInt<Digit, DIGIT_BITNESS>,
+                        BigInt<Digit, DIGIT_BITNESS>,
+                    )>,
+                > + Signed
+                + Zeroable,
+            BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+                + Clone
+                + One
+                + Ord
+                + Parity
+                + Shl<usize, Output = BigInt<Digit, DIGIT_BITNESS>>,
+            $bits: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+            usize: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+        {
+            type Error = ToFloatError;
+
+            fn try_from(
+                value: &Fraction<BigInt<Digit, DIGIT_BITNESS>>,
+            ) -> Result<$float, Self::Error> {
+                value
+                    .$method(RoundingMode::HalfEven)
+                    .map(|(result, _)| result)
+            }
+        }
+    )*)
+}
+
+try_float_bits_from_big_int_fraction_impl!(
+    Binary128 => u128, try_to_binary128_rounded;
+);
+#[cfg(feature = "half")]
+try_float_bits_from_big_int_fraction_impl!(
+    bf16 => u16, try_to_bf16_rounded;
+    f16 => u16, try_to_f16_rounded;
+);
+
 macro_rules! try_float_from_integer_fraction_impl {
     ($float:ty => $($integer:ty)*) => ($(
         impl TryFrom<Fraction<$integer>> for $float {
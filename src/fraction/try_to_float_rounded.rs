@@ -0,0 +1,158 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::ops::{Add, Shl};
+
+#[cfg(feature = "half")]
+use half::{bf16, f16};
+use traiter::numbers::{
+    Abs, BitLength, CheckedDivRemEuclid, One, Parity, Signed, Zeroable,
+};
+
+use crate::big_int::BigInt;
+use crate::binary128::Binary128;
+use crate::rounding_mode::RoundingMode;
+
+use super::types::{Fraction, ToFloatError};
+
+macro_rules! try_to_float_rounded_impl {
+    ($($float:ty => $bits:ty, $method:ident;)*) => ($(
+        impl<Digit, const DIGIT_BITNESS: usize>
+            Fraction<BigInt<Digit, DIGIT_BITNESS>>
+        where
+            for<'a> &'a BigInt<Digit, DIGIT_BITNESS>: Abs<Output = BigInt<Digit, DIGIT_BITNESS>>
+                + BitLength<Output = BigInt<Digit, DIGIT_BITNESS>>
+                + CheckedDivRemEuclid<
+                    Output = Option<(
+                        BigInt<Digit, DIGIT_BITNESS>,
+                        BigInt<Digit, DIGIT_BITNESS>,
+                    )>,
+                > + Signed
+                + Zeroable,
+            BigInt<Digit, DIGIT_BITNESS>: Add<Output = BigInt<Digit, DIGIT_BITNESS>>
+                + Clone
+                + One
+                + Ord
+                + Parity
+                + Shl<usize, Output = BigInt<Digit, DIGIT_BITNESS>>,
+            $bits: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+            usize: TryFrom<BigInt<Digit, DIGIT_BITNESS>>,
+        {
+            pub fn $method(
+                &self,
+                mode: RoundingMode,
+            ) -> Result<($float, Ordering), ToFloatError> {
+                const MANTISSA_DIGITS: isize = <$float>::MANTISSA_DIGITS as isize;
+                const MAX_EXP: isize = <$float>::MAX_EXP as isize;
+                const MIN_EXP: isize = <$float>::MIN_EXP as isize;
+                if (&self.numerator).is_zero() {
+                    return Ok((<$float>::from_bits(0), Ordering::Equal));
+                }
+                let is_negative = (&self.numerator).is_negative();
+                let to_ordering = |is_exact: bool, is_rounded_up: bool| {
+                    if is_exact {
+                        Ordering::Equal
+                    } else if is_rounded_up != is_negative {
+                        Ordering::Greater
+                    } else {
+                        Ordering::Less
+                    }
+                };
+                let to_float = |magnitude_bits: $bits| {
+                    <$float>::from_bits(if is_negative {
+                        magnitude_bits | (1 << (<$bits>::BITS - 1))
+                    } else {
+                        magnitude_bits
+                    })
+                };
+                let infinity_bits = ((2 * MAX_EXP - 1) as $bits)
+                    << (MANTISSA_DIGITS - 1);
+                let to_overflow_result = || {
+                    if mode.rounds_overflow_toward_zero(is_negative) {
+                        Ok((
+                            to_float(infinity_bits - 1),
+                            to_ordering(false, false),
+                        ))
+                    } else {
+                        Err(ToFloatError::TooLarge)
+                    }
+                };
+                let dividend = (&self.numerator).abs();
+                let divisor = &self.denominator;
+                let bit_lengths_difference = unsafe {
+                    (usize::try_from((&dividend).bit_length())
+                        .unwrap_unchecked() as isize)
+                        - (usize::try_from(divisor.bit_length())
+                            .unwrap_unchecked() as isize)
+                };
+                if bit_lengths_difference > MAX_EXP {
+                    return to_overflow_result();
+                }
+                let exponent = bit_lengths_difference
+                    + isize::from(if bit_lengths_difference >= 0 {
+                        dividend
+                            >= divisor.clone()
+                                << bit_lengths_difference.unsigned_abs()
+                    } else {
+                        dividend.clone()
+                            << bit_lengths_difference.unsigned_abs()
+                            >= *divisor
+                    });
+                if exponent > MAX_EXP {
+                    return to_overflow_result();
+                }
+                let shift =
+                    (exponent - MANTISSA_DIGITS).max(MIN_EXP - MANTISSA_DIGITS);
+                let (dividend, divisor) = if shift >= 0 {
+                    (dividend, divisor.clone() << shift.unsigned_abs())
+                } else {
+                    (dividend << shift.unsigned_abs(), divisor.clone())
+                };
+                let (quotient, remainder) = unsafe {
+                    (&dividend)
+                        .checked_div_rem_euclid(&divisor)
+                        .unwrap_unchecked()
+                };
+                let is_exact = (&remainder).is_zero();
+                let is_rounded_up = mode
+                    .rounds_magnitude_up(
+                        is_negative,
+                        quotient.clone().is_odd(),
+                        is_exact,
+                        (remainder << 1usize).cmp(&divisor),
+                    )
+                    .ok_or(ToFloatError::Inexact)?;
+                let significand = unsafe {
+                    <$bits>::try_from(if is_rounded_up {
+                        quotient + BigInt::one()
+                    } else {
+                        quotient
+                    })
+                    .unwrap_unchecked()
+                };
+                let magnitude_bits = (((shift
+                    - (MIN_EXP - MANTISSA_DIGITS))
+                    as $bits)
+                    << (MANTISSA_DIGITS - 1))
+                    + significand;
+                if magnitude_bits >= infinity_bits {
+                    return to_overflow_result();
+                }
+                Ok((
+                    to_float(magnitude_bits),
+                    to_ordering(is_exact, is_rounded_up),
+                ))
+            }
+        }
+    )*)
+}
+
+try_to_float_rounded_impl!(
+    Binary128 => u128, try_to_binary128_rounded;
+    f32 => u32, try_to_f32_rounded;
+    f64 => u64, try_to_f64_rounded;
+);
+#[cfg(feature = "half")]
+try_to_float_rounded_impl!(
+    bf16 => u16, try_to_bf16_rounded;
+    f16 => u16, try_to_f16_rounded;
+);
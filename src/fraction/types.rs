@@ -60,6 +60,21 @@ impl<Component> Fraction<Component> {
     }
 }
 
+#[derive(Clone)]
+pub struct Convergents<Component> {
+    pub(super) partial_quotients: PartialQuotients<Component>,
+    pub(super) previous_numerator: Component,
+    pub(super) previous_denominator: Component,
+    pub(super) numerator: Component,
+    pub(super) denominator: Component,
+}
+
+#[derive(Clone)]
+pub struct PartialQuotients<Component> {
+    pub(super) dividend: Component,
+    pub(super) divisor: Component,
+}
+
 pub trait NormalizeModuli<Other = Self> {
     type Output;
 
@@ -262,3 +277,77 @@ impl fmt::Display for FromFloatConstructionError {
         fmt::Display::fmt(&self.description(), formatter)
     }
 }
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum FromStringConstructionError {
+    ExponentOutOfBounds,
+    InvalidSymbol(char),
+    OutOfMemory,
+    UnexpectedEnd,
+    ZeroDenominator,
+}
+
+impl FromStringConstructionError {
+    fn description(self) -> String {
+        match self {
+            FromStringConstructionError::ExponentOutOfBounds => {
+                String::from("Exponent is out of bounds.")
+            }
+            FromStringConstructionError::InvalidSymbol(character) => {
+                format!("Invalid symbol: {character:?}.")
+            }
+            FromStringConstructionError::OutOfMemory => {
+                String::from("Not enough memory for digits.")
+            }
+            FromStringConstructionError::UnexpectedEnd => {
+                String::from("Unexpected end of string.")
+            }
+            FromStringConstructionError::ZeroDenominator => {
+                String::from("Denominator should not be zero.")
+            }
+        }
+    }
+}
+
+impl fmt::Debug for FromStringConstructionError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(&self.description())
+    }
+}
+
+impl fmt::Display for FromStringConstructionError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt::Display::fmt(&self.description(), formatter)
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum ToFloatError {
+    Inexact,
+    TooLarge,
+}
+
+impl ToFloatError {
+    fn description(self) -> &'static str {
+        match self {
+            ToFloatError::Inexact => {
+                "Value is not exactly representable as floating point."
+            }
+            ToFloatError::TooLarge => {
+                "Value too large to be expressed as floating point."
+            }
+        }
+    }
+}
+
+impl fmt::Debug for ToFloatError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.description())
+    }
+}
+
+impl fmt::Display for ToFloatError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt::Display::fmt(&self.description(), formatter)
+    }
+}
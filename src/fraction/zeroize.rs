@@ -0,0 +1,14 @@
+use traiter::numbers::One;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use super::types::Fraction;
+
+impl<Component: One + Zeroize> Zeroize for Fraction<Component> {
+    fn zeroize(&mut self) {
+        self.numerator.zeroize();
+        self.denominator.zeroize();
+        self.denominator = Component::one();
+    }
+}
+
+impl<Component: ZeroizeOnDrop> ZeroizeOnDrop for Fraction<Component> {}
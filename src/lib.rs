@@ -25,18 +25,35 @@ use traiter::numbers::{
     Zeroable,
 };
 
-use crate::constants::UNDEFINED_DIVISION_ERROR_MESSAGE;
+use crate::big_int::group_digits;
+use crate::constants::{
+    INEXACT_ROUNDING_ERROR_MESSAGE, UNDEFINED_DIVISION_ERROR_MESSAGE,
+};
+use crate::format_spec::FormatSpec;
+use crate::rounding_mode::RoundingMode;
 
 pub mod big_int;
+pub mod binary128;
 mod constants;
 mod contracts;
+pub mod ct_uint;
+mod format_spec;
 pub mod fraction;
+pub mod rounding_mode;
 mod traits;
 
 #[cfg(target_arch = "x86")]
 type Digit = u16;
-#[cfg(not(target_arch = "x86"))]
+#[cfg(all(
+    not(target_arch = "x86"),
+    not(all(feature = "u64-digits", target_pointer_width = "64"))
+))]
 type Digit = u32;
+#[cfg(all(
+    not(target_arch = "x86"),
+    all(feature = "u64-digits", target_pointer_width = "64")
+))]
+type Digit = u64;
 const DIGIT_BITNESS: usize = (Digit::BITS - 1) as usize;
 const _: () =
     assert!(big_int::is_valid_digit_bitness::<Digit, DIGIT_BITNESS>());
@@ -44,6 +61,7 @@ const _: () =
 const HASH_BITS: usize = 31;
 #[cfg(not(target_arch = "x86"))]
 const HASH_BITS: usize = 61;
+const HASH_DIGIT_SHIFT: usize = DIGIT_BITNESS % HASH_BITS;
 const HASH_INF: ffi::Py_hash_t = 314_159;
 const HASH_MODULUS: usize = (1 << HASH_BITS) - 1;
 
@@ -62,6 +80,10 @@ struct PyFraction(Fraction);
 #[derive(Clone)]
 struct PyInt(BigInt);
 
+#[pyclass(name = "RoundingMode", module = "rithm.enums", frozen)]
+#[derive(Clone)]
+struct PyRoundingMode(RoundingMode);
+
 #[pyclass(name = "TieBreaking", module = "rithm.enums", frozen)]
 #[derive(Clone)]
 struct PyTieBreaking(TieBreaking);
@@ -133,6 +155,143 @@ impl PyEndianness {
     }
 }
 
+fn to_py_rounding_mode_values(py: Python) -> &[Py<PyRoundingMode>; 9] {
+    static VALUES: GILOnceCell<[Py<PyRoundingMode>; 9]> = GILOnceCell::new();
+    VALUES.get_or_init(py, || {
+        [
+            PyCell::new(py, PyRoundingMode(RoundingMode::Up))
+                .unwrap()
+                .into(),
+            PyCell::new(py, PyRoundingMode(RoundingMode::Down))
+                .unwrap()
+                .into(),
+            PyCell::new(py, PyRoundingMode(RoundingMode::Ceiling))
+                .unwrap()
+                .into(),
+            PyCell::new(py, PyRoundingMode(RoundingMode::Floor))
+                .unwrap()
+                .into(),
+            PyCell::new(py, PyRoundingMode(RoundingMode::HalfUp))
+                .unwrap()
+                .into(),
+            PyCell::new(py, PyRoundingMode(RoundingMode::HalfDown))
+                .unwrap()
+                .into(),
+            PyCell::new(py, PyRoundingMode(RoundingMode::HalfEven))
+                .unwrap()
+                .into(),
+            PyCell::new(py, PyRoundingMode(RoundingMode::Unnecessary))
+                .unwrap()
+                .into(),
+            PyCell::new(py, PyRoundingMode(RoundingMode::HalfOdd))
+                .unwrap()
+                .into(),
+        ]
+    })
+}
+
+#[allow(non_snake_case)]
+#[pymethods]
+impl PyRoundingMode {
+    #[classattr]
+    fn UP(py: Python) -> Py<PyRoundingMode> {
+        to_py_rounding_mode_values(py)[0].clone_ref(py)
+    }
+
+    #[classattr]
+    fn DOWN(py: Python) -> Py<PyRoundingMode> {
+        to_py_rounding_mode_values(py)[1].clone_ref(py)
+    }
+
+    #[classattr]
+    fn CEILING(py: Python) -> Py<PyRoundingMode> {
+        to_py_rounding_mode_values(py)[2].clone_ref(py)
+    }
+
+    #[classattr]
+    fn FLOOR(py: Python) -> Py<PyRoundingMode> {
+        to_py_rounding_mode_values(py)[3].clone_ref(py)
+    }
+
+    #[classattr]
+    fn HALF_UP(py: Python) -> Py<PyRoundingMode> {
+        to_py_rounding_mode_values(py)[4].clone_ref(py)
+    }
+
+    #[classattr]
+    fn HALF_DOWN(py: Python) -> Py<PyRoundingMode> {
+        to_py_rounding_mode_values(py)[5].clone_ref(py)
+    }
+
+    #[classattr]
+    fn HALF_EVEN(py: Python) -> Py<PyRoundingMode> {
+        to_py_rounding_mode_values(py)[6].clone_ref(py)
+    }
+
+    #[classattr]
+    fn UNNECESSARY(py: Python) -> Py<PyRoundingMode> {
+        to_py_rounding_mode_values(py)[7].clone_ref(py)
+    }
+
+    #[classattr]
+    fn HALF_ODD(py: Python) -> Py<PyRoundingMode> {
+        to_py_rounding_mode_values(py)[8].clone_ref(py)
+    }
+
+    #[new]
+    #[pyo3(signature = (value, /))]
+    fn new(value: &PyAny, py: Python) -> PyResult<Py<Self>> {
+        let values = to_py_rounding_mode_values(py);
+        match value.extract::<usize>() {
+            Ok(value) if value < values.len() => {
+                Ok(values[value].clone_ref(py))
+            }
+            _ => Err(PyValueError::new_err(format!(
+                "{} is not a valid {}",
+                value.repr()?,
+                Self::NAME
+            ))),
+        }
+    }
+
+    #[getter]
+    fn value(&self) -> u8 {
+        match self.0 {
+            RoundingMode::Up => 0,
+            RoundingMode::Down => 1,
+            RoundingMode::Ceiling => 2,
+            RoundingMode::Floor => 3,
+            RoundingMode::HalfUp => 4,
+            RoundingMode::HalfDown => 5,
+            RoundingMode::HalfEven => 6,
+            RoundingMode::Unnecessary => 7,
+            RoundingMode::HalfOdd => 8,
+        }
+    }
+
+    fn __getnewargs__<'a>(&self, py: Python<'a>) -> &'a PyTuple {
+        PyTuple::new(py, [self.value()])
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "{}.{}",
+            Self::NAME,
+            match self.0 {
+                RoundingMode::Up => "UP",
+                RoundingMode::Down => "DOWN",
+                RoundingMode::Ceiling => "CEILING",
+                RoundingMode::Floor => "FLOOR",
+                RoundingMode::HalfUp => "HALF_UP",
+                RoundingMode::HalfDown => "HALF_DOWN",
+                RoundingMode::HalfEven => "HALF_EVEN",
+                RoundingMode::Unnecessary => "UNNECESSARY",
+                RoundingMode::HalfOdd => "HALF_ODD",
+            }
+        )
+    }
+}
+
 fn to_py_tie_breaking_values(py: Python) -> &[Py<PyTieBreaking>; 4] {
     static VALUES: GILOnceCell<[Py<PyTieBreaking>; 4]> = GILOnceCell::new();
     VALUES.get_or_init(py, || {
@@ -243,15 +402,17 @@ impl PyInt {
                         }
                         None => 10,
                     };
-                    match BigInt::from_str_radix(
-                        value.extract::<&str>()?,
-                        base,
-                    ) {
-                        Ok(value) => Ok(PyInt(value)),
-                        Err(reason) => {
-                            Err(PyValueError::new_err(reason.to_string()))
-                        }
-                    }
+                    BigInt::from_str_radix(value.extract::<&str>()?, base)
+                        .map(PyInt)
+                        .map_err(|reason| {
+                            if reason
+                                == big_int::TryFromStringError::OutOfMemory
+                            {
+                                PyMemoryError::new_err(reason.to_string())
+                            } else {
+                                PyValueError::new_err(reason.to_string())
+                            }
+                        })
                 } else if value.is_instance(PyFloat::type_object(py))? {
                     Ok(PyInt(
                         BigInt::try_from(value.extract::<&PyFloat>()?.value())
@@ -317,10 +478,11 @@ impl PyInt {
 
     fn __add__(&self, other: &PyAny) -> PyResult<PyObject> {
         let py = other.py();
-        other
-            .extract::<PyRef<Self>>()
-            .map(|other| Self(&self.0 + &other.0).into_py(py))
-            .or_else(|_| self.__radd__(other))
+        if let Ok(other) = other.extract::<PyRef<Self>>() {
+            try_add(&self.0, &other.0).map(|result| Self(result).into_py(py))
+        } else {
+            self.__radd__(other)
+        }
     }
 
     fn __and__(&self, other: &PyAny) -> PyResult<PyObject> {
@@ -377,6 +539,12 @@ impl PyInt {
         }
     }
 
+    fn __format__(&self, specifier: &str) -> PyResult<String> {
+        FormatSpec::parse(specifier, "Int")
+            .map_err(PyValueError::new_err)
+            .and_then(|specifier| format_big_int(&self.0, &specifier))
+    }
+
     fn __hash__(&self) -> ffi::Py_hash_t {
         hash(&self.0) as ffi::Py_hash_t
     }
@@ -418,10 +586,11 @@ impl PyInt {
 
     fn __mul__(&self, other: &PyAny) -> PyResult<PyObject> {
         let py = other.py();
-        other
-            .extract::<PyRef<Self>>()
-            .map(|other| Self(&self.0 * &other.0).into_py(py))
-            .or_else(|_| self.__rmul__(other))
+        if let Ok(other) = other.extract::<PyRef<Self>>() {
+            try_mul(&self.0, &other.0).map(|result| Self(result).into_py(py))
+        } else {
+            self.__rmul__(other)
+        }
     }
 
     fn __neg__(&self) -> PyInt {
@@ -431,7 +600,8 @@ impl PyInt {
     fn __or__(&self, other: &PyAny) -> PyResult<PyObject> {
         let py = other.py();
         if other.is_instance(PyInt::type_object(py))? {
-            Ok(PyInt(&self.0 | other.extract::<PyInt>()?.0).into_py(py))
+            Ok(PyInt(&self.0 | &other.extract::<PyRef<PyInt>>()?.0)
+                .into_py(py))
         } else {
             self.__ror__(other)
         }
@@ -474,8 +644,8 @@ impl PyInt {
                     if (&exponent).is_negative() {
                         try_pow_negative_exponent(self.0.clone(), exponent, py)
                     } else {
-                        Ok(Self(pow_non_negative_exponent(&self.0, &exponent))
-                            .into_py(py))
+                        try_pow_non_negative_exponent(&self.0, &exponent)
+                            .map(|power| Self(power).into_py(py))
                     }
                 }
             }
@@ -486,9 +656,12 @@ impl PyInt {
 
     fn __radd__(&self, other: &PyAny) -> PyResult<PyObject> {
         let py = other.py();
-        try_big_int_from_py_integral(other)
-            .map(|other| Self(other + &self.0).into_py(py))
-            .or_else(|_| Ok(py.NotImplemented()))
+        match try_big_int_from_py_integral(other) {
+            Ok(other) => {
+                try_add(&other, &self.0).map(|result| Self(result).into_py(py))
+            }
+            Err(_) => Ok(py.NotImplemented()),
+        }
     }
 
     fn __rand__(&self, other: &PyAny) -> PyResult<PyObject> {
@@ -561,8 +734,8 @@ impl PyInt {
     fn __rmul__(&self, other: &PyAny) -> PyResult<PyObject> {
         let py = other.py();
         if other.is_instance(PyLong::type_object(py))? {
-            Ok(PyInt(try_big_int_from_py_integral(other)? * &self.0)
-                .into_py(py))
+            try_mul(&try_big_int_from_py_integral(other)?, &self.0)
+                .map(|result| PyInt(result).into_py(py))
         } else {
             Ok(py.NotImplemented())
         }
@@ -640,8 +813,8 @@ impl PyInt {
                 if (&self.0).is_negative() {
                     try_pow_negative_exponent(base, self.0.clone(), py)
                 } else {
-                    Ok(PyInt(pow_non_negative_exponent(&base, &self.0))
-                        .into_py(py))
+                    try_pow_non_negative_exponent(&base, &self.0)
+                        .map(|power| PyInt(power).into_py(py))
                 }
             }
         }
@@ -671,9 +844,11 @@ impl PyInt {
 
     fn __rsub__(&self, minuend: &PyAny) -> PyResult<PyObject> {
         let py = minuend.py();
-        try_big_int_from_py_integral(minuend)
-            .map(|minuend| PyInt(minuend - &self.0).into_py(py))
-            .or_else(|_| Ok(py.NotImplemented()))
+        match try_big_int_from_py_integral(minuend) {
+            Ok(minuend) => try_sub(&minuend, &self.0)
+                .map(|result| PyInt(result).into_py(py)),
+            Err(_) => Ok(py.NotImplemented()),
+        }
     }
 
     fn __rtruediv__(&self, dividend: &PyAny) -> PyResult<PyObject> {
@@ -703,14 +878,16 @@ impl PyInt {
 
     fn __sub__(&self, subtrahend: &PyAny) -> PyResult<PyObject> {
         let py = subtrahend.py();
-        subtrahend
-            .extract::<PyRef<Self>>()
-            .map(|subtrahend| Self(&self.0 - &subtrahend.0).into_py(py))
-            .or_else(|_| {
-                try_big_int_from_py_integral(subtrahend)
-                    .map(|subtrahend| Self(&self.0 - subtrahend).into_py(py))
-                    .or_else(|_| Ok(py.NotImplemented()))
-            })
+        if let Ok(subtrahend) = subtrahend.extract::<PyRef<Self>>() {
+            try_sub(&self.0, &subtrahend.0)
+                .map(|result| Self(result).into_py(py))
+        } else if let Ok(subtrahend) = try_big_int_from_py_integral(subtrahend)
+        {
+            try_sub(&self.0, &subtrahend)
+                .map(|result| Self(result).into_py(py))
+        } else {
+            Ok(py.NotImplemented())
+        }
     }
 
     fn __truediv__(&self, divisor: &PyAny) -> PyResult<PyObject> {
@@ -730,13 +907,159 @@ impl PyInt {
     fn __xor__(&self, other: &PyAny) -> PyResult<PyObject> {
         let py = other.py();
         if other.is_instance(PyInt::type_object(py))? {
-            Ok(Self(&self.0 ^ other.extract::<PyInt>()?.0).into_py(py))
+            Ok(
+                Self(&self.0 ^ &other.extract::<PyRef<PyInt>>()?.0)
+                    .into_py(py),
+            )
         } else {
             self.__rxor__(other)
         }
     }
 }
 
+fn format_big_int(value: &BigInt, specifier: &FormatSpec) -> PyResult<String> {
+    let kind = specifier.kind.unwrap_or('d');
+    if let Some(separator) = specifier.grouping {
+        let is_grouping_allowed = match kind {
+            'd' | 'e' | 'E' | 'f' | 'F' | 'g' | 'G' | '%' => true,
+            'b' | 'o' | 'x' | 'X' => separator == '_',
+            _ => false,
+        };
+        if !is_grouping_allowed {
+            return Err(PyValueError::new_err(format!(
+                "Cannot specify '{separator}' with {}.",
+                to_quoted_format_kind(kind)
+            )));
+        }
+    }
+    match kind {
+        'b' | 'c' | 'd' | 'n' | 'o' | 'x' | 'X' => {
+            if specifier.precision.is_some() {
+                return Err(PyValueError::new_err(
+                    "Precision not allowed in integer format specifier",
+                ));
+            }
+            if specifier.coerce_negative_zero {
+                return Err(PyValueError::new_err(
+                    "Negative zero coercion (z) not allowed \
+                     in integer format specifier",
+                ));
+            }
+            if kind == 'c' {
+                if specifier.sign.is_some() {
+                    return Err(PyValueError::new_err(
+                        "Sign not allowed with integer format specifier 'c'",
+                    ));
+                }
+                if specifier.alternate {
+                    return Err(PyValueError::new_err(
+                        "Alternate form (#) not allowed \
+                         with integer format specifier 'c'",
+                    ));
+                }
+                let character = u32::try_from(value)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| {
+                        PyOverflowError::new_err(
+                            "%c arg not in range(0x110000)",
+                        )
+                    })?;
+                return Ok(specifier.pad("", &character.to_string()));
+            }
+            let (radix, group_size, prefix) = match kind {
+                'b' => (2, 4, "0b"),
+                'o' => (8, 4, "0o"),
+                'x' => (16, 4, "0x"),
+                'X' => (16, 4, "0X"),
+                _ => (10, 3, ""),
+            };
+            let mut digits =
+                unsafe { value.abs().to_str_radix(radix).unwrap_unchecked() };
+            if kind == 'X' {
+                digits.make_ascii_uppercase();
+            }
+            let prefix = format!(
+                "{}{}",
+                specifier.to_sign(value.is_negative()),
+                if specifier.alternate { prefix } else { "" }
+            );
+            let body = specifier.group_integral_digits(
+                &digits,
+                group_size,
+                prefix.chars().count(),
+            );
+            Ok(specifier.pad(&prefix, &body))
+        }
+        'e' | 'E' | 'f' | 'F' | 'g' | 'G' | '%' => {
+            let (is_negative, leading, trailing) =
+                specifier.to_rational_parts(value, &BigInt::one(), kind);
+            let prefix = specifier.to_sign(is_negative);
+            let body = format!(
+                "{}{trailing}",
+                specifier.group_integral_digits(
+                    &leading,
+                    3,
+                    prefix.chars().count() + trailing.chars().count(),
+                )
+            );
+            Ok(specifier.pad(prefix, &body))
+        }
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown format code {} for object of type 'Int'",
+            to_quoted_format_kind(kind)
+        ))),
+    }
+}
+
+fn format_fraction(value: &Fraction, specifier: &str) -> PyResult<String> {
+    if specifier.is_empty() {
+        return Ok(value.to_string());
+    }
+    let invalid_specifier_message = format!(
+        "Invalid format specifier '{specifier}' for object of type 'Fraction'"
+    );
+    let specifier = FormatSpec::parse(specifier, "Fraction")
+        .ok()
+        .filter(|specifier| {
+            !specifier.has_redundant_zeros
+                && matches!(
+                    specifier.kind,
+                    Some('e' | 'E' | 'f' | 'F' | 'g' | 'G' | '%')
+                )
+        })
+        .ok_or_else(|| {
+            PyValueError::new_err(invalid_specifier_message.clone())
+        })?;
+    if specifier.alignment.is_some() && specifier.zero_padding {
+        return Err(PyValueError::new_err(format!(
+            "{invalid_specifier_message}; \
+             can't use explicit alignment when zero-padding"
+        )));
+    }
+    let (is_negative, mut leading, trailing) = specifier.to_rational_parts(
+        value.numerator(),
+        value.denominator(),
+        unsafe { specifier.kind.unwrap_unchecked() },
+    );
+    let sign = specifier.to_sign(is_negative);
+    if specifier.zero_padding {
+        let minimum_leading_length = specifier
+            .width
+            .saturating_sub(sign.len() + trailing.chars().count());
+        let leading_length = if specifier.grouping.is_some() {
+            3 * minimum_leading_length / 4 + 1
+        } else {
+            minimum_leading_length
+        };
+        leading = format!("{leading:0>leading_length$}");
+    }
+    if let Some(separator) = specifier.grouping {
+        leading = group_digits(&leading, separator, 3);
+    }
+    Ok(specifier.pad(sign, &format!("{leading}{trailing}")))
+}
+
 #[inline]
 fn to_py_long<'a, T>(value: &'a T, py: Python) -> PyObject
 where
@@ -751,6 +1074,21 @@ where
     }
 }
 
+fn to_quoted_format_kind(kind: char) -> String {
+    if (33..128).contains(&u32::from(kind)) {
+        format!("'{kind}'")
+    } else {
+        format!("'\\x{:x}'", u32::from(kind))
+    }
+}
+
+#[inline]
+fn try_add(first: &BigInt, second: &BigInt) -> PyResult<BigInt> {
+    first
+        .try_add(second)
+        .map_err(|reason| PyMemoryError::new_err(reason.to_string()))
+}
+
 #[inline]
 fn try_divmod<
     Dividend: CheckedDivRemEuclid<Divisor, Output = Option<(Quotient, Remainder)>>,
@@ -802,7 +1140,7 @@ fn try_lshift<
         big_int::ShlError::OutOfMemory => {
             PyMemoryError::new_err(reason.to_string())
         }
-        big_int::ShlError::TooLarge => {
+        big_int::ShlError::LimitExceeded | big_int::ShlError::TooLarge => {
             PyOverflowError::new_err(reason.to_string())
         }
     })
@@ -842,7 +1180,7 @@ fn try_mod_to_near(dividend: &BigInt, divisor: &BigInt) -> PyResult<BigInt> {
             big_int::ShlError::OutOfMemory => {
                 PyMemoryError::new_err(reason.to_string())
             }
-            big_int::ShlError::TooLarge => {
+            big_int::ShlError::LimitExceeded | big_int::ShlError::TooLarge => {
                 PyOverflowError::new_err(reason.to_string())
             }
         },
@@ -863,9 +1201,29 @@ fn try_mod_to_near(dividend: &BigInt, divisor: &BigInt) -> PyResult<BigInt> {
 }
 
 #[inline]
-fn pow_non_negative_exponent(base: &BigInt, exponent: &BigInt) -> BigInt {
+fn try_pow_non_negative_exponent(
+    base: &BigInt,
+    exponent: &BigInt,
+) -> PyResult<BigInt> {
     debug_assert!(!exponent.is_negative());
-    unsafe { base.checked_pow(exponent).unwrap_unchecked() }
+    base.try_pow(exponent).map_err(|reason| match reason {
+        big_int::TryPowError::LimitExceeded => {
+            PyOverflowError::new_err(reason.to_string())
+        }
+        big_int::TryPowError::NegativeExponent => {
+            PyValueError::new_err(reason.to_string())
+        }
+        big_int::TryPowError::OutOfMemory => {
+            PyMemoryError::new_err(reason.to_string())
+        }
+    })
+}
+
+#[inline]
+fn try_mul(first: &BigInt, second: &BigInt) -> PyResult<BigInt> {
+    first
+        .try_mul(second)
+        .map_err(|reason| PyMemoryError::new_err(reason.to_string()))
 }
 
 #[inline]
@@ -899,11 +1257,18 @@ fn try_rshift<
     })
 }
 
+#[inline]
+fn try_sub(minuend: &BigInt, subtrahend: &BigInt) -> PyResult<BigInt> {
+    minuend
+        .try_sub(subtrahend)
+        .map_err(|reason| PyMemoryError::new_err(reason.to_string()))
+}
+
 #[inline]
 fn try_big_int_from_py_any(value: &PyAny) -> PyResult<BigInt> {
     value
-        .extract::<PyInt>()
-        .map(|value| value.0)
+        .extract::<PyRef<PyInt>>()
+        .map(|value| value.0.clone())
         .or_else(|_| try_big_int_from_py_integral(value))
 }
 
@@ -988,6 +1353,19 @@ impl PyFraction {
         }
     }
 
+    #[classmethod]
+    #[pyo3(signature = (value, /))]
+    fn simplest_from_float(_cls: &PyType, value: f64) -> PyResult<Self> {
+        Fraction::simplest_from_f64(value)
+            .map(Self)
+            .map_err(|reason| match reason {
+                fraction::FromFloatConstructionError::NaN => {
+                    PyValueError::new_err(reason.to_string())
+                }
+                _ => PyOverflowError::new_err(reason.to_string()),
+            })
+    }
+
     #[getter]
     fn denominator(&self) -> PyInt {
         PyInt(self.0.denominator().clone())
@@ -998,9 +1376,75 @@ impl PyFraction {
         PyInt(self.0.numerator().clone())
     }
 
-    #[pyo3(signature = (tie_breaking, /))]
-    fn round(&self, tie_breaking: &PyTieBreaking) -> PyInt {
-        PyInt((&self.0).round(tie_breaking.0))
+    #[pyo3(signature = (max_denominator=None, /))]
+    fn limit_denominator(
+        &self,
+        max_denominator: Option<&PyAny>,
+    ) -> PyResult<Self> {
+        let max_denominator = match max_denominator {
+            Some(max_denominator) => {
+                try_big_int_from_py_integral(max_denominator)?
+            }
+            None => BigInt::from(1_000_000u32),
+        };
+        self.0
+            .limit_denominator(&max_denominator)
+            .map(Self)
+            .ok_or_else(|| {
+                PyValueError::new_err("max_denominator should be at least 1")
+            })
+    }
+
+    #[pyo3(signature = (mode, /))]
+    fn round(&self, mode: &PyAny) -> PyResult<PyInt> {
+        if let Ok(tie_breaking) = mode.extract::<PyRef<PyTieBreaking>>() {
+            Ok(PyInt((&self.0).round(tie_breaking.0)))
+        } else {
+            self.0
+                .checked_round(mode.extract::<PyRef<PyRoundingMode>>()?.0)
+                .map(PyInt)
+                .ok_or_else(|| {
+                    PyValueError::new_err(INEXACT_ROUNDING_ERROR_MESSAGE)
+                })
+        }
+    }
+
+    fn to_decimal_string(&self) -> PyResult<String> {
+        self.0.to_decimal_string().ok_or_else(|| {
+            PyValueError::new_err(
+                "Fraction with denominator having prime factors \
+                 other than 2 and 5 has no finite decimal representation.",
+            )
+        })
+    }
+
+    #[pyo3(signature = (ndigits, /))]
+    fn to_fixed_string(&self, ndigits: &PyLong) -> PyResult<String> {
+        ndigits
+            .extract::<usize>()
+            .map(|ndigits| self.0.to_fixed_string(ndigits))
+            .or(Err(PyValueError::new_err(format!(
+                "Number of digits should be non-negative, but found: {}.",
+                ndigits.repr()?
+            ))))
+    }
+
+    #[pyo3(signature = (base=None, /))]
+    fn to_positional_string(&self, base: Option<&PyLong>) -> PyResult<String> {
+        let base = match base {
+            Some(base) => base.extract::<u32>().or(Err(
+                PyValueError::new_err(format!(
+                    "Base should be in range from {} to {}, but found: {}.",
+                    big_int::MIN_REPRESENTABLE_BASE,
+                    big_int::MAX_REPRESENTABLE_BASE,
+                    base.repr()?
+                )),
+            ))?,
+            None => 10,
+        };
+        self.0
+            .to_positional_string(base)
+            .map_err(|reason| PyValueError::new_err(reason.to_string()))
     }
 
     fn __abs__(&self) -> PyFraction {
@@ -1010,7 +1454,8 @@ impl PyFraction {
     fn __add__(&self, other: &PyAny) -> PyResult<PyObject> {
         let py = other.py();
         if other.is_instance(PyFraction::type_object(py))? {
-            Ok(Self(&self.0 + other.extract::<PyFraction>()?.0).into_py(py))
+            Ok(Self(&self.0 + &other.extract::<PyRef<PyFraction>>()?.0)
+                .into_py(py))
         } else {
             self.__radd__(other)
         }
@@ -1088,6 +1533,10 @@ impl PyFraction {
         }
     }
 
+    fn __format__(&self, specifier: &str) -> PyResult<String> {
+        format_fraction(&self.0, specifier)
+    }
+
     fn __hash__(&self) -> ffi::Py_hash_t {
         let inverted_denominator = unsafe {
             self.0
@@ -1332,28 +1781,13 @@ impl PyFraction {
     ) -> PyResult<PyObject> {
         match digits {
             Some(digits) => {
-                let digits = try_big_int_from_py_integral(digits)?;
-                let is_digits_positive = (&digits).is_positive();
-                let shift = unsafe {
-                    BigInt::from(10)
-                        .checked_pow(digits.abs())
-                        .unwrap_unchecked()
-                };
-                if is_digits_positive {
-                    Ok(Self(unsafe {
-                        Fraction::new(
-                            (&self.0 * &shift).round(TieBreaking::ToEven),
-                            shift,
-                        )
+                let digits = digits.extract::<isize>()?;
+                Ok(Self(unsafe {
+                    self.0
+                        .round_to(digits, 10, RoundingMode::HalfEven)
                         .unwrap_unchecked()
-                    })
-                    .into_py(py))
-                } else {
-                    Ok(Self(Fraction::from(
-                        (&self.0 / &shift).round(TieBreaking::ToEven) * shift,
-                    ))
-                    .into_py(py))
-                }
+                })
+                .into_py(py))
             }
             None => {
                 Ok(PyInt((&self.0).round(TieBreaking::ToEven)).into_py(py))
@@ -1472,9 +1906,23 @@ fn try_py_fraction_from_value(value: Option<&PyAny>) -> PyResult<PyFraction> {
                         _ => PyOverflowError::new_err(reason.to_string()),
                     },
                 )
+            } else if let Ok(value) = value.downcast::<PyString>() {
+                value.to_str()?.parse::<Fraction>().map(PyFraction).map_err(
+                    |reason| match reason {
+                        fraction::FromStringConstructionError::OutOfMemory => {
+                            PyMemoryError::new_err(reason.to_string())
+                        }
+                        fraction::FromStringConstructionError::ZeroDenominator => {
+                            PyZeroDivisionError::new_err(
+                                UNDEFINED_DIVISION_ERROR_MESSAGE,
+                            )
+                        }
+                        _ => PyValueError::new_err(reason.to_string()),
+                    },
+                )
             } else {
                 Err(PyTypeError::new_err(
-                                format!("Value should be rational or floating point number, but found: {}",
+                                format!("Value should be rational, floating point number or string, but found: {}",
                                         value.get_type().repr()?),
                             ))
             }
@@ -1495,7 +1943,7 @@ fn compare<T: PartialOrd<U>, U>(left: &T, right: &U, op: CompareOp) -> bool {
 }
 
 fn hash(value: &BigInt) -> usize {
-    if value.digits().len() == 1 {
+    if DIGIT_BITNESS < HASH_BITS && value.digits().len() == 1 {
         return if value.is_negative() {
             usize::MAX
                 - unsafe {
@@ -1512,9 +1960,14 @@ fn hash(value: &BigInt) -> usize {
     };
     let mut result = 0;
     for &position in value.digits().iter().rev() {
-        result = ((result << DIGIT_BITNESS) & HASH_MODULUS)
-            | (result >> (HASH_BITS - DIGIT_BITNESS));
-        result += unsafe { usize::try_from(position).unwrap_unchecked() };
+        result = ((result << HASH_DIGIT_SHIFT) & HASH_MODULUS)
+            | (result >> (HASH_BITS - HASH_DIGIT_SHIFT));
+        let digit = unsafe { usize::try_from(position).unwrap_unchecked() };
+        result += if DIGIT_BITNESS < HASH_BITS {
+            digit
+        } else {
+            digit % HASH_MODULUS
+        };
         if result >= HASH_MODULUS {
             result -= HASH_MODULUS;
         }
@@ -1536,6 +1989,7 @@ fn _crithm(py: Python, module: &PyModule) -> PyResult<()> {
     module.add_class::<PyEndianness>()?;
     module.add_class::<PyFraction>()?;
     module.add_class::<PyInt>()?;
+    module.add_class::<PyRoundingMode>()?;
     module.add_class::<PyTieBreaking>()?;
     let numbers_module = py.import("numbers")?;
     let integral_cls = numbers_module.getattr(intern!(py, "Integral"))?;
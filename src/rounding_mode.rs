@@ -0,0 +1,75 @@
+use std::cmp::Ordering;
+
+use traiter::numbers::TieBreaking;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RoundingMode {
+    Ceiling,
+    Down,
+    Floor,
+    HalfDown,
+    HalfEven,
+    HalfOdd,
+    HalfUp,
+    Unnecessary,
+    Up,
+}
+
+impl RoundingMode {
+    pub(crate) fn rounds_magnitude_up(
+        self,
+        is_negative: bool,
+        is_truncated_odd: bool,
+        is_exact: bool,
+        half_ordering: Ordering,
+    ) -> Option<bool> {
+        if is_exact {
+            return Some(false);
+        }
+        match self {
+            RoundingMode::Ceiling => Some(!is_negative),
+            RoundingMode::Down => Some(false),
+            RoundingMode::Floor => Some(is_negative),
+            RoundingMode::HalfDown => Some(half_ordering.is_gt()),
+            RoundingMode::HalfEven => Some(
+                half_ordering.is_gt()
+                    || (half_ordering.is_eq() && is_truncated_odd),
+            ),
+            RoundingMode::HalfOdd => Some(
+                half_ordering.is_gt()
+                    || (half_ordering.is_eq() && !is_truncated_odd),
+            ),
+            RoundingMode::HalfUp => Some(half_ordering.is_ge()),
+            RoundingMode::Unnecessary => None,
+            RoundingMode::Up => Some(true),
+        }
+    }
+
+    pub(crate) fn rounds_overflow_toward_zero(
+        self,
+        is_negative: bool,
+    ) -> bool {
+        match self {
+            RoundingMode::Ceiling => is_negative,
+            RoundingMode::Down => true,
+            RoundingMode::Floor => !is_negative,
+            RoundingMode::HalfDown
+            | RoundingMode::HalfEven
+            | RoundingMode::HalfOdd
+            | RoundingMode::HalfUp
+            | RoundingMode::Unnecessary
+            | RoundingMode::Up => false,
+        }
+    }
+}
+
+impl From<TieBreaking> for RoundingMode {
+    fn from(tie_breaking: TieBreaking) -> Self {
+        match tie_breaking {
+            TieBreaking::AwayFromZero => RoundingMode::HalfUp,
+            TieBreaking::ToEven => RoundingMode::HalfEven,
+            TieBreaking::ToOdd => RoundingMode::HalfOdd,
+            TieBreaking::TowardZero => RoundingMode::HalfDown,
+        }
+    }
+}
@@ -1,6 +1,6 @@
-use std::ops::Neg;
+use std::ops::{Mul, Neg};
 
-use traiter::numbers::Signed;
+use traiter::numbers::{One, Signed};
 
 use crate::constants::UNDEFINED_DIVISION_ERROR_MESSAGE;
 
@@ -37,6 +37,38 @@ try_div_integer_as_float_impl!(
     u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize => f64
 );
 
+pub(crate) trait BalancedProduct: Sized {
+    fn balanced_product<Values: Iterator<Item = Self>>(values: Values)
+        -> Self;
+}
+
+impl<Value: Mul<Output = Value> + One> BalancedProduct for Value {
+    fn balanced_product<Values: Iterator<Item = Self>>(
+        values: Values,
+    ) -> Self {
+        let mut products: Vec<(Self, usize)> = Vec::new();
+        for value in values {
+            let (mut product, mut level) = (value, 0);
+            while matches!(products.last(), Some((_, last_level)) if *last_level == level)
+            {
+                let (last_product, _) =
+                    unsafe { products.pop().unwrap_unchecked() };
+                product = last_product * product;
+                level += 1;
+            }
+            products.push((product, level));
+        }
+        let mut result = match products.pop() {
+            Some((product, _)) => product,
+            None => return Self::one(),
+        };
+        while let Some((product, _)) = products.pop() {
+            result = product * result;
+        }
+        result
+    }
+}
+
 pub trait DoublePrecision: Sized {
     type Result: From<Self>;
 }
@@ -219,6 +251,30 @@ impl Oppose for usize {
     type Result = isize;
 }
 
+pub trait ToFloat<Float> {
+    fn to_float(self) -> Float;
+}
+
+macro_rules! integer_to_float_impl {
+    ($float:ty => $($integer:ty)+) => {
+        $(
+            impl ToFloat<$float> for $integer {
+                #[inline(always)]
+                fn to_float(self) -> $float {
+                    self as $float
+                }
+            }
+        )+
+    }
+}
+
+integer_to_float_impl!(
+    f32 => u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize
+);
+integer_to_float_impl!(
+    f64 => u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize
+);
+
 pub trait UncheckedToInt<Int> {
     unsafe fn unchecked_to_int(self) -> Int;
 }
@@ -266,5 +322,44 @@ integer_wrapping_sub_impl!(
     i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize
 );
 
+pub(crate) trait UnsignedAbs {
+    type Output;
+
+    fn unsigned_abs(self) -> Self::Output;
+}
+
+macro_rules! signed_integer_unsigned_abs_impl {
+    ($($integer:ty => $unsigned:ty)*) => ($(
+        impl UnsignedAbs for $integer {
+            type Output = $unsigned;
+
+            #[inline(always)]
+            fn unsigned_abs(self) -> Self::Output {
+                <$integer>::unsigned_abs(self)
+            }
+        }
+    )*)
+}
+
+signed_integer_unsigned_abs_impl!(
+    i8 => u8 i16 => u16 i32 => u32 i64 => u64 i128 => u128 isize => usize
+);
+
+macro_rules! unsigned_integer_unsigned_abs_impl {
+    ($($integer:ty)*) => ($(
+        impl UnsignedAbs for $integer {
+            type Output = $integer;
+
+            #[inline(always)]
+            fn unsigned_abs(self) -> Self::Output {
+                self
+            }
+        }
+    )*)
+}
+
+unsigned_integer_unsigned_abs_impl!(u8 u16 u32 u64 u128 usize);
+
 pub type DoublePrecisionOf<T> = <T as DoublePrecision>::Result;
 pub type OppositionOf<T> = <T as Oppose>::Result;
+pub(crate) type UnsignedAbsOf<T> = <T as UnsignedAbs>::Output;
@@ -0,0 +1,151 @@
+use rithm::big_int::{Alphabet, AlphabetError, BigInt};
+
+type BigInt7 = BigInt<u8, 7>;
+type BigInt15 = BigInt<u16, 15>;
+type BigInt31 = BigInt<u32, 31>;
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+fn to_binary_alphabet() -> Alphabet {
+    Alphabet::new("01").unwrap()
+}
+
+/// Symbols are taken from Latin Extended blocks
+/// to have multibyte characters which do not clash with sign symbols.
+fn to_largest_alphabet() -> Alphabet {
+    Alphabet::new(
+        &(0x100u32..0x200)
+            .filter_map(char::from_u32)
+            .collect::<String>(),
+    )
+    .unwrap()
+}
+
+/// URL-safe variant of `base64` alphabet
+/// with `-` replaced since it is reserved for the sign.
+fn to_url_safe_alphabet() -> Alphabet {
+    Alphabet::new(
+        "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789._",
+    )
+    .unwrap()
+}
+
+macro_rules! to_samples {
+    ($big_int:ty, $seed:expr) => {{
+        let mut generator = XorShift($seed);
+        let mut result =
+            [0i64, 1, -1, 2, -2, 127, 128, 255, 256, -257, i64::MAX]
+                .iter()
+                .map(|&value| <$big_int>::from(value))
+                .collect::<Vec<_>>();
+        for chunks_count in [1usize, 2, 3, 7, 20] {
+            let mut value = <$big_int>::from(0u8);
+            for _ in 0..chunks_count {
+                value = (value << 64u32) + <$big_int>::from(generator.next());
+            }
+            result.push(value.clone());
+            result.push(-value);
+        }
+        result
+    }};
+}
+
+macro_rules! check_round_trip {
+    ($big_int:ty, $seed:expr) => {{
+        for alphabet in [
+            to_binary_alphabet(),
+            to_largest_alphabet(),
+            to_url_safe_alphabet(),
+        ] {
+            for value in to_samples!($big_int, $seed) {
+                let string = value.to_str_with_alphabet(&alphabet);
+
+                assert_eq!(
+                    <$big_int>::from_str_with_alphabet(&string, &alphabet),
+                    Ok(value)
+                );
+            }
+        }
+    }};
+}
+
+#[test]
+fn round_trip() {
+    check_round_trip!(BigInt7, 0x2545_f491_4f6c_dd1d);
+    check_round_trip!(BigInt15, 0x9e37_79b9_7f4a_7c15);
+    check_round_trip!(BigInt31, 0xbf58_476d_1ce4_e5b9);
+}
+
+macro_rules! check_known_strings {
+    ($big_int:ty) => {{
+        let binary = to_binary_alphabet();
+        let largest = to_largest_alphabet();
+        let symbols = largest.symbols();
+        let value = <$big_int>::from(-0x1_02ffi32);
+
+        assert_eq!(value.to_str_with_alphabet(&binary), "-10000001011111111");
+        assert_eq!(
+            value.to_str_with_alphabet(&largest),
+            ['-', symbols[1], symbols[2], symbols[255]]
+                .iter()
+                .collect::<String>()
+        );
+        assert_eq!(
+            <$big_int>::from(0u8).to_str_with_alphabet(&largest),
+            symbols[0].to_string()
+        );
+        assert_eq!(
+            <$big_int>::from_str_with_alphabet("+0000101", &binary),
+            Ok(<$big_int>::from(5u8))
+        );
+    }};
+}
+
+#[test]
+fn known_strings() {
+    check_known_strings!(BigInt7);
+    check_known_strings!(BigInt15);
+    check_known_strings!(BigInt31);
+}
+
+#[test]
+fn sign_symbols_are_rejected() {
+    assert_eq!(
+        Alphabet::new(
+            "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",
+        ),
+        Err(AlphabetError::SignSymbol('-'))
+    );
+    assert_eq!(
+        Alphabet::new(
+            "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+        ),
+        Err(AlphabetError::SignSymbol('+'))
+    );
+}
+
+#[test]
+fn negative_values_are_unambiguous() {
+    let alphabet = to_url_safe_alphabet();
+    let value = BigInt31::from(-0x3e_ffffi32);
+    let string = value.to_str_with_alphabet(&alphabet);
+
+    assert_eq!(string, "-Pv__");
+    assert_eq!(
+        BigInt31::from_str_with_alphabet(&string, &alphabet),
+        Ok(value.clone())
+    );
+    assert_eq!(
+        BigInt31::from_str_with_alphabet(&string[1..], &alphabet),
+        Ok(-value)
+    );
+}
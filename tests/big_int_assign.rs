@@ -0,0 +1,125 @@
+use rithm::big_int::BigInt;
+
+type BigInt15 = BigInt<u16, 15>;
+type BigInt31 = BigInt<u32, 31>;
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+macro_rules! to_samples {
+    ($big_int:ty, $seed:expr) => {{
+        let mut generator = XorShift($seed);
+        let mut result = [0i64, 1, -1, 2, -2, 32767, -32768, i64::MAX]
+            .iter()
+            .map(|&value| <$big_int>::from(value))
+            .collect::<Vec<_>>();
+        for chunks_count in [1usize, 2, 3, 5, 8, 40] {
+            let mut value = <$big_int>::from(0u8);
+            for _ in 0..chunks_count {
+                value = (value << 64u32) + <$big_int>::from(generator.next());
+            }
+            result.push(value.clone());
+            result.push(-value);
+        }
+        result
+    }};
+}
+
+macro_rules! check_assign_operator {
+    ($samples:expr, $assign_operator:tt, $operator:tt, $filter:expr) => {
+        for first in $samples {
+            for second in $samples.iter().filter($filter) {
+                let expected = first.clone() $operator second.clone();
+
+                let mut result = first.clone();
+                result $assign_operator second;
+                assert_eq!(result, expected);
+
+                let mut result = first.clone();
+                result $assign_operator second.clone();
+                assert_eq!(result, expected);
+            }
+        }
+    };
+}
+
+macro_rules! check_assign_operators {
+    ($big_int:ty, $seed:expr) => {{
+        let samples = to_samples!($big_int, $seed);
+        let zero = <$big_int>::from(0u8);
+        check_assign_operator!(&samples, +=, +, |_| true);
+        check_assign_operator!(&samples, -=, -, |_| true);
+        check_assign_operator!(&samples, *=, *, |_| true);
+        check_assign_operator!(&samples, /=, /, |value| **value != zero);
+        check_assign_operator!(&samples, %=, %, |value| **value != zero);
+        check_assign_operator!(&samples, &=, &, |_| true);
+        check_assign_operator!(&samples, |=, |, |_| true);
+        check_assign_operator!(&samples, ^=, ^, |_| true);
+    }};
+}
+
+macro_rules! check_fused_operations {
+    ($big_int:ty, $seed:expr) => {{
+        let samples = to_samples!($big_int, $seed);
+        for target in &samples {
+            for first in &samples {
+                for second in &samples {
+                    let product = first * second;
+
+                    let mut result = target.clone();
+                    result.add_mul_assign(first, second);
+                    assert_eq!(result, target + &product);
+
+                    let mut result = target.clone();
+                    result.sub_mul_assign(first, second);
+                    assert_eq!(result, target - &product);
+                }
+                let square = first * first;
+
+                let mut result = target.clone();
+                result.add_mul_assign(first, first);
+                assert_eq!(result, target + &square);
+
+                let mut result = target.clone();
+                result.sub_mul_assign(first, first);
+                assert_eq!(result, target - &square);
+            }
+        }
+    }};
+}
+
+#[test]
+fn assign_operators() {
+    check_assign_operators!(BigInt15, 0x2545_f491_4f6c_dd1d);
+    check_assign_operators!(BigInt31, 0x9e37_79b9_7f4a_7c15);
+}
+
+#[test]
+fn fused_operations() {
+    check_fused_operations!(BigInt15, 0xbf58_476d_1ce4_e5b9);
+    check_fused_operations!(BigInt31, 0x94d0_49bb_1331_11eb);
+}
+
+#[test]
+fn fused_operations_cancellation() {
+    let first = BigInt31::from(u64::MAX) << 100u32;
+    let second = BigInt31::from(-3i8);
+    let mut result = &first * &second;
+
+    result.sub_mul_assign(&first, &second);
+    assert_eq!(result, BigInt31::from(0u8));
+
+    result.add_mul_assign(&first, &second);
+    assert_eq!(result, &first * &second);
+
+    result.add_mul_assign(&first, &-second);
+    assert_eq!(result, BigInt31::from(0u8));
+}
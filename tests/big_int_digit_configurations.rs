@@ -0,0 +1,140 @@
+use rithm::big_int::BigInt;
+use traiter::numbers::FromStrRadix;
+
+type BigInt7 = BigInt<u8, 7>;
+type BigInt15 = BigInt<u16, 15>;
+type BigInt31 = BigInt<u32, 31>;
+type BigInt63 = BigInt<u64, 63>;
+
+const DECIMAL_STRINGS: [&str; 6] = [
+    "0",
+    "-1",
+    "2370558173225809277924436977",
+    "-340282366920938463463374607431768211457",
+    "18446744073709551615",
+    "98765432109876543210987654321098765432109876543210",
+];
+
+#[test]
+fn parsing_in_several_configurations() {
+    for _ in 0..2 {
+        for string in DECIMAL_STRINGS {
+            let expected = BigInt31::from_str_radix(string, 10).unwrap();
+            assert_eq!(
+                BigInt7::from_str_radix(string, 10).unwrap().to_string(),
+                string
+            );
+            assert_eq!(
+                BigInt15::from_str_radix(string, 10).unwrap().to_string(),
+                string
+            );
+            assert_eq!(
+                BigInt63::from_str_radix(string, 10).unwrap().to_string(),
+                string
+            );
+            assert_eq!(
+                BigInt31::from_big_int(
+                    &BigInt7::from_str_radix(string, 10).unwrap()
+                ),
+                expected
+            );
+            assert_eq!(
+                BigInt31::from_big_int(
+                    &BigInt63::from_str_radix(string, 10).unwrap()
+                ),
+                expected
+            );
+        }
+    }
+}
+
+#[test]
+fn parsing_in_several_configurations_and_bases() {
+    let string = "zyxwvutsrqponmlkjihgfedcba9876543210";
+    for base in 2..=36 {
+        let string = &string[string.len() - base as usize..];
+        let expected =
+            BigInt31::from_str_radix(string, base).unwrap().to_string();
+        assert_eq!(
+            BigInt7::from_str_radix(string, base).unwrap().to_string(),
+            expected
+        );
+        assert_eq!(
+            BigInt15::from_str_radix(string, base).unwrap().to_string(),
+            expected
+        );
+        assert_eq!(
+            BigInt63::from_str_radix(string, base).unwrap().to_string(),
+            expected
+        );
+    }
+}
+
+#[test]
+fn parsing_in_several_threads() {
+    let handles = (0..8)
+        .map(|index| {
+            std::thread::spawn(move || {
+                let string = DECIMAL_STRINGS[index % DECIMAL_STRINGS.len()];
+                for _ in 0..100 {
+                    let parsed = match index % 4 {
+                        0 => BigInt7::from_str_radix(string, 10)
+                            .unwrap()
+                            .to_string(),
+                        1 => BigInt15::from_str_radix(string, 10)
+                            .unwrap()
+                            .to_string(),
+                        2 => BigInt31::from_str_radix(string, 10)
+                            .unwrap()
+                            .to_string(),
+                        _ => BigInt63::from_str_radix(string, 10)
+                            .unwrap()
+                            .to_string(),
+                    };
+                    assert_eq!(parsed, string);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
+
+fn to_values() -> Vec<BigInt31> {
+    let mut result = DECIMAL_STRINGS
+        .iter()
+        .map(|string| BigInt31::from_str_radix(string, 10).unwrap())
+        .collect::<Vec<_>>();
+    result.extend(
+        [i128::MIN + 1, -(1 << 64), -(1 << 63), 1 << 62, i128::MAX]
+            .map(BigInt31::from),
+    );
+    result
+}
+
+#[test]
+fn from_big_int_round_trip() {
+    for value in to_values() {
+        assert_eq!(
+            BigInt31::from_big_int(&BigInt7::from_big_int(&value)),
+            value
+        );
+        assert_eq!(
+            BigInt31::from_big_int(&BigInt15::from_big_int(&value)),
+            value
+        );
+        assert_eq!(
+            BigInt31::from_big_int(&BigInt63::from_big_int(&value)),
+            value
+        );
+        assert_eq!(
+            BigInt7::from_big_int(&BigInt63::from_big_int(&value)),
+            BigInt7::from_big_int(&value)
+        );
+        assert_eq!(
+            BigInt15::from_big_int(&value).to_string(),
+            value.to_string()
+        );
+    }
+}
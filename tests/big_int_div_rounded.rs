@@ -0,0 +1,166 @@
+use std::cmp::Ordering;
+
+use rithm::big_int::BigInt;
+use rithm::rounding_mode::RoundingMode;
+
+type BigInt15 = BigInt<u16, 15>;
+type BigInt31 = BigInt<u32, 31>;
+
+const MODES: [RoundingMode; 9] = [
+    RoundingMode::Ceiling,
+    RoundingMode::Down,
+    RoundingMode::Floor,
+    RoundingMode::HalfDown,
+    RoundingMode::HalfEven,
+    RoundingMode::HalfOdd,
+    RoundingMode::HalfUp,
+    RoundingMode::Unnecessary,
+    RoundingMode::Up,
+];
+
+fn floor_div_rem(dividend: i128, divisor: i128) -> (i128, i128) {
+    if divisor > 0 {
+        (dividend.div_euclid(divisor), dividend.rem_euclid(divisor))
+    } else {
+        (
+            (-dividend).div_euclid(-divisor),
+            -(-dividend).rem_euclid(-divisor),
+        )
+    }
+}
+
+fn to_expected_quotient(
+    dividend: i128,
+    divisor: i128,
+    mode: RoundingMode,
+) -> Option<i128> {
+    let (floor, remainder) = floor_div_rem(dividend, divisor);
+    if remainder == 0 {
+        return Some(floor);
+    }
+    let ceil = floor + 1;
+    let toward_zero = if floor < 0 { ceil } else { floor };
+    let away_from_zero = if floor < 0 { floor } else { ceil };
+    let even = if floor % 2 == 0 { floor } else { ceil };
+    let odd = if floor % 2 == 0 { ceil } else { floor };
+    let half_ordering = (2 * remainder).abs().cmp(&divisor.abs());
+    let nearest_or = |tie: i128| match half_ordering {
+        Ordering::Less => floor,
+        Ordering::Equal => tie,
+        Ordering::Greater => ceil,
+    };
+    match mode {
+        RoundingMode::Ceiling => Some(ceil),
+        RoundingMode::Down => Some(toward_zero),
+        RoundingMode::Floor => Some(floor),
+        RoundingMode::HalfDown => Some(nearest_or(toward_zero)),
+        RoundingMode::HalfEven => Some(nearest_or(even)),
+        RoundingMode::HalfOdd => Some(nearest_or(odd)),
+        RoundingMode::HalfUp => Some(nearest_or(away_from_zero)),
+        RoundingMode::Unnecessary => None,
+        RoundingMode::Up => Some(away_from_zero),
+    }
+}
+
+macro_rules! check_small_values {
+    ($big_int:ty) => {
+        for dividend in -40i128..=40 {
+            for divisor in (-12i128..=12).filter(|&divisor| divisor != 0) {
+                for mode in MODES {
+                    assert_eq!(
+                        <$big_int>::from(dividend).checked_div_rounded(
+                            &<$big_int>::from(divisor),
+                            mode
+                        ),
+                        to_expected_quotient(dividend, divisor, mode)
+                            .map(<$big_int>::from),
+                        "{} / {} {:?}",
+                        dividend,
+                        divisor,
+                        mode
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[test]
+fn small_values() {
+    check_small_values!(BigInt15);
+    check_small_values!(BigInt31);
+}
+
+#[test]
+fn multi_digit_values() {
+    let dividends = [
+        i128::MAX,
+        -i128::MAX,
+        (1 << 100) + 1,
+        -(1 << 100) - 1,
+        3 << 90,
+        -(5 << 70),
+    ];
+    let divisors = [2i128, -2, 3, 1 << 40, -(1 << 40) - 1, (1 << 63) + 7, 10];
+    for dividend in dividends {
+        for divisor in divisors {
+            for mode in MODES {
+                assert_eq!(
+                    BigInt31::from(dividend)
+                        .checked_div_rounded(&BigInt31::from(divisor), mode),
+                    to_expected_quotient(dividend, divisor, mode)
+                        .map(BigInt31::from),
+                    "{} / {} {:?}",
+                    dividend,
+                    divisor,
+                    mode
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn ties() {
+    let check = |dividend: i8, divisor: i8, expected: [i8; 9]| {
+        for (&mode, &expected) in MODES.iter().zip(expected.iter()) {
+            assert_eq!(
+                BigInt31::from(dividend)
+                    .checked_div_rounded(&BigInt31::from(divisor), mode),
+                (mode != RoundingMode::Unnecessary)
+                    .then(|| BigInt31::from(expected)),
+                "{} / {} {:?}",
+                dividend,
+                divisor,
+                mode
+            );
+        }
+    };
+    // modes order: Ceiling, Down, Floor, HalfDown, HalfEven, HalfOdd,
+    // HalfUp, Unnecessary, Up
+    check(5, 2, [3, 2, 2, 2, 2, 3, 3, 0, 3]);
+    check(-5, 2, [-2, -2, -3, -2, -2, -3, -3, 0, -3]);
+    check(7, -2, [-3, -3, -4, -3, -4, -3, -4, 0, -4]);
+    check(-7, -2, [4, 3, 3, 3, 4, 3, 4, 0, 4]);
+}
+
+#[test]
+fn exact_and_zero_divisor() {
+    for mode in MODES {
+        assert_eq!(
+            BigInt31::from(-12i8)
+                .checked_div_rounded(&BigInt31::from(4u8), mode),
+            Some(BigInt31::from(-3i8))
+        );
+        assert_eq!(
+            BigInt31::from(0u8)
+                .checked_div_rounded(&BigInt31::from(-7i8), mode),
+            Some(BigInt31::from(0u8))
+        );
+        assert_eq!(
+            BigInt31::from(5u8)
+                .checked_div_rounded(&BigInt31::from(0u8), mode),
+            None
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use std::fmt::{self, Binary, Formatter, LowerHex, Octal, UpperHex};
+
+use rithm::big_int::BigInt;
+
+type BigInt15 = BigInt<u16, 15>;
+type BigInt31 = BigInt<u32, 31>;
+
+const VALUES: [i128; 14] = [
+    0,
+    1,
+    7,
+    125,
+    255,
+    995,
+    1234,
+    9950,
+    99999,
+    1 << 40,
+    u64::MAX as i128,
+    (1 << 100) + 12345,
+    i128::MAX,
+    123_456_789_012_345_678_901_234_567_890,
+];
+
+/// Primitives format negative values in radix notations
+/// as two's complement, so negative big integers are compared
+/// with magnitude formatted the same way with explicit minus sign.
+struct Negative(u128);
+
+macro_rules! negative_radix_format_impl {
+    ($($trait_:ident $prefix:literal $spec:literal)*) => ($(
+        impl $trait_ for Negative {
+            fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+                formatter.pad_integral(false, $prefix, &format!($spec, self.0))
+            }
+        }
+    )*)
+}
+
+negative_radix_format_impl!(
+    Binary "0b" "{:b}"
+    LowerHex "0x" "{:x}"
+    Octal "0o" "{:o}"
+    UpperHex "0x" "{:X}"
+);
+
+macro_rules! check_radix_formats {
+    ($big_int:ty, $($spec:literal)*) => {
+        for value in VALUES {
+            let big_int = <$big_int>::from(value);
+            let negative_big_int = -<$big_int>::from(value);
+            $(
+                assert_eq!(
+                    format!($spec, big_int),
+                    format!($spec, value),
+                    "{} {}",
+                    $spec,
+                    value
+                );
+                if value != 0 {
+                    assert_eq!(
+                        format!($spec, negative_big_int),
+                        format!($spec, Negative(value as u128)),
+                        "{} -{}",
+                        $spec,
+                        value
+                    );
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! check_exponent_formats {
+    ($big_int:ty, $($spec:literal)*) => {
+        for value in VALUES {
+            for value in [value, -value] {
+                let big_int = <$big_int>::from(value);
+                $(
+                    assert_eq!(
+                        format!($spec, big_int),
+                        format!($spec, value),
+                        "{} {}",
+                        $spec,
+                        value
+                    );
+                )*
+            }
+        }
+    };
+}
+
+macro_rules! check_all_radix_formats {
+    ($big_int:ty) => {
+        check_radix_formats!(
+            $big_int,
+            "{:b}" "{:#b}" "{:+b}" "{:70b}" "{:#<70b}" "{:^+#70b}" "{:070b}"
+            "{:#070b}"
+            "{:o}" "{:#o}" "{:+o}" "{:12o}" "{:_<12o}" "{:^#12o}" "{:012o}"
+            "{:+#012o}"
+            "{:x}" "{:#x}" "{:+x}" "{:10x}" "{:*<10x}" "{:^#10x}" "{:010x}"
+            "{:+#012x}"
+            "{:X}" "{:#X}" "{:+X}" "{:10X}" "{:*>10X}" "{:^#10X}" "{:010X}"
+            "{:+#012X}"
+        );
+    };
+}
+
+macro_rules! check_all_exponent_formats {
+    ($big_int:ty) => {
+        check_exponent_formats!(
+            $big_int,
+            "{:e}" "{:+e}" "{:.0e}" "{:.1e}" "{:.2e}" "{:.5e}" "{:.40e}"
+            "{:12e}" "{:<12e}" "{:^+12.1e}" "{:012.2e}"
+            "{:E}" "{:+E}" "{:.0E}" "{:.1E}" "{:.3E}" "{:#>14.2E}" "{:014E}"
+        );
+    };
+}
+
+#[test]
+fn radix_formats() {
+    check_all_radix_formats!(BigInt15);
+    check_all_radix_formats!(BigInt31);
+}
+
+#[test]
+fn exponent_formats() {
+    check_all_exponent_formats!(BigInt15);
+    check_all_exponent_formats!(BigInt31);
+}
+
+#[test]
+fn negative_radix_formats() {
+    let value = BigInt31::from(-255i16);
+
+    assert_eq!(format!("{:x}", value), "-ff");
+    assert_eq!(format!("{:#x}", value), "-0xff");
+    assert_eq!(format!("{:#08x}", value), "-0x000ff");
+    assert_eq!(format!("{:>6X}", value), "   -FF");
+    assert_eq!(format!("{:#b}", value), "-0b11111111");
+    assert_eq!(format!("{:+o}", value), "-377");
+}
@@ -0,0 +1,115 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use rithm::big_int::BigInt;
+
+type BigInt15 = BigInt<u16, 15>;
+type BigInt31 = BigInt<u32, 31>;
+type BigInt63 = BigInt<u64, 63>;
+
+/// Counts allocations per thread,
+/// so tests running concurrently do not affect each other.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS_COUNT: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS_COUNT.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(
+        &self,
+        ptr: *mut u8,
+        layout: Layout,
+        new_size: usize,
+    ) -> *mut u8 {
+        let _ = ALLOCATIONS_COUNT.try_with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn count_allocations<T>(function: impl FnOnce() -> T) -> (T, usize) {
+    let start = ALLOCATIONS_COUNT.with(Cell::get);
+    let result = function();
+    (result, ALLOCATIONS_COUNT.with(Cell::get) - start)
+}
+
+macro_rules! check_small_values {
+    ($big_int:ty, $digit_bitness:expr) => {{
+        // largest magnitudes which fit in one and two digits
+        let one_digit_max = (1i128 << $digit_bitness) - 1;
+        let two_digits_max = (1i128 << (2 * $digit_bitness)) - 1;
+        let values = [
+            0,
+            1,
+            -1,
+            one_digit_max,
+            -one_digit_max,
+            one_digit_max + 1,
+            two_digits_max,
+            -two_digits_max,
+            two_digits_max / 3,
+        ];
+        for &first in &values {
+            for &second in &values {
+                let (_, allocations_count) = count_allocations(|| {
+                    let first_big_int = <$big_int>::from(first);
+                    let second_big_int = <$big_int>::from(second);
+                    let _ = first_big_int.clone();
+                    let _ = -&first_big_int;
+                    let _ = first_big_int == second_big_int;
+                    let _ = first_big_int < second_big_int;
+                    // working buffers of arithmetic operations
+                    // have room for a carry digit
+                    if first.abs() <= one_digit_max
+                        && second.abs() <= one_digit_max
+                    {
+                        let _ = &first_big_int + &second_big_int;
+                        let _ = &first_big_int - &second_big_int;
+                        let _ = &first_big_int * &second_big_int;
+                    }
+                });
+
+                assert_eq!(
+                    allocations_count, 0,
+                    "{} and {} with {}-bit digits",
+                    first, second, $digit_bitness
+                );
+            }
+        }
+    }};
+}
+
+#[test]
+fn small_values_do_not_allocate() {
+    check_small_values!(BigInt15, 15);
+    check_small_values!(BigInt31, 31);
+    check_small_values!(BigInt63, 63);
+}
+
+#[test]
+fn large_values_allocate() {
+    let (_, allocations_count) =
+        count_allocations(|| BigInt15::from(1u32 << 30));
+
+    assert!(allocations_count > 0);
+    let (_, allocations_count) =
+        count_allocations(|| BigInt31::from(1u64 << 62));
+
+    assert!(allocations_count > 0);
+    let (_, allocations_count) =
+        count_allocations(|| BigInt63::from(1u128 << 126));
+
+    assert!(allocations_count > 0);
+}
@@ -0,0 +1,165 @@
+use std::convert::TryFrom;
+
+use rithm::big_int::{
+    BigInt, ShlError, TryFromStringError, TryMulError, TryPowError,
+};
+use traiter::numbers::BitLength;
+
+type BigInt31 = BigInt<u32, 31>;
+
+const VALUES: [i64; 11] =
+    [-65537, -256, -255, -3, -2, -1, 0, 1, 2, 7, 1 << 40];
+
+fn to_bit_length(value: &BigInt31) -> usize {
+    usize::try_from(value.bit_length()).unwrap()
+}
+
+#[test]
+fn pow_within() {
+    for value in VALUES {
+        let value = BigInt31::from(value);
+        for exponent in 0u8..=40 {
+            let exponent = BigInt31::from(exponent);
+            let power = value.try_pow(&exponent).unwrap();
+            let power_bit_length = to_bit_length(&power);
+
+            assert_eq!(
+                value.try_pow_within(&exponent, power_bit_length),
+                Ok(power)
+            );
+            if power_bit_length > 0 {
+                assert_eq!(
+                    value.try_pow_within(&exponent, power_bit_length - 1),
+                    Err(TryPowError::LimitExceeded)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn pow_within_huge_exponent() {
+    let exponent =
+        BigInt31::from(10u8).try_pow(&BigInt31::from(12u8)).unwrap();
+
+    assert_eq!(
+        BigInt31::from(2u8).try_pow_within(&exponent, 1 << 20),
+        Err(TryPowError::LimitExceeded)
+    );
+    assert_eq!(
+        BigInt31::from(-1i8).try_pow_within(&exponent, 1),
+        Ok(BigInt31::from(1u8))
+    );
+    assert_eq!(
+        BigInt31::from(2u8).try_pow_within(&-exponent, usize::MAX),
+        Err(TryPowError::NegativeExponent)
+    );
+}
+
+#[test]
+fn checked_shl_within() {
+    for value in VALUES {
+        let value = BigInt31::from(value);
+        for shift in 0u8..=70 {
+            let shift = BigInt31::from(shift);
+            let shifted = &value << &shift;
+            let shifted_bit_length = to_bit_length(&shifted);
+
+            assert_eq!(
+                value.checked_shl_within(&shift, shifted_bit_length),
+                Ok(shifted)
+            );
+            if shifted_bit_length > 0 {
+                assert_eq!(
+                    value.checked_shl_within(&shift, shifted_bit_length - 1),
+                    Err(ShlError::LimitExceeded)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn checked_shl_within_huge_shift() {
+    let shift = BigInt31::from(10u8).try_pow(&BigInt31::from(30u8)).unwrap();
+
+    assert_eq!(
+        BigInt31::from(1u8).checked_shl_within(&shift, usize::MAX),
+        Err(ShlError::LimitExceeded)
+    );
+    assert_eq!(
+        BigInt31::from(0u8).checked_shl_within(&shift, 0),
+        Ok(BigInt31::from(0u8))
+    );
+    assert_eq!(
+        BigInt31::from(1u8).checked_shl_within(&-shift, usize::MAX),
+        Err(ShlError::NegativeShift)
+    );
+}
+
+#[test]
+fn try_mul_within() {
+    for first in VALUES {
+        let first = BigInt31::from(first);
+        for second in VALUES {
+            let second = BigInt31::from(second);
+            let product = &first * &second;
+            let product_bit_length = to_bit_length(&product);
+
+            assert_eq!(
+                first.try_mul_within(&second, product_bit_length),
+                Ok(product)
+            );
+            if product_bit_length > 0 {
+                assert_eq!(
+                    first.try_mul_within(&second, product_bit_length - 1),
+                    Err(TryMulError::LimitExceeded)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn from_str_radix_within() {
+    for (string, radix) in [
+        ("0", 10),
+        ("-1", 10),
+        ("255", 10),
+        ("256", 10),
+        ("0x_ff_ff", 16),
+        ("-0b1000000000000000000000000000000000000", 2),
+        ("000000000000000000000000000000000000007", 10),
+        ("99999999999999999999999999999999999999", 10),
+        ("zzzzzzzzzzzzzzzzzzzzzzzzzzzzzz", 36),
+    ] {
+        let value = BigInt31::from_str_radix_within(string, radix, usize::MAX)
+            .unwrap();
+        let value_bit_length = to_bit_length(&value);
+
+        assert_eq!(
+            BigInt31::from_str_radix_within(string, radix, value_bit_length),
+            Ok(value)
+        );
+        if value_bit_length > 0 {
+            assert_eq!(
+                BigInt31::from_str_radix_within(
+                    string,
+                    radix,
+                    value_bit_length - 1
+                ),
+                Err(TryFromStringError::LimitExceeded)
+            );
+        }
+    }
+}
+
+#[test]
+fn from_str_radix_within_long_string() {
+    let string = "1".repeat(1 << 20);
+
+    assert_eq!(
+        BigInt31::from_str_radix_within(&string, 10, 1 << 10),
+        Err(TryFromStringError::LimitExceeded)
+    );
+}
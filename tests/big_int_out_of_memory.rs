@@ -0,0 +1,113 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::ptr::null_mut;
+
+use rithm::big_int::{Alphabet, BigInt, TryFromStringError};
+use rithm::fraction::{Fraction, FromStringConstructionError};
+use traiter::numbers::{FromStrRadix, Pow};
+
+type BigInt31 = BigInt<u32, 31>;
+type Fraction31 = Fraction<BigInt31>;
+
+thread_local! {
+    static ALLOCATION_BUDGET: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+struct BudgetedAllocator;
+
+unsafe impl GlobalAlloc for BudgetedAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let is_allowed = ALLOCATION_BUDGET
+            .try_with(|budget| match budget.get() {
+                Some(left) if left < layout.size() => false,
+                Some(left) => {
+                    budget.set(Some(left - layout.size()));
+                    true
+                }
+                None => true,
+            })
+            .unwrap_or(true);
+        if is_allowed {
+            System.alloc(layout)
+        } else {
+            null_mut()
+        }
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: BudgetedAllocator = BudgetedAllocator;
+
+fn with_allocation_budget<T>(
+    budget: usize,
+    function: impl FnOnce() -> T,
+) -> T {
+    ALLOCATION_BUDGET.with(|cell| cell.set(Some(budget)));
+    let result = function();
+    ALLOCATION_BUDGET.with(|cell| cell.set(None));
+    result
+}
+
+#[test]
+fn karatsuba_multiplication() {
+    let value = (BigInt31::from(1u8) << 100_000u32) - BigInt31::from(1u8);
+
+    let result = with_allocation_budget(0, || value.try_mul(&value));
+
+    assert!(result.is_err());
+    assert_eq!(
+        with_allocation_budget(usize::MAX, || value.try_mul(&value)),
+        Ok(&value * &value)
+    );
+}
+
+#[test]
+fn parsing() {
+    let string = "9".repeat(20_000);
+
+    let result = with_allocation_budget(string.len(), || {
+        BigInt31::from_str_radix(&string, 10)
+    });
+
+    assert_eq!(result, Err(TryFromStringError::OutOfMemory));
+    assert!(with_allocation_budget(2 * string.len(), || {
+        BigInt31::from_str_radix(&string, 10)
+    })
+    .is_ok());
+}
+
+#[test]
+fn parsing_with_alphabet() {
+    let alphabet = Alphabet::new("0123456789").unwrap();
+    let string = "9".repeat(20_000);
+
+    let result = with_allocation_budget(string.len(), || {
+        BigInt31::from_str_with_alphabet(&string, &alphabet)
+    });
+
+    assert_eq!(result, Err(TryFromStringError::OutOfMemory));
+    assert!(with_allocation_budget(2 * string.len(), || {
+        BigInt31::from_str_with_alphabet(&string, &alphabet)
+    })
+    .is_ok());
+}
+
+#[test]
+fn fraction_parsing_with_exponent() {
+    let string = "1e100000";
+
+    let result =
+        with_allocation_budget(1_000, || string.parse::<Fraction31>());
+
+    assert_eq!(result, Err(FromStringConstructionError::OutOfMemory));
+    assert_eq!(
+        with_allocation_budget(usize::MAX, || string.parse::<Fraction31>()),
+        Ok(Fraction31::from(
+            BigInt31::from(10u8).pow(BigInt31::from(100_000u32))
+        ))
+    );
+}
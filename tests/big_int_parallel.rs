@@ -0,0 +1,93 @@
+use std::convert::TryFrom;
+
+use rithm::big_int::BigInt;
+
+type BigInt31 = BigInt<u32, 31>;
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn big_int(&mut self, chunks_count: usize) -> BigInt31 {
+        let mut result = BigInt31::from(0u8);
+        for _ in 0..chunks_count {
+            result = (result << 64u32) + BigInt31::from(self.next());
+        }
+        if self.next() % 2 == 0 {
+            -result
+        } else {
+            result
+        }
+    }
+}
+
+fn to_chunks(value: &BigInt31) -> (bool, Vec<u64>) {
+    let is_negative = *value < 0u8;
+    let mut rest = if is_negative { -value } else { value.clone() };
+    let mask = BigInt31::from(u64::MAX);
+    let mut chunks = Vec::new();
+    while rest != 0u8 {
+        chunks.push(u64::try_from(&rest & &mask).unwrap());
+        rest = rest >> 64u32;
+    }
+    (is_negative, chunks)
+}
+
+/// Multiplies by 64-bit chunks of the second factor,
+/// so only non-recursive kernels are involved.
+fn multiply_by_chunks(first: &BigInt31, second: &BigInt31) -> BigInt31 {
+    let (is_negative, chunks) = to_chunks(second);
+    let mut result = BigInt31::from(0u8);
+    for chunk in chunks.into_iter().rev() {
+        result = (result << 64u32) + first * BigInt31::from(chunk);
+    }
+    if is_negative {
+        -result
+    } else {
+        result
+    }
+}
+
+// sizes are in 64-bit chunks, parallel multiplication cutoff
+// corresponds to 1120 31-bit digits or ~543 chunks
+const SIZES: [(usize, usize); 4] =
+    [(600, 600), (600, 1300), (1100, 1100), (1300, 2700)];
+
+#[test]
+fn multiplication_above_parallel_cutoff() {
+    let mut generator = XorShift(0x2545_f491_4f6c_dd1d);
+    for (first_size, second_size) in SIZES {
+        let first = generator.big_int(first_size);
+        let second = generator.big_int(second_size);
+
+        assert_eq!(&first * &second, multiply_by_chunks(&first, &second));
+        assert_eq!(&second * &first, multiply_by_chunks(&first, &second));
+        assert_eq!(&first * &first, multiply_by_chunks(&first, &first));
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_product() {
+    use traiter::numbers::One;
+
+    let mut generator = XorShift(0x9e37_79b9_7f4a_7c15);
+    for count in [0usize, 1, 2, 3, 7, 64] {
+        let values = (0..count)
+            .map(|index| generator.big_int(1 + index * 37 % 300))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            BigInt31::par_product(values.clone()),
+            values
+                .iter()
+                .fold(BigInt31::one(), |product, value| product * value)
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use rithm::big_int::{BigInt, TryFromDigitsError};
+use traiter::numbers::Sign;
+
+type BigInt15 = BigInt<u16, 15>;
+type BigInt31 = BigInt<u32, 31>;
+
+#[test]
+fn round_trip() {
+    for value in [i64::MIN + 1, -(1 << 40), -1, 0, 1, 1 << 31, i64::MAX] {
+        let (sign, digits) = BigInt31::from(value).into_sign_and_digits();
+        assert_eq!(
+            BigInt31::try_from_sign_and_digits(sign, digits),
+            Ok(BigInt31::from(value))
+        );
+        let (sign, digits) = BigInt15::from(value).into_sign_and_digits();
+        assert_eq!(
+            BigInt15::try_from_sign_and_digits(sign, digits),
+            Ok(BigInt15::from(value))
+        );
+    }
+}
+
+#[test]
+fn no_digits() {
+    assert_eq!(
+        BigInt31::try_from_sign_and_digits(Sign::Positive, vec![]),
+        Err(TryFromDigitsError::NoDigits)
+    );
+    assert_eq!(
+        BigInt31::try_from_sign_and_digits(Sign::Zero, vec![]),
+        Err(TryFromDigitsError::NoDigits)
+    );
+}
+
+#[test]
+fn leading_zeros() {
+    assert_eq!(
+        BigInt31::try_from_sign_and_digits(Sign::Positive, vec![1, 0]),
+        Err(TryFromDigitsError::LeadingZeros)
+    );
+    assert_eq!(
+        BigInt31::try_from_sign_and_digits(Sign::Zero, vec![0, 0]),
+        Err(TryFromDigitsError::LeadingZeros)
+    );
+}
+
+#[test]
+fn digit_out_of_range() {
+    assert_eq!(
+        BigInt31::try_from_sign_and_digits(Sign::Positive, vec![1, 1 << 31]),
+        Err(TryFromDigitsError::DigitOutOfRange(1))
+    );
+    assert_eq!(
+        BigInt15::try_from_sign_and_digits(Sign::Negative, vec![u16::MAX]),
+        Err(TryFromDigitsError::DigitOutOfRange(0))
+    );
+    assert_eq!(
+        BigInt15::try_from_sign_and_digits(
+            Sign::Negative,
+            vec![(1 << 15) - 1]
+        ),
+        Ok(BigInt15::from(-((1 << 15) - 1)))
+    );
+}
+
+#[test]
+fn sign_mismatch() {
+    assert_eq!(
+        BigInt31::try_from_sign_and_digits(Sign::Zero, vec![1]),
+        Err(TryFromDigitsError::SignMismatch)
+    );
+    assert_eq!(
+        BigInt31::try_from_sign_and_digits(Sign::Positive, vec![0]),
+        Err(TryFromDigitsError::SignMismatch)
+    );
+    assert_eq!(
+        BigInt31::try_from_sign_and_digits(Sign::Negative, vec![0]),
+        Err(TryFromDigitsError::SignMismatch)
+    );
+}
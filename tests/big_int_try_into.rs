@@ -0,0 +1,79 @@
+use std::convert::TryFrom;
+
+use rithm::big_int::BigInt;
+
+type BigInt15 = BigInt<u16, 15>;
+type BigInt31 = BigInt<u32, 31>;
+
+#[test]
+fn narrow_unsigned_integers_round_trip() {
+    for value in 0..=u8::MAX {
+        assert_eq!(u8::try_from(BigInt15::from(value)).ok(), Some(value));
+        assert_eq!(u8::try_from(BigInt31::from(value)).ok(), Some(value));
+    }
+    for value in (0..=u16::MAX).step_by(257) {
+        assert_eq!(u16::try_from(BigInt15::from(value)).ok(), Some(value));
+        assert_eq!(u16::try_from(BigInt31::from(value)).ok(), Some(value));
+    }
+    assert_eq!(u16::try_from(BigInt15::from(u16::MAX)).ok(), Some(u16::MAX));
+}
+
+#[test]
+fn narrow_signed_integers_round_trip() {
+    for value in i8::MIN..=i8::MAX {
+        assert_eq!(i8::try_from(BigInt15::from(value)).ok(), Some(value));
+        assert_eq!(i8::try_from(BigInt31::from(value)).ok(), Some(value));
+    }
+    for value in [i16::MIN, i16::MIN + 1, -1, 0, 1, i16::MAX] {
+        assert_eq!(i16::try_from(BigInt15::from(value)).ok(), Some(value));
+        assert_eq!(i16::try_from(BigInt31::from(value)).ok(), Some(value));
+    }
+}
+
+#[test]
+fn overflowing_integers_are_rejected() {
+    assert!(u8::try_from(BigInt31::from(256u16)).is_err());
+    assert!(u16::try_from(BigInt15::from(65_536u32)).is_err());
+    assert!(u16::try_from(BigInt31::from(u32::MAX)).is_err());
+    assert!(i8::try_from(BigInt31::from(128i16)).is_err());
+    assert!(i8::try_from(BigInt31::from(-129i16)).is_err());
+    assert!(u32::try_from(BigInt15::from(1u64 << 32)).is_err());
+    assert!(u64::try_from(BigInt31::from(u128::MAX)).is_err());
+    assert_eq!(u64::try_from(BigInt15::from(u64::MAX)).ok(), Some(u64::MAX));
+}
+
+macro_rules! check_signed_minimums {
+    ($big_int:ty) => {
+        assert_eq!(<$big_int>::from(i8::MIN), -<$big_int>::from(1u8 << 7));
+        assert_eq!(<$big_int>::from(i16::MIN), -<$big_int>::from(1u16 << 15));
+        assert_eq!(<$big_int>::from(i32::MIN), -<$big_int>::from(1u32 << 31));
+        assert_eq!(<$big_int>::from(i64::MIN), -<$big_int>::from(1u64 << 63));
+        assert_eq!(
+            <$big_int>::from(i128::MIN),
+            -<$big_int>::from(1u128 << 127)
+        );
+        assert_eq!(
+            <$big_int>::from(isize::MIN),
+            -<$big_int>::from(isize::MIN.unsigned_abs())
+        );
+        assert_eq!(
+            i8::try_from(<$big_int>::from(i8::MIN)).ok(),
+            Some(i8::MIN)
+        );
+        assert_eq!(
+            i64::try_from(<$big_int>::from(i64::MIN)).ok(),
+            Some(i64::MIN)
+        );
+        assert_eq!(
+            i128::try_from(<$big_int>::from(i128::MIN)).ok(),
+            Some(i128::MIN)
+        );
+    };
+}
+
+#[test]
+fn signed_minimums() {
+    check_signed_minimums!(BigInt<u8, 7>);
+    check_signed_minimums!(BigInt15);
+    check_signed_minimums!(BigInt31);
+}
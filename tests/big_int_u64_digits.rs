@@ -0,0 +1,139 @@
+use std::convert::TryFrom;
+
+use rithm::big_int::BigInt;
+use traiter::numbers::{CheckedDivRemEuclid, FromStrRadix};
+
+type BigInt31 = BigInt<u32, 31>;
+type BigInt63 = BigInt<u64, 63>;
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn big_int(&mut self, chunks_count: usize) -> BigInt31 {
+        let mut result = BigInt31::from(0u8);
+        for _ in 0..chunks_count {
+            result =
+                (result << BigInt31::from(64u8)) + BigInt31::from(self.next());
+        }
+        if self.next() % 2 == 0 {
+            -result
+        } else {
+            result
+        }
+    }
+}
+
+fn to_samples(generator: &mut XorShift) -> Vec<BigInt31> {
+    let mut result = [0usize, 1, 2, 3, 4, 5, 8, 16, 33, 70, 71, 150, 300]
+        .iter()
+        .map(|&chunks_count| generator.big_int(chunks_count))
+        .collect::<Vec<_>>();
+    for exponent in [62u8, 63, 64, 126, 127, 128] {
+        let power = BigInt31::from(1u8) << BigInt31::from(exponent);
+        result.push(&power - BigInt31::from(1u8));
+        result.push(power.clone());
+        result.push(&power + BigInt31::from(1u8));
+        result.push(-power);
+    }
+    result
+}
+
+#[test]
+fn multiplication() {
+    let mut generator = XorShift(0x9e37_79b9_7f4a_7c15);
+    let samples = to_samples(&mut generator);
+    for first in &samples {
+        for second in &samples {
+            assert_eq!(
+                BigInt63::from_big_int(first) * BigInt63::from_big_int(second),
+                BigInt63::from_big_int(&(first * second))
+            );
+        }
+    }
+}
+
+#[test]
+fn karatsuba_multiplication() {
+    let mut generator = XorShift(0xdead_beef_cafe_babe);
+    for (first_chunks_count, second_chunks_count) in
+        [(70, 70), (71, 140), (150, 151), (300, 90), (1_200, 1_300)]
+    {
+        let first = generator.big_int(first_chunks_count);
+        let second = generator.big_int(second_chunks_count);
+        let product =
+            BigInt63::from_big_int(&first) * BigInt63::from_big_int(&second);
+        assert_eq!(product, BigInt63::from_big_int(&(&first * &second)));
+        assert_eq!(product.to_string(), (first * second).to_string());
+    }
+}
+
+#[test]
+fn division() {
+    let mut generator = XorShift(0x0123_4567_89ab_cdef);
+    let samples = to_samples(&mut generator);
+    for dividend in &samples {
+        for divisor in &samples {
+            let result = BigInt63::from_big_int(dividend)
+                .checked_div_rem_euclid(BigInt63::from_big_int(divisor));
+            let expected =
+                dividend.clone().checked_div_rem_euclid(divisor.clone());
+            assert_eq!(
+                result,
+                expected.map(|(quotient, remainder)| (
+                    BigInt63::from_big_int(&quotient),
+                    BigInt63::from_big_int(&remainder)
+                ))
+            );
+        }
+    }
+}
+
+#[test]
+fn float_conversion() {
+    let mut generator = XorShift(0xfeed_face_f00d_d00d);
+    for value in to_samples(&mut generator) {
+        assert_eq!(
+            f64::try_from(BigInt63::from_big_int(&value)).ok(),
+            f64::try_from(value.clone()).ok()
+        );
+        assert_eq!(
+            f32::try_from(BigInt63::from_big_int(&value)).ok(),
+            f32::try_from(value).ok()
+        );
+    }
+    for value in [
+        0.0f64,
+        1.0,
+        -1.0,
+        9_007_199_254_740_993.0,
+        1e300,
+        -1e300,
+        f64::MAX,
+        f64::MIN,
+    ] {
+        let big_int = BigInt63::try_from(value).unwrap();
+        assert_eq!(f64::try_from(big_int.clone()).ok(), Some(value));
+        assert_eq!(
+            big_int.to_string(),
+            BigInt31::try_from(value).unwrap().to_string()
+        );
+    }
+    assert_eq!(
+        f64::try_from(
+            BigInt63::from_str_radix("9007199254740993", 10).unwrap()
+        )
+        .ok(),
+        Some(9_007_199_254_740_992.0)
+    );
+    assert!(
+        f64::try_from(BigInt63::from(1u8) << BigInt63::from(1_024u16))
+            .is_err()
+    );
+}
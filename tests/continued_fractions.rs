@@ -0,0 +1,139 @@
+use rithm::big_int::BigInt;
+use rithm::fraction::Fraction;
+
+type BigInt31 = BigInt<u32, 31>;
+type Fraction31 = Fraction<BigInt31>;
+
+fn to_fraction(numerator: i128, denominator: i128) -> Fraction31 {
+    Fraction31::new(BigInt31::from(numerator), BigInt31::from(denominator))
+        .unwrap()
+}
+
+fn to_big_ints(values: &[i64]) -> Vec<BigInt31> {
+    values.iter().map(|&value| BigInt31::from(value)).collect()
+}
+
+fn to_samples() -> Vec<Fraction31> {
+    let mut result = [
+        (0, 1),
+        (1, 1),
+        (-1, 1),
+        (7, 1),
+        (-7, 1),
+        (1, 2),
+        (-1, 2),
+        (7, 3),
+        (-7, 3),
+        (415, 93),
+        (-415, 93),
+        (93, 415),
+        (1, i128::MAX),
+        (i128::MAX, i128::MAX - 1),
+        (-i128::MAX, 3),
+    ]
+    .iter()
+    .map(|&(numerator, denominator)| to_fraction(numerator, denominator))
+    .collect::<Vec<_>>();
+    // consecutive Fibonacci numbers have the longest expansions
+    let (mut previous, mut current) =
+        (BigInt31::from(1u8), BigInt31::from(1u8));
+    for _ in 0..200 {
+        let next = &previous + &current;
+        previous = std::mem::replace(&mut current, next);
+    }
+    result.push(Fraction31::new(current.clone(), previous.clone()).unwrap());
+    result.push(Fraction31::new(-previous, current).unwrap());
+    result
+}
+
+#[test]
+fn reconstruction() {
+    for value in to_samples() {
+        assert_eq!(
+            Fraction31::from_partial_quotients(value.partial_quotients()),
+            Some(value)
+        );
+    }
+}
+
+#[test]
+fn partial_quotients_tail() {
+    for value in to_samples() {
+        let quotients = value.partial_quotients().collect::<Vec<_>>();
+
+        assert!(!quotients.is_empty());
+        assert!(quotients[1..].iter().all(|quotient| *quotient > 0u8));
+        if quotients.len() > 1 {
+            assert!(quotients[quotients.len() - 1] > 1u8);
+        }
+    }
+}
+
+#[test]
+fn convergents_end_at_value() {
+    for value in to_samples() {
+        let convergents = value.convergents().collect::<Vec<_>>();
+
+        assert_eq!(convergents.len(), value.partial_quotients().count());
+        assert_eq!(convergents.last(), Some(&value));
+    }
+}
+
+#[test]
+fn convergents_determinants() {
+    for value in to_samples() {
+        let convergents = value.convergents().collect::<Vec<_>>();
+        for window in convergents.windows(2) {
+            let (previous, current) = (&window[0], &window[1]);
+            let determinant = current.numerator() * previous.denominator()
+                - previous.numerator() * current.denominator();
+
+            assert!(determinant == 1u8 || determinant == -1i8);
+        }
+    }
+}
+
+#[test]
+fn negative_and_integer_values() {
+    assert_eq!(
+        to_fraction(-7, 3).partial_quotients().collect::<Vec<_>>(),
+        to_big_ints(&[-3, 1, 2])
+    );
+    assert_eq!(
+        to_fraction(-5, 1).partial_quotients().collect::<Vec<_>>(),
+        to_big_ints(&[-5])
+    );
+    assert_eq!(
+        to_fraction(0, 1).partial_quotients().collect::<Vec<_>>(),
+        to_big_ints(&[0])
+    );
+    assert_eq!(
+        to_fraction(-7, 3).convergents().collect::<Vec<_>>(),
+        vec![to_fraction(-3, 1), to_fraction(-2, 1), to_fraction(-7, 3)]
+    );
+}
+
+#[test]
+fn zero_quotient_after_first() {
+    // [a; 0, b, ...] collapses to [a + b; ...]
+    assert_eq!(
+        Fraction31::from_partial_quotients(to_big_ints(&[3, 0, 5])),
+        Some(to_fraction(8, 1))
+    );
+    assert_eq!(
+        Fraction31::from_partial_quotients(to_big_ints(&[1, 0, 2, 3])),
+        Fraction31::from_partial_quotients(to_big_ints(&[3, 3]))
+    );
+    assert_eq!(
+        Fraction31::from_partial_quotients(to_big_ints(&[-2, 0, 1, 4])),
+        Some(to_fraction(-3, 4))
+    );
+    assert_eq!(
+        Fraction31::from_partial_quotients(to_big_ints(&[1, 0])),
+        None
+    );
+    assert_eq!(
+        Fraction31::from_partial_quotients(Vec::<BigInt31>::new()),
+        None
+    );
+}
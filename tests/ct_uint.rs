@@ -0,0 +1,414 @@
+use std::time::Instant;
+
+use rithm::big_int::BigInt;
+use rithm::ct_uint::CtUint;
+use traiter::numbers::{
+    CheckedPowRemEuclid, CheckedRemEuclid, CheckedRemEuclidInv, Gcd,
+};
+
+type BigInt31 = BigInt<u32, 31>;
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn ct_uint<const LIMBS: usize>(&mut self) -> CtUint<LIMBS> {
+        let mut limbs = [0; LIMBS];
+        let bit_length = (self.next() % (64 * LIMBS as u64 + 1)) as usize;
+        for (index, limb) in limbs.iter_mut().enumerate() {
+            let limb_bit_length =
+                bit_length.saturating_sub(64 * index).min(64);
+            *limb = if limb_bit_length == 64 {
+                self.next()
+            } else {
+                self.next() & ((1 << limb_bit_length) - 1)
+            };
+        }
+        CtUint::from_limbs(limbs)
+    }
+}
+
+fn to_big_int<const LIMBS: usize>(value: &CtUint<LIMBS>) -> BigInt31 {
+    value
+        .limbs()
+        .iter()
+        .rev()
+        .fold(BigInt31::from(0u8), |result, &limb| {
+            (result << 64u32) + BigInt31::from(limb)
+        })
+}
+
+fn to_samples<const LIMBS: usize>(
+    generator: &mut XorShift,
+) -> Vec<CtUint<LIMBS>> {
+    let mut result = [0u64, 1, 2, 3, 4, 5, 6, 7, 8, 255, u64::MAX]
+        .iter()
+        .map(|&value| CtUint::from(value))
+        .collect::<Vec<_>>();
+    result.push(CtUint::from_limbs([u64::MAX; LIMBS]));
+    let mut almost_max_limbs = [u64::MAX; LIMBS];
+    almost_max_limbs[0] -= 1;
+    result.push(CtUint::from_limbs(almost_max_limbs));
+    result.extend((0..8).map(|_| generator.ct_uint::<LIMBS>()));
+    result
+}
+
+fn check_arithmetic<const LIMBS: usize>(seed: u64) {
+    let mut generator = XorShift(seed);
+    let samples = to_samples::<LIMBS>(&mut generator);
+    for modulus in &samples {
+        let big_modulus = to_big_int(modulus);
+        let is_modulus_zero = big_modulus == 0u8;
+        for first in &samples {
+            let big_first = to_big_int(first);
+            let rem_modulo = first.rem_modulo(modulus);
+            let inverse = first.invert_modulo(modulus);
+
+            assert_eq!(bool::from(rem_modulo.is_none()), is_modulus_zero);
+            if !is_modulus_zero {
+                assert_eq!(
+                    to_big_int(&rem_modulo.unwrap()),
+                    (&big_first).checked_rem_euclid(&big_modulus).unwrap()
+                );
+                let is_invertible = big_modulus != 1u8
+                    && big_first.clone().gcd(&big_modulus) == 1u8;
+
+                assert_eq!(bool::from(inverse.is_some()), is_invertible);
+                if is_invertible {
+                    assert_eq!(
+                        to_big_int(&inverse.unwrap()),
+                        (&big_first)
+                            .checked_rem_euclid_inv(&big_modulus)
+                            .unwrap()
+                    );
+                }
+            }
+            for second in &samples {
+                let big_second = to_big_int(second);
+                let sum = first.add_modulo(second, modulus);
+                let difference = first.sub_modulo(second, modulus);
+                let product = first.mul_modulo(second, modulus);
+
+                assert_eq!(bool::from(sum.is_none()), is_modulus_zero);
+                assert_eq!(bool::from(difference.is_none()), is_modulus_zero);
+                assert_eq!(bool::from(product.is_none()), is_modulus_zero);
+                if !is_modulus_zero {
+                    assert_eq!(
+                        to_big_int(&sum.unwrap()),
+                        (&big_first + &big_second)
+                            .checked_rem_euclid(&big_modulus)
+                            .unwrap()
+                    );
+                    assert_eq!(
+                        to_big_int(&difference.unwrap()),
+                        (&big_first - &big_second)
+                            .checked_rem_euclid(&big_modulus)
+                            .unwrap()
+                    );
+                    assert_eq!(
+                        to_big_int(&product.unwrap()),
+                        (&big_first * &big_second)
+                            .checked_rem_euclid(&big_modulus)
+                            .unwrap()
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn arithmetic() {
+    check_arithmetic::<1>(0x2545_f491_4f6c_dd1d);
+    check_arithmetic::<2>(0x9e37_79b9_7f4a_7c15);
+    check_arithmetic::<3>(0xbf58_476d_1ce4_e5b9);
+}
+
+fn check_pow_modulo<const LIMBS: usize>(seed: u64) {
+    let mut generator = XorShift(seed);
+    let samples = to_samples::<LIMBS>(&mut generator);
+    for modulus in samples.iter().step_by(3) {
+        let big_modulus = to_big_int(modulus);
+        let is_modulus_zero = big_modulus == 0u8;
+        for base in samples.iter().skip(1).step_by(2) {
+            let big_base = to_big_int(base);
+            for exponent in samples.iter().step_by(2) {
+                let power = base.pow_modulo(exponent, modulus);
+
+                assert_eq!(bool::from(power.is_none()), is_modulus_zero);
+                if !is_modulus_zero {
+                    assert_eq!(
+                        to_big_int(&power.unwrap()),
+                        (&big_base)
+                            .checked_pow_rem_euclid(
+                                &to_big_int(exponent),
+                                &big_modulus
+                            )
+                            .unwrap()
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn pow_modulo() {
+    check_pow_modulo::<1>(0x2545_f491_4f6c_dd1d);
+    check_pow_modulo::<2>(0x9e37_79b9_7f4a_7c15);
+}
+
+#[test]
+fn widening_mul() {
+    let mut generator = XorShift(0x94d0_49bb_1331_11eb);
+    let samples = to_samples::<2>(&mut generator);
+    for first in &samples {
+        for second in &samples {
+            let (low, high) = first.widening_mul(second);
+
+            assert_eq!(
+                (to_big_int(&high) << 128u32) + to_big_int(&low),
+                to_big_int(first) * to_big_int(second)
+            );
+        }
+    }
+}
+
+#[test]
+fn invert_modulo_small() {
+    for modulus in 0u64..=200 {
+        let big_modulus = BigInt31::from(modulus);
+        for value in 0u64..=200 {
+            let inverse =
+                CtUint::<1>::from(value).invert_modulo(&CtUint::from(modulus));
+            let big_value = BigInt31::from(value);
+            let is_invertible =
+                modulus > 1 && big_value.clone().gcd(&big_modulus) == 1u8;
+
+            assert_eq!(bool::from(inverse.is_some()), is_invertible);
+            if is_invertible {
+                assert_eq!(
+                    (BigInt31::from(inverse.unwrap().limbs()[0]) * big_value)
+                        .checked_rem_euclid(&big_modulus)
+                        .unwrap(),
+                    BigInt31::from(1u8)
+                );
+            }
+        }
+    }
+}
+
+/// Executes the function in a forked child process stepping over
+/// every instruction with `ptrace` and returns the number of executed
+/// instructions with a hash of their addresses,
+/// so inputs that take different branches give different traces.
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+fn to_instruction_trace(function: impl FnOnce()) -> (usize, u64) {
+    use std::ptr::null_mut;
+
+    unsafe {
+        let pid = libc::fork();
+        assert!(pid >= 0, "{}", std::io::Error::last_os_error());
+        if pid == 0 {
+            if libc::ptrace(
+                libc::PTRACE_TRACEME,
+                0,
+                null_mut::<libc::c_void>(),
+                null_mut::<libc::c_void>(),
+            ) != 0
+            {
+                libc::_exit(1);
+            }
+            libc::raise(libc::SIGSTOP);
+            function();
+            libc::_exit(0);
+        }
+        let mut status = 0;
+        assert_eq!(libc::waitpid(pid, &mut status, 0), pid);
+        assert!(libc::WIFSTOPPED(status), "Tracing is not permitted.");
+        // FNV-1a hashing of instruction addresses
+        let (mut instructions_count, mut hash) =
+            (0usize, 0xcbf2_9ce4_8422_2325u64);
+        loop {
+            assert_eq!(
+                libc::ptrace(
+                    libc::PTRACE_SINGLESTEP,
+                    pid,
+                    null_mut::<libc::c_void>(),
+                    null_mut::<libc::c_void>(),
+                ),
+                0
+            );
+            assert_eq!(libc::waitpid(pid, &mut status, 0), pid);
+            if libc::WIFEXITED(status) {
+                break;
+            }
+            assert!(
+                libc::WIFSTOPPED(status)
+                    && libc::WSTOPSIG(status) == libc::SIGTRAP
+            );
+            let mut registers = std::mem::zeroed::<libc::user_regs_struct>();
+            libc::ptrace(
+                libc::PTRACE_GETREGS,
+                pid,
+                null_mut::<libc::c_void>(),
+                &mut registers as *mut libc::user_regs_struct
+                    as *mut libc::c_void,
+            );
+            hash = (hash ^ registers.rip).wrapping_mul(0x100_0000_01b3);
+            instructions_count += 1;
+        }
+        (instructions_count, hash)
+    }
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[test]
+fn instruction_traces() {
+    let mut generator = XorShift(0x5851_f42d_4c95_7f2d);
+    let modulus = CtUint::<1>::from(0xffff_ffff_0000_0001u64);
+    let secrets = [
+        CtUint::<1>::from(0u64),
+        generator.ct_uint::<1>(),
+        CtUint::<1>::from(generator.next()),
+    ];
+    let base = CtUint::<1>::from(generator.next());
+    let check = |name: &str, function: &dyn Fn(&CtUint<1>)| {
+        let traces = secrets
+            .iter()
+            .map(|secret| to_instruction_trace(|| function(secret)))
+            .collect::<Vec<_>>();
+
+        assert!(
+            traces.iter().all(|trace| *trace == traces[0]),
+            "{}: {:?}",
+            name,
+            traces
+        );
+    };
+
+    check("add_modulo", &|value| {
+        std::hint::black_box(
+            std::hint::black_box(value).add_modulo(&base, &modulus),
+        );
+    });
+    check("sub_modulo", &|value| {
+        std::hint::black_box(
+            std::hint::black_box(value).sub_modulo(&base, &modulus),
+        );
+    });
+    check("mul_modulo", &|value| {
+        std::hint::black_box(
+            std::hint::black_box(value).mul_modulo(&base, &modulus),
+        );
+    });
+    // unoptimized code is stepped through an order of magnitude slower,
+    // so operations built from the ones above are traced
+    // only in optimized builds
+    if !cfg!(debug_assertions) {
+        check("invert_modulo", &|value| {
+            std::hint::black_box(
+                std::hint::black_box(value).invert_modulo(&modulus),
+            );
+        });
+        check("pow_modulo", &|exponent| {
+            std::hint::black_box(
+                std::hint::black_box(&base).pow_modulo(exponent, &modulus),
+            );
+        });
+    }
+}
+
+/// Welch's t-statistic of timings for two classes of inputs
+/// following "dude, is my code constant time?" approach:
+/// values far from zero (over 10 by dudect convention)
+/// indicate that execution time depends on the inputs.
+fn to_timings_t_statistic<Input>(
+    classes: [&[Input]; 2],
+    mut function: impl FnMut(&Input),
+) -> f64 {
+    const MEASUREMENTS_COUNT: usize = 20_000;
+    let mut generator = XorShift(0xd1b5_4a32_d192_ed03);
+    let mut timings = [Vec::new(), Vec::new()];
+    for _ in 0..MEASUREMENTS_COUNT {
+        let class_index = (generator.next() % 2) as usize;
+        let inputs = classes[class_index];
+        let input = &inputs[(generator.next() as usize) % inputs.len()];
+        let start = Instant::now();
+        function(input);
+        timings[class_index].push(start.elapsed().as_nanos() as f64);
+    }
+    // cropping outliers caused by interrupts and preemption
+    let mut threshold = timings.concat();
+    threshold.sort_by(f64::total_cmp);
+    let threshold = threshold[threshold.len() * 9 / 10];
+    let [first, second] = timings.map(|timings| {
+        let timings = timings
+            .into_iter()
+            .filter(|&timing| timing <= threshold)
+            .collect::<Vec<_>>();
+        let count = timings.len() as f64;
+        let mean = timings.iter().sum::<f64>() / count;
+        let variance = timings
+            .iter()
+            .map(|timing| (timing - mean) * (timing - mean))
+            .sum::<f64>()
+            / (count - 1.0);
+        (mean, variance, count)
+    });
+    (first.0 - second.0)
+        / (first.1 / first.2 + second.1 / second.2)
+            .sqrt()
+            .max(f64::EPSILON)
+}
+
+#[test]
+#[ignore = "timing measurements require optimized build and quiet machine, \
+            run with `cargo test --release --test ct_uint -- --ignored`"]
+fn timing_independence() {
+    const T_STATISTIC_THRESHOLD: f64 = 10.;
+    let mut generator = XorShift(0x2127_599b_f432_5c37);
+    let modulus = CtUint::<4>::from_limbs([
+        0xffff_ffff_ffff_ffff,
+        0x0000_0000_ffff_ffff,
+        0x0000_0000_0000_0000,
+        0xffff_ffff_0000_0001,
+    ]);
+    let fixed = [CtUint::<4>::from(0u64)];
+    let random = (0..64)
+        .map(|_| generator.ct_uint::<4>())
+        .collect::<Vec<_>>();
+    let base = generator.ct_uint::<4>();
+
+    let t_statistics = [
+        to_timings_t_statistic([&fixed, &random], |exponent| {
+            std::hint::black_box(
+                std::hint::black_box(&base).pow_modulo(exponent, &modulus),
+            );
+        }),
+        to_timings_t_statistic([&fixed, &random], |value| {
+            std::hint::black_box(
+                std::hint::black_box(value).invert_modulo(&modulus),
+            );
+        }),
+        to_timings_t_statistic([&fixed, &random], |value| {
+            std::hint::black_box(
+                std::hint::black_box(value).mul_modulo(&base, &modulus),
+            );
+        }),
+    ];
+
+    for t_statistic in t_statistics {
+        assert!(
+            t_statistic.abs() < T_STATISTIC_THRESHOLD,
+            "{:?}",
+            t_statistics
+        );
+    }
+}
@@ -0,0 +1,776 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+
+#[cfg(feature = "half")]
+use half::{bf16, f16};
+use rithm::big_int::BigInt;
+use rithm::binary128::Binary128;
+use rithm::fraction::{Fraction, ToFloatError};
+use rithm::rounding_mode::RoundingMode;
+use traiter::numbers::{Abs, Signed};
+
+type BigInt31 = BigInt<u32, 31>;
+type Fraction31 = Fraction<BigInt31>;
+
+const MODES: [RoundingMode; 9] = [
+    RoundingMode::Ceiling,
+    RoundingMode::Down,
+    RoundingMode::Floor,
+    RoundingMode::HalfDown,
+    RoundingMode::HalfEven,
+    RoundingMode::HalfOdd,
+    RoundingMode::HalfUp,
+    RoundingMode::Unnecessary,
+    RoundingMode::Up,
+];
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+fn to_fraction(numerator: i128, denominator: i128) -> Fraction31 {
+    Fraction31::new(BigInt31::from(numerator), BigInt31::from(denominator))
+        .unwrap()
+}
+
+fn power_of_two(exponent: i32) -> Fraction31 {
+    let one = BigInt31::from(1u8);
+    if exponent >= 0 {
+        Fraction31::new(one.clone() << exponent.unsigned_abs(), one).unwrap()
+    } else {
+        Fraction31::new(one.clone(), one << exponent.unsigned_abs()).unwrap()
+    }
+}
+
+/// Values around subnormals, normals and overflow threshold
+/// of floating point format with given parameters
+/// along with few ordinary and random values.
+fn to_samples(
+    mantissa_digits: i32,
+    min_exp: i32,
+    max_exp: i32,
+) -> Vec<Fraction31> {
+    let tiny_exponent = min_exp - mantissa_digits;
+    let max_ulp_exponent = max_exp - mantissa_digits;
+    let smallest_subnormal = power_of_two(tiny_exponent);
+    let min_positive = power_of_two(min_exp - 1);
+    let largest_subnormal = &min_positive - &smallest_subnormal;
+    let max = power_of_two(max_exp) - power_of_two(max_ulp_exponent);
+    let subnormal_half_ulp = power_of_two(tiny_exponent - 1);
+    let subnormal_epsilon = power_of_two(tiny_exponent - 8);
+    let max_half_ulp = power_of_two(max_ulp_exponent - 1);
+    let max_epsilon = power_of_two(max_ulp_exponent - 8);
+    let mut result = vec![
+        to_fraction(0, 1),
+        to_fraction(1, 1),
+        to_fraction(1, 3),
+        to_fraction(2, 3),
+        to_fraction(1, 10),
+        to_fraction(22, 7),
+        to_fraction(355, 113),
+        power_of_two(mantissa_digits) + to_fraction(1, 1),
+        power_of_two(mantissa_digits) + to_fraction(3, 1),
+        power_of_two(mantissa_digits + 1) + to_fraction(1, 1),
+        power_of_two(tiny_exponent - 20),
+        &subnormal_half_ulp - &subnormal_epsilon,
+        subnormal_half_ulp.clone(),
+        &subnormal_half_ulp + &subnormal_epsilon,
+        smallest_subnormal.clone(),
+        &smallest_subnormal + &subnormal_epsilon,
+        &subnormal_half_ulp * to_fraction(3, 1),
+        &subnormal_half_ulp * to_fraction(5, 1),
+        &largest_subnormal - &subnormal_half_ulp,
+        &largest_subnormal - &subnormal_epsilon,
+        largest_subnormal.clone(),
+        &largest_subnormal + &subnormal_epsilon,
+        &largest_subnormal + &subnormal_half_ulp,
+        &min_positive - &subnormal_epsilon,
+        min_positive.clone(),
+        &min_positive + &subnormal_epsilon,
+        &min_positive + &subnormal_half_ulp,
+        &min_positive + &smallest_subnormal,
+        &max - &max_half_ulp,
+        &max - &max_epsilon,
+        max.clone(),
+        &max + &max_epsilon,
+        &max + &max_half_ulp - &max_epsilon,
+        &max + &max_half_ulp,
+        &max + &max_half_ulp + &max_epsilon,
+        power_of_two(max_exp),
+        power_of_two(max_exp) * to_fraction(3, 1),
+        power_of_two(max_exp + 10),
+    ];
+    let mut generator = XorShift(0x2545_f491_4f6c_dd1d);
+    for _ in 0..64 {
+        let numerator = BigInt31::from(generator.next());
+        let denominator = BigInt31::from(generator.next() | 1);
+        let scale = power_of_two(
+            (generator.next() % ((max_exp - tiny_exponent) as u64)) as i32
+                + tiny_exponent,
+        );
+        result.push(Fraction31::new(numerator, denominator).unwrap() * scale);
+    }
+    let negated = result.iter().map(|value| -value).collect::<Vec<_>>();
+    result.extend(negated);
+    result
+}
+
+/// Checks rounded conversion against the pair of adjacent floats
+/// enclosing the value, the infinity stands for the float
+/// with the magnitude of `2 ** MAX_EXP`
+/// and encloses all the values above it.
+macro_rules! check_try_to_float_rounded {
+    ($float:ty => $bits:ty, $try_method:ident, $value:expr) => {{
+        let value: &Fraction31 = $value;
+        let sign_bit: $bits = 1 << (<$bits>::BITS - 1);
+        let infinity_bits = <$float>::MAX.to_bits() + 1;
+        let is_negative = value.numerator().is_negative();
+        let magnitude = value.abs();
+        let to_exact_magnitude = |bits: $bits| {
+            if bits == infinity_bits {
+                power_of_two(<$float>::MAX_EXP)
+            } else {
+                Fraction31::try_from(<$float>::from_bits(bits))
+                    .unwrap()
+                    .abs()
+            }
+        };
+        let lower_bits =
+            value.$try_method(RoundingMode::Down).unwrap().0.to_bits()
+                & !sign_bit;
+        let upper_bits = lower_bits + 1;
+        let lower = to_exact_magnitude(lower_bits);
+        let upper = to_exact_magnitude(upper_bits);
+        let is_out_of_range = magnitude >= power_of_two(<$float>::MAX_EXP);
+        assert!(
+            lower <= magnitude && (magnitude < upper || is_out_of_range),
+            "{}",
+            value
+        );
+        let is_exact = lower == magnitude;
+        let half_ordering = (&magnitude + &magnitude).cmp(&(lower + upper));
+        for mode in MODES {
+            let result = value.$try_method(mode);
+            let is_rounded_up = if is_exact {
+                false
+            } else {
+                match mode {
+                    RoundingMode::Ceiling => !is_negative,
+                    RoundingMode::Down => false,
+                    RoundingMode::Floor => is_negative,
+                    RoundingMode::HalfDown => half_ordering.is_gt(),
+                    RoundingMode::HalfEven => {
+                        half_ordering.is_gt()
+                            || (half_ordering.is_eq() && lower_bits % 2 == 1)
+                    }
+                    RoundingMode::HalfOdd => {
+                        half_ordering.is_gt()
+                            || (half_ordering.is_eq() && lower_bits % 2 == 0)
+                    }
+                    RoundingMode::HalfUp => half_ordering.is_ge(),
+                    RoundingMode::Unnecessary => {
+                        assert_eq!(
+                            result.map(|(float, ordering)| (
+                                float.to_bits(),
+                                ordering
+                            )),
+                            Err(if is_out_of_range {
+                                ToFloatError::TooLarge
+                            } else {
+                                ToFloatError::Inexact
+                            }),
+                            "{} {:?}",
+                            value,
+                            mode
+                        );
+                        continue;
+                    }
+                    RoundingMode::Up => true,
+                }
+            };
+            let expected_bits = if is_rounded_up {
+                upper_bits
+            } else {
+                lower_bits
+            };
+            assert_eq!(
+                result.map(|(float, ordering)| (float.to_bits(), ordering)),
+                if expected_bits == infinity_bits {
+                    Err(ToFloatError::TooLarge)
+                } else {
+                    Ok((
+                        if is_negative {
+                            expected_bits | sign_bit
+                        } else {
+                            expected_bits
+                        },
+                        if is_exact {
+                            Ordering::Equal
+                        } else if is_rounded_up != is_negative {
+                            Ordering::Greater
+                        } else {
+                            Ordering::Less
+                        },
+                    ))
+                },
+                "{} {:?}",
+                value,
+                mode
+            );
+        }
+    }};
+}
+
+#[test]
+fn try_to_f32_rounded() {
+    for value in
+        to_samples(f32::MANTISSA_DIGITS as i32, f32::MIN_EXP, f32::MAX_EXP)
+    {
+        check_try_to_float_rounded!(f32 => u32, try_to_f32_rounded, &value);
+    }
+}
+
+#[test]
+fn try_to_f64_rounded() {
+    for value in
+        to_samples(f64::MANTISSA_DIGITS as i32, f64::MIN_EXP, f64::MAX_EXP)
+    {
+        check_try_to_float_rounded!(f64 => u64, try_to_f64_rounded, &value);
+    }
+}
+
+#[test]
+fn try_to_binary128_rounded() {
+    for value in to_samples(
+        Binary128::MANTISSA_DIGITS as i32,
+        Binary128::MIN_EXP,
+        Binary128::MAX_EXP,
+    ) {
+        check_try_to_float_rounded!(
+            Binary128 => u128,
+            try_to_binary128_rounded,
+            &value
+        );
+    }
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn try_to_bf16_rounded() {
+    for value in
+        to_samples(bf16::MANTISSA_DIGITS as i32, bf16::MIN_EXP, bf16::MAX_EXP)
+    {
+        check_try_to_float_rounded!(bf16 => u16, try_to_bf16_rounded, &value);
+    }
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn try_to_f16_rounded() {
+    for value in
+        to_samples(f16::MANTISSA_DIGITS as i32, f16::MIN_EXP, f16::MAX_EXP)
+    {
+        check_try_to_float_rounded!(f16 => u16, try_to_f16_rounded, &value);
+    }
+}
+
+#[test]
+fn try_to_f64_rounded_orderings() {
+    let third = to_fraction(1, 3);
+    let lower_third = f64::from_bits(0x3fd5_5555_5555_5555);
+    let upper_third = f64::from_bits(0x3fd5_5555_5555_5556);
+
+    assert_eq!(
+        third.try_to_f64_rounded(RoundingMode::HalfEven),
+        Ok((lower_third, Ordering::Less))
+    );
+    assert_eq!(
+        third.try_to_f64_rounded(RoundingMode::Floor),
+        Ok((lower_third, Ordering::Less))
+    );
+    assert_eq!(
+        third.try_to_f64_rounded(RoundingMode::Ceiling),
+        Ok((upper_third, Ordering::Greater))
+    );
+    assert_eq!(
+        (-&third).try_to_f64_rounded(RoundingMode::Floor),
+        Ok((-upper_third, Ordering::Less))
+    );
+    assert_eq!(
+        (-&third).try_to_f64_rounded(RoundingMode::Down),
+        Ok((-lower_third, Ordering::Greater))
+    );
+    assert_eq!(
+        third.try_to_f64_rounded(RoundingMode::Unnecessary),
+        Err(ToFloatError::Inexact)
+    );
+    assert_eq!(
+        to_fraction(-1, 2).try_to_f64_rounded(RoundingMode::Unnecessary),
+        Ok((-0.5, Ordering::Equal))
+    );
+}
+
+#[test]
+fn try_to_f64_rounded_subnormals() {
+    let smallest_subnormal = f64::from_bits(1);
+    // three quarters of the smallest subnormal
+    let value = power_of_two(-1076) * to_fraction(3, 1);
+
+    assert_eq!(
+        value.try_to_f64_rounded(RoundingMode::HalfEven),
+        Ok((smallest_subnormal, Ordering::Greater))
+    );
+    assert_eq!(
+        value.try_to_f64_rounded(RoundingMode::Down),
+        Ok((0., Ordering::Less))
+    );
+    let (result, ordering) =
+        (-&value).try_to_f64_rounded(RoundingMode::Ceiling).unwrap();
+    assert!(result == 0. && result.is_sign_negative());
+    assert_eq!(ordering, Ordering::Greater);
+    assert_eq!(
+        (-&value).try_to_f64_rounded(RoundingMode::Floor),
+        Ok((-smallest_subnormal, Ordering::Less))
+    );
+    assert_eq!(
+        power_of_two(-1074).try_to_f64_rounded(RoundingMode::Unnecessary),
+        Ok((smallest_subnormal, Ordering::Equal))
+    );
+}
+
+#[test]
+fn try_to_f64_rounded_overflow() {
+    let value = power_of_two(1024);
+
+    assert_eq!(
+        value.try_to_f64_rounded(RoundingMode::Down),
+        Ok((f64::MAX, Ordering::Less))
+    );
+    assert_eq!(
+        value.try_to_f64_rounded(RoundingMode::Floor),
+        Ok((f64::MAX, Ordering::Less))
+    );
+    assert_eq!(
+        value.try_to_f64_rounded(RoundingMode::HalfEven),
+        Err(ToFloatError::TooLarge)
+    );
+    assert_eq!(
+        value.try_to_f64_rounded(RoundingMode::Up),
+        Err(ToFloatError::TooLarge)
+    );
+    assert_eq!(
+        (-&value).try_to_f64_rounded(RoundingMode::Ceiling),
+        Ok((-f64::MAX, Ordering::Greater))
+    );
+    assert_eq!(
+        (-&value).try_to_f64_rounded(RoundingMode::Floor),
+        Err(ToFloatError::TooLarge)
+    );
+    assert_eq!(
+        (-&value).try_to_f64_rounded(RoundingMode::Unnecessary),
+        Err(ToFloatError::TooLarge)
+    );
+}
+
+#[test]
+fn inexact_values() {
+    let just_above_one = to_fraction(1, 1) + power_of_two(-53);
+
+    assert_eq!(
+        just_above_one.try_to_f64_rounded(RoundingMode::Unnecessary),
+        Err(ToFloatError::Inexact)
+    );
+    assert_eq!(
+        just_above_one
+            .try_to_binary128_rounded(RoundingMode::Unnecessary)
+            .err(),
+        None
+    );
+    assert_eq!(
+        (-&just_above_one).try_to_f32_rounded(RoundingMode::Unnecessary),
+        Err(ToFloatError::Inexact)
+    );
+    assert_eq!(
+        to_fraction(1, 3)
+            .try_to_binary128_rounded(RoundingMode::Unnecessary)
+            .err(),
+        Some(ToFloatError::Inexact)
+    );
+    // values below the smallest subnormal can not be represented exactly
+    assert_eq!(
+        power_of_two(-1080).try_to_f64_rounded(RoundingMode::Unnecessary),
+        Err(ToFloatError::Inexact)
+    );
+    assert_eq!(
+        ToFloatError::Inexact.to_string(),
+        "Value is not exactly representable as floating point."
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "Rounding is necessary, but rounding mode forbids it."
+)]
+fn to_f64_rounded_unnecessary_inexact() {
+    to_fraction(1, 3).to_f64_rounded(RoundingMode::Unnecessary);
+}
+
+#[test]
+#[should_panic(
+    expected = "Rounding is necessary, but rounding mode forbids it."
+)]
+fn to_f64_rounded_unnecessary_overflow() {
+    (-power_of_two(1024)).to_f64_rounded(RoundingMode::Unnecessary);
+}
+
+macro_rules! check_to_float_rounded_boundaries {
+    ($float:ty, $method:ident) => {{
+        let check = |value: &Fraction31,
+                     mode: RoundingMode,
+                     expected: $float,
+                     ordering: Ordering| {
+            let (result, result_ordering) = value.$method(mode);
+            assert_eq!(
+                (result.to_bits(), result_ordering),
+                (expected.to_bits(), ordering),
+                "{} {:?}",
+                value,
+                mode
+            );
+        };
+        let tiny_exponent =
+            <$float>::MIN_EXP - (<$float>::MANTISSA_DIGITS as i32);
+        let max_ulp_exponent =
+            <$float>::MAX_EXP - (<$float>::MANTISSA_DIGITS as i32);
+        let smallest_subnormal = <$float>::from_bits(1);
+        let largest_subnormal =
+            <$float>::from_bits(<$float>::MIN_POSITIVE.to_bits() - 1);
+        let subnormal_half_ulp = power_of_two(tiny_exponent - 1);
+        let subnormal_epsilon = power_of_two(tiny_exponent - 30);
+        let max_half_ulp = power_of_two(max_ulp_exponent - 1);
+        let max_epsilon = power_of_two(max_ulp_exponent - 30);
+        let exact_min_positive =
+            Fraction31::try_from(<$float>::MIN_POSITIVE).unwrap();
+        let exact_largest_subnormal =
+            Fraction31::try_from(largest_subnormal).unwrap();
+        let exact_max = Fraction31::try_from(<$float>::MAX).unwrap();
+
+        for mode in MODES {
+            check(&to_fraction(0, 1), mode, 0., Ordering::Equal);
+            check(
+                &exact_min_positive,
+                mode,
+                <$float>::MIN_POSITIVE,
+                Ordering::Equal,
+            );
+            check(
+                &-&exact_min_positive,
+                mode,
+                -<$float>::MIN_POSITIVE,
+                Ordering::Equal,
+            );
+            check(
+                &exact_largest_subnormal,
+                mode,
+                largest_subnormal,
+                Ordering::Equal,
+            );
+            check(&exact_max, mode, <$float>::MAX, Ordering::Equal);
+        }
+
+        // half of the smallest subnormal ties to even zero
+        check(
+            &subnormal_half_ulp,
+            RoundingMode::HalfEven,
+            0.,
+            Ordering::Less,
+        );
+        check(
+            &-&subnormal_half_ulp,
+            RoundingMode::HalfEven,
+            -0.,
+            Ordering::Greater,
+        );
+        check(
+            &(&subnormal_half_ulp + &subnormal_epsilon),
+            RoundingMode::HalfEven,
+            smallest_subnormal,
+            Ordering::Greater,
+        );
+        check(
+            &(&subnormal_half_ulp - &subnormal_epsilon),
+            RoundingMode::HalfEven,
+            0.,
+            Ordering::Less,
+        );
+        check(
+            &-(&subnormal_half_ulp - &subnormal_epsilon),
+            RoundingMode::Up,
+            -smallest_subnormal,
+            Ordering::Less,
+        );
+        check(
+            &-(&subnormal_half_ulp - &subnormal_epsilon),
+            RoundingMode::Down,
+            -0.,
+            Ordering::Greater,
+        );
+        check(
+            &-(&subnormal_half_ulp - &subnormal_epsilon),
+            RoundingMode::Ceiling,
+            -0.,
+            Ordering::Greater,
+        );
+        check(
+            &(&subnormal_half_ulp - &subnormal_epsilon),
+            RoundingMode::Floor,
+            0.,
+            Ordering::Less,
+        );
+
+        // largest subnormal has odd significand,
+        // so the tie with the smallest normal goes up
+        check(
+            &(&exact_largest_subnormal + &subnormal_half_ulp),
+            RoundingMode::HalfEven,
+            <$float>::MIN_POSITIVE,
+            Ordering::Greater,
+        );
+        check(
+            &(&exact_largest_subnormal + &subnormal_half_ulp),
+            RoundingMode::Down,
+            largest_subnormal,
+            Ordering::Less,
+        );
+        check(
+            &(&exact_min_positive - &subnormal_epsilon),
+            RoundingMode::Floor,
+            largest_subnormal,
+            Ordering::Less,
+        );
+        check(
+            &(&exact_min_positive - &subnormal_epsilon),
+            RoundingMode::HalfEven,
+            <$float>::MIN_POSITIVE,
+            Ordering::Greater,
+        );
+
+        // maximum has odd significand,
+        // so the tie above it goes to infinity and the tie below it goes down
+        check(
+            &(&exact_max + &max_half_ulp),
+            RoundingMode::HalfEven,
+            <$float>::INFINITY,
+            Ordering::Greater,
+        );
+        check(
+            &(&exact_max + &max_half_ulp - &max_epsilon),
+            RoundingMode::HalfEven,
+            <$float>::MAX,
+            Ordering::Less,
+        );
+        check(
+            &-(&exact_max + &max_half_ulp),
+            RoundingMode::HalfEven,
+            <$float>::NEG_INFINITY,
+            Ordering::Less,
+        );
+        check(
+            &(&exact_max - &max_half_ulp),
+            RoundingMode::HalfEven,
+            <$float>::from_bits(<$float>::MAX.to_bits() - 1),
+            Ordering::Less,
+        );
+        check(
+            &(&exact_max - &max_half_ulp),
+            RoundingMode::Up,
+            <$float>::MAX,
+            Ordering::Greater,
+        );
+
+        // overflow
+        let huge = power_of_two(<$float>::MAX_EXP + 1);
+        check(
+            &huge,
+            RoundingMode::Up,
+            <$float>::INFINITY,
+            Ordering::Greater,
+        );
+        check(&huge, RoundingMode::Down, <$float>::MAX, Ordering::Less);
+        check(&huge, RoundingMode::Floor, <$float>::MAX, Ordering::Less);
+        check(
+            &-&huge,
+            RoundingMode::Floor,
+            <$float>::NEG_INFINITY,
+            Ordering::Less,
+        );
+        check(
+            &-&huge,
+            RoundingMode::Ceiling,
+            -<$float>::MAX,
+            Ordering::Greater,
+        );
+        check(
+            &(&exact_max + &max_epsilon),
+            RoundingMode::Ceiling,
+            <$float>::INFINITY,
+            Ordering::Greater,
+        );
+    }};
+}
+
+#[test]
+fn to_f32_rounded_boundaries() {
+    check_to_float_rounded_boundaries!(f32, to_f32_rounded);
+}
+
+#[test]
+fn to_f64_rounded_boundaries() {
+    check_to_float_rounded_boundaries!(f64, to_f64_rounded);
+}
+
+/// Quotients at or above three quarters of the smallest subnormal
+/// used to be flushed to zero by the early underflow check
+/// of plain float conversion.
+#[test]
+fn float_from_fraction_near_underflow() {
+    let three_quarters_ulp = |tiny_exponent: i32| {
+        power_of_two(tiny_exponent - 2) * to_fraction(3, 1)
+    };
+
+    assert_eq!(
+        f32::try_from(three_quarters_ulp(-149)),
+        Ok(f32::from_bits(1))
+    );
+    assert_eq!(
+        f32::try_from(-three_quarters_ulp(-149)),
+        Ok(-f32::from_bits(1))
+    );
+    assert_eq!(
+        f64::try_from(three_quarters_ulp(-1074)),
+        Ok(f64::from_bits(1))
+    );
+    assert_eq!(
+        f64::try_from(&-three_quarters_ulp(-1074)),
+        Ok(-f64::from_bits(1))
+    );
+    assert_eq!(f64::try_from(power_of_two(-1076)).map(f64::to_bits), Ok(0));
+}
+
+/// Finite floats are converted to fractions and back exactly
+/// in every rounding mode, except for negative zero
+/// which has no distinct fraction counterpart.
+#[cfg(feature = "half")]
+macro_rules! check_round_trips {
+    ($float:ty, $try_method:ident) => {
+        for bits in 0..=u16::MAX {
+            let float = <$float>::from_bits(bits);
+            let value = Fraction31::try_from(float);
+            if float.is_finite() {
+                let value = value.unwrap();
+                let expected_bits = if bits == 1 << 15 { 0 } else { bits };
+                for mode in MODES {
+                    assert_eq!(
+                        value.$try_method(mode).map(|(float, ordering)| (
+                            float.to_bits(),
+                            ordering
+                        )),
+                        Ok((expected_bits, Ordering::Equal)),
+                        "{:#06x} {:?}",
+                        bits,
+                        mode
+                    );
+                }
+                assert_eq!(
+                    <$float>::try_from(value).map(<$float>::to_bits),
+                    Ok(expected_bits)
+                );
+            } else {
+                assert!(value.is_err());
+            }
+        }
+    };
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn bf16_round_trips() {
+    check_round_trips!(bf16, try_to_bf16_rounded);
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn f16_round_trips() {
+    check_round_trips!(f16, try_to_f16_rounded);
+}
+
+#[test]
+fn binary128_boundaries() {
+    const SIGN_BIT: u128 = 1 << 127;
+    const MIN_POSITIVE_BITS: u128 = 1 << 112;
+    let max_bits = Binary128::MAX.to_bits();
+    for bits in [
+        0,
+        1,
+        2,
+        MIN_POSITIVE_BITS - 1,
+        MIN_POSITIVE_BITS,
+        MIN_POSITIVE_BITS + 1,
+        0x3fff << 112,
+        max_bits - 1,
+        max_bits,
+    ] {
+        for bits in [bits, bits | SIGN_BIT] {
+            let value =
+                Fraction31::try_from(Binary128::from_bits(bits)).unwrap();
+            let expected_bits = if bits == SIGN_BIT { 0 } else { bits };
+
+            for mode in MODES {
+                assert_eq!(
+                    value
+                        .try_to_binary128_rounded(mode)
+                        .map(|(float, ordering)| (float.to_bits(), ordering)),
+                    Ok((expected_bits, Ordering::Equal)),
+                    "{:#034x} {:?}",
+                    bits,
+                    mode
+                );
+            }
+            assert_eq!(
+                Binary128::try_from(value).map(Binary128::to_bits),
+                Ok(expected_bits)
+            );
+        }
+    }
+    assert!(Fraction31::try_from(Binary128::INFINITY).is_err());
+    assert!(Fraction31::try_from(Binary128::NEG_INFINITY).is_err());
+    assert!(Fraction31::try_from(Binary128::NAN).is_err());
+
+    let smallest_subnormal = power_of_two(-16494);
+    let (result, ordering) = (-&smallest_subnormal / to_fraction(2, 1))
+        .to_binary128_rounded(RoundingMode::HalfEven);
+    assert_eq!((result.to_bits(), ordering), (SIGN_BIT, Ordering::Greater));
+    let (result, ordering) = (&smallest_subnormal * to_fraction(3, 4))
+        .to_binary128_rounded(RoundingMode::HalfEven);
+    assert_eq!((result.to_bits(), ordering), (1, Ordering::Greater));
+    let (result, ordering) = (power_of_two(Binary128::MAX_EXP)
+        - power_of_two(Binary128::MAX_EXP - 114))
+    .to_binary128_rounded(RoundingMode::HalfEven);
+    assert_eq!(
+        (result.to_bits(), ordering),
+        (Binary128::INFINITY.to_bits(), Ordering::Greater)
+    );
+    let (result, ordering) = (-power_of_two(Binary128::MAX_EXP))
+        .to_binary128_rounded(RoundingMode::Down);
+    assert_eq!(
+        (result.to_bits(), ordering),
+        (max_bits | SIGN_BIT, Ordering::Greater)
+    );
+}
@@ -0,0 +1,87 @@
+use std::time::{Duration, Instant};
+
+use rithm::big_int::BigInt;
+use rithm::fraction::{Fraction, FromStringConstructionError};
+use traiter::numbers::Pow;
+
+type BigInt31 = BigInt<u32, 31>;
+type Fraction31 = Fraction<BigInt31>;
+
+fn to_fraction(numerator: i128, denominator: i128) -> Fraction31 {
+    Fraction31::new(BigInt31::from(numerator), BigInt31::from(denominator))
+        .unwrap()
+}
+
+#[test]
+fn exponents() {
+    assert_eq!("1e0".parse::<Fraction31>(), Ok(to_fraction(1, 1)));
+    assert_eq!("-25e-2".parse::<Fraction31>(), Ok(to_fraction(-1, 4)));
+    assert_eq!(
+        "1.5E+3_0".parse::<Fraction31>(),
+        Ok(to_fraction(15 * 10i128.pow(29), 1))
+    );
+    assert_eq!(
+        "1e-1000"
+            .parse::<Fraction31>()
+            .map(|value| value.denominator().clone()),
+        Ok(BigInt31::from(10u8).pow(BigInt31::from(1000u32)))
+    );
+    assert_eq!("0e1000".parse::<Fraction31>(), Ok(to_fraction(0, 1)));
+}
+
+#[test]
+fn exponents_out_of_bounds() {
+    for string in [
+        "1e1048577",
+        "1e-1048577",
+        "1e1000000000",
+        "1e4294967296",
+        "1e-4294967296",
+        "1.5e18446744073709551615",
+        "1e18446744073709551616",
+        "-0.(3)e-99999999999999999999999999",
+    ] {
+        assert_eq!(
+            string.parse::<Fraction31>(),
+            Err(FromStringConstructionError::ExponentOutOfBounds),
+            "{}",
+            string
+        );
+    }
+}
+
+#[test]
+fn exponents_are_checked_before_computing_powers() {
+    let start = Instant::now();
+
+    assert_eq!(
+        "1e1000000000".parse::<Fraction31>(),
+        Err(FromStringConstructionError::ExponentOutOfBounds)
+    );
+    assert!(start.elapsed() < Duration::from_secs(1));
+}
+
+#[test]
+fn errors_are_not_reported_as_out_of_memory() {
+    assert_eq!(
+        "1/0".parse::<Fraction31>(),
+        Err(FromStringConstructionError::ZeroDenominator)
+    );
+    assert_eq!("1.".parse::<Fraction31>(), Ok(to_fraction(1, 1)));
+    assert_eq!(
+        "1e".parse::<Fraction31>(),
+        Err(FromStringConstructionError::UnexpectedEnd)
+    );
+    assert_eq!(
+        "1_".parse::<Fraction31>(),
+        Err(FromStringConstructionError::UnexpectedEnd)
+    );
+    assert_eq!(
+        "1x".parse::<Fraction31>(),
+        Err(FromStringConstructionError::InvalidSymbol('x'))
+    );
+    assert_eq!(
+        "/2".parse::<Fraction31>(),
+        Err(FromStringConstructionError::InvalidSymbol('/'))
+    );
+}
@@ -0,0 +1,102 @@
+use rithm::big_int::BigInt;
+use rithm::fraction::Fraction;
+use traiter::numbers::Pow;
+
+type BigInt31 = BigInt<u32, 31>;
+type Fraction31 = Fraction<BigInt31>;
+
+fn to_fraction(numerator: i128, denominator: i128) -> Fraction31 {
+    Fraction31::new(BigInt31::from(numerator), BigInt31::from(denominator))
+        .unwrap()
+}
+
+fn to_period(string: &str) -> usize {
+    string
+        .split_once('(')
+        .map_or(0, |(_, repetend)| repetend.len() - 1)
+}
+
+fn to_brute_force_period(denominator: u64, base: u64) -> usize {
+    let mut repeating_denominator = denominator;
+    loop {
+        let gcd = to_gcd(repeating_denominator, base);
+        if gcd == 1 {
+            break;
+        }
+        repeating_denominator /= gcd;
+    }
+    if repeating_denominator == 1 {
+        return 0;
+    }
+    let (mut power, mut result) = (base % repeating_denominator, 1);
+    while power != 1 {
+        power = power * base % repeating_denominator;
+        result += 1;
+    }
+    result
+}
+
+fn to_gcd(mut first: u64, mut second: u64) -> u64 {
+    while second != 0 {
+        (first, second) = (second, first % second);
+    }
+    first
+}
+
+#[test]
+fn known_strings() {
+    assert_eq!(
+        to_fraction(1, 7).to_positional_string(10).unwrap(),
+        "0.(142857)"
+    );
+    assert_eq!(
+        to_fraction(-5, 12).to_positional_string(10).unwrap(),
+        "-0.41(6)"
+    );
+    assert_eq!(
+        to_fraction(1, 91).to_positional_string(10).unwrap(),
+        "0.(010989)"
+    );
+    assert_eq!(to_fraction(1, 3).to_positional_string(2).unwrap(), "0.(01)");
+    assert_eq!(to_fraction(7, 4).to_positional_string(10).unwrap(), "1.75");
+    assert_eq!(to_fraction(3, 1).to_positional_string(10).unwrap(), "3");
+}
+
+#[test]
+fn periods_are_minimal() {
+    for base in [2u32, 3, 7, 10, 16, 36] {
+        for denominator in 1u32..1500 {
+            let string = to_fraction(1, denominator.into())
+                .to_positional_string(base)
+                .unwrap();
+
+            assert_eq!(
+                to_period(&string),
+                to_brute_force_period(denominator.into(), base.into()),
+                "1/{} in base {}",
+                denominator,
+                base
+            );
+        }
+    }
+}
+
+#[test]
+fn large_periods() {
+    // ord(10, 3^k) = 3^(k - 2) for k >= 2
+    let denominator = BigInt31::from(3u8).pow(BigInt31::from(9u8));
+    let value = Fraction31::new(BigInt31::from(1u8), denominator).unwrap();
+
+    assert_eq!(to_period(&value.to_positional_string(10).unwrap()), 2187);
+    // periods beyond `u16::MAX` digits exceed formatting width limit
+    for (denominator, base) in
+        [(65_539u32, 10u32), (65_537 * 7, 2), (99_991 * 3, 36)]
+    {
+        let value = to_fraction(1, denominator.into());
+
+        assert_eq!(
+            to_period(&value.to_positional_string(base).unwrap()),
+            to_brute_force_period(denominator.into(), base.into())
+        );
+    }
+}
@@ -1,8 +1,16 @@
+import string
+
 from hypothesis import strategies
 
-from rithm.enums import TieBreaking
+from rithm.enums import (RoundingMode,
+                         TieBreaking)
 from rithm.fraction import Fraction
 from rithm.integer import Int
+from tests.strategies import (
+    aligned_zero_padded_floating_point_format_specifiers,
+    non_zero_padded_floating_point_format_specifiers,
+    zero_padded_floating_point_format_specifiers
+)
 from tests.utils import (to_fraction_with_builtin,
                          to_int_with_builtin)
 
@@ -10,7 +18,21 @@ tie_breakings = strategies.sampled_from([TieBreaking.AWAY_FROM_ZERO,
                                          TieBreaking.TO_EVEN,
                                          TieBreaking.TO_ODD,
                                          TieBreaking.TOWARD_ZERO])
+rounding_modes = strategies.sampled_from([RoundingMode.UP,
+                                          RoundingMode.DOWN,
+                                          RoundingMode.CEILING,
+                                          RoundingMode.FLOOR,
+                                          RoundingMode.HALF_UP,
+                                          RoundingMode.HALF_DOWN,
+                                          RoundingMode.HALF_EVEN,
+                                          RoundingMode.HALF_ODD])
 floats = strategies.floats()
+finite_floats = strategies.floats(allow_nan=False,
+                                  allow_infinity=False).map(
+        lambda value: value or 0.0
+)
+infinite_floats = strategies.sampled_from([float('-inf'), float('inf')])
+nan_floats = strategies.just(float('nan'))
 integers = strategies.integers()
 zero_integers = strategies.builds(int)
 non_zero_integers = integers.filter(bool)
@@ -29,19 +51,121 @@ non_zero_fractions = fractions.filter(bool)
 fractions_or_ints_or_builtin_ints = fractions | ints | integers
 invalid_fractions_components = strategies.floats()
 invalid_fractions_single_arguments = strategies.decimals()
+fraction_literals_signs = strategies.sampled_from(['', '+', '-'])
+fraction_literals_paddings = strategies.sampled_from(['', ' ', '\t\n'])
+fraction_literals_digits = strategies.text(alphabet=string.digits,
+                                           min_size=1,
+                                           max_size=20)
+maybe_fraction_literals_digits = (strategies.just('')
+                                  | fraction_literals_digits)
+separated_fraction_literals_digits = strategies.lists(
+        fraction_literals_digits,
+        min_size=1,
+        max_size=3
+).map('_'.join)
+fraction_literals_exponents = strategies.builds(
+        '{}{}{}'.format, strategies.sampled_from(['e', 'E']),
+        fraction_literals_signs,
+        strategies.text(alphabet=string.digits,
+                        min_size=1,
+                        max_size=3)
+)
+separated_fraction_literals = strategies.builds(
+        '{}{}{}'.format, fraction_literals_paddings,
+        strategies.builds('{}{}/{}'.format, fraction_literals_signs,
+                          separated_fraction_literals_digits,
+                          separated_fraction_literals_digits)
+        | strategies.builds(
+                '{}{}{}{}'.format, fraction_literals_signs,
+                strategies.just('') | separated_fraction_literals_digits,
+                strategies.just('')
+                | (strategies.just('')
+                   | separated_fraction_literals_digits).map('.{}'.format),
+                strategies.just('') | fraction_literals_exponents
+        ).filter(lambda literal: any(character.isdigit()
+                                     for character
+                                     in literal.lower().partition('e')[0])),
+        fraction_literals_paddings
+)
+fraction_literals = separated_fraction_literals.map(
+        lambda literal: literal.replace('_', '')
+)
+zero_denominators_fraction_literals = strategies.builds(
+        '{}{}/{}'.format, fraction_literals_signs, fraction_literals_digits,
+        strategies.text(alphabet='0', min_size=1, max_size=5)
+)
+invalid_fraction_literals = strategies.sampled_from(
+        ['', '-', '.', 'e1', '/3', '1/', '1/-2', '1/2.', '1.5/2', '1 /2',
+         '1__0', '1_', '_1', '1._5', '0.1(', '0.1()', '0.1(6', '(3)',
+         '0.1(6)7', '1.2e3(4)', '1e', '1e+', '1e1.5', 'abc', 'inf', 'nan',
+         '0x10', '1/2/3', '\u0661']
+)
 ints_with_builtins = strategies.builds(to_int_with_builtin, integers)
 non_zero_ints_with_builtins = ints_with_builtins.filter(all)
+positive_ints_with_builtins = strategies.builds(
+        to_int_with_builtin, strategies.integers(min_value=1)
+)
+non_positive_ints_or_builtins = (
+        strategies.integers(max_value=0).map(Int)
+        | strategies.integers(max_value=0)
+)
 fractions_with_builtins = (strategies.builds(to_fraction_with_builtin,
                                              ints_with_builtins)
                            | strategies.builds(to_fraction_with_builtin,
                                                ints_with_builtins,
                                                non_zero_ints_with_builtins))
 rationals_with_builtins = fractions_with_builtins | ints_with_builtins
+float_significands_with_builtins = strategies.builds(
+        to_int_with_builtin,
+        strategies.builds(
+                int.__add__,
+                strategies.integers(-(1 << 54), 1 << 54).map(
+                        lambda value: value << 64
+                ),
+                strategies.sampled_from([-1, 0, 1])
+                | strategies.integers(-(1 << 64), 1 << 64)
+        )
+)
+float_boundaries_scales_with_builtins = strategies.builds(
+        to_int_with_builtin,
+        strategies.integers(1120, 1200).map(lambda value: 1 << value)
+)
+float_boundaries_fractions_with_builtins = (
+        strategies.builds(to_fraction_with_builtin,
+                          float_significands_with_builtins,
+                          float_boundaries_scales_with_builtins)
+        | strategies.builds(
+                to_fraction_with_builtin,
+                strategies.builds(
+                        lambda significand_with_builtin, scale: (
+                            significand_with_builtin[0] << scale,
+                            significand_with_builtin[1] << scale
+                        ),
+                        float_significands_with_builtins,
+                        strategies.integers(885, 910)
+                )
+        )
+)
 zero_fractions_or_ints_or_builtin_ints = (zero_fractions | zero_ints
                                           | zero_integers)
 non_zero_fractions_or_ints_or_builtin_ints = (
         non_zero_fractions | non_zero_ints | non_zero_integers
 )
 small_integers = strategies.integers(-100, 100)
+non_negative_small_integers = strategies.integers(0, 100)
+negative_integers = strategies.integers(max_value=-1)
 small_ints_with_builtins = strategies.builds(to_int_with_builtin,
                                              small_integers)
+decimal_fractions = finite_floats.map(Fraction)
+non_decimal_fractions = decimal_fractions.map(Fraction(1, 3).__add__)
+positional_fractions = strategies.builds(Fraction, ints,
+                                         strategies.integers(1, 1_000))
+representable_bases = strategies.integers(2, 36)
+non_representable_bases = (strategies.integers(max_value=1)
+                           | strategies.integers(min_value=37))
+format_specifiers = (non_zero_padded_floating_point_format_specifiers
+                     | zero_padded_floating_point_format_specifiers)
+invalid_format_specifiers = (
+        aligned_zero_padded_floating_point_format_specifiers
+        | strategies.sampled_from(['d', 'x', '.f', '007f', '.01f', ',_f'])
+)
@@ -1,7 +1,8 @@
 import pytest
 from hypothesis import given
 
-from tests.utils import FractionWithBuiltin
+from tests.utils import (FractionWithBuiltin,
+                         is_equivalent_to_builtin_float)
 from . import strategies
 
 
@@ -18,3 +19,16 @@ def test_connection_with_builtin(
             float(builtin_fraction)
     else:
         assert result == float(builtin_fraction)
+
+
+@given(strategies.float_boundaries_fractions_with_builtins)
+def test_boundaries(fraction_with_builtin: FractionWithBuiltin) -> None:
+    fraction, builtin_fraction = fraction_with_builtin
+
+    try:
+        result = float(fraction)
+    except OverflowError as exception:
+        with pytest.raises(type(exception)):
+            float(builtin_fraction)
+    else:
+        assert is_equivalent_to_builtin_float(result, float(builtin_fraction))
@@ -0,0 +1,27 @@
+import pytest
+from hypothesis import given
+
+from rithm.fraction import Fraction
+from . import strategies
+
+
+@given(strategies.finite_floats,
+       strategies.format_specifiers.filter(
+               lambda specifier: (not specifier.startswith('0=')
+                                  and not specifier.endswith('%'))
+       ))
+def test_connection_with_float(float_: float, specifier: str) -> None:
+    fraction = Fraction(float_)
+
+    assert format(fraction, specifier) == format(float_, specifier)
+
+
+@given(strategies.fractions)
+def test_empty_specifier(fraction: Fraction) -> None:
+    assert format(fraction, '') == str(fraction)
+
+
+@given(strategies.fractions, strategies.invalid_format_specifiers)
+def test_invalid_specifier(fraction: Fraction, specifier: str) -> None:
+    with pytest.raises(ValueError):
+        format(fraction, specifier)
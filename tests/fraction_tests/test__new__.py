@@ -87,3 +87,59 @@ def test_invalid_denominator(numerator: IntOrBuiltin,
                              denominator: Any) -> None:
     with pytest.raises(TypeError):
         Fraction(numerator, denominator)
+
+
+@given(strategies.fraction_literals)
+def test_string_connection_with_builtin(literal: str) -> None:
+    try:
+        result = Fraction(literal)
+    except ZeroDivisionError as exception:
+        with pytest.raises(type(exception)):
+            fractions.Fraction(literal)
+    else:
+        assert is_equivalent_to_builtin_fraction(result,
+                                                 fractions.Fraction(literal))
+
+
+@given(strategies.separated_fraction_literals)
+def test_separated_string(literal: str) -> None:
+    try:
+        result = Fraction(literal)
+    except ZeroDivisionError as exception:
+        with pytest.raises(type(exception)):
+            Fraction(literal.replace('_', ''))
+    else:
+        assert result == Fraction(literal.replace('_', ''))
+
+
+@given(strategies.fraction_literals_signs,
+       strategies.maybe_fraction_literals_digits,
+       strategies.maybe_fraction_literals_digits,
+       strategies.fraction_literals_digits)
+def test_repeating_decimal_string(sign: str,
+                                  integral_digits: str,
+                                  fractional_digits: str,
+                                  repeating_digits: str) -> None:
+    result = Fraction(f'{sign}{integral_digits}.{fractional_digits}'
+                      f'({repeating_digits})')
+
+    modulus = (fractions.Fraction(f'{integral_digits or 0}'
+                                  f'.{fractional_digits}')
+               + fractions.Fraction(int(repeating_digits),
+                                    10 ** len(fractional_digits)
+                                    * (10 ** len(repeating_digits) - 1)))
+    assert is_equivalent_to_builtin_fraction(
+            result, -modulus if sign == '-' else modulus
+    )
+
+
+@given(strategies.zero_denominators_fraction_literals)
+def test_zero_denominator_string(literal: str) -> None:
+    with pytest.raises(ZeroDivisionError):
+        Fraction(literal)
+
+
+@given(strategies.invalid_fraction_literals)
+def test_invalid_string(literal: str) -> None:
+    with pytest.raises(ValueError):
+        Fraction(literal)
@@ -0,0 +1,52 @@
+import pytest
+from hypothesis import given
+
+from rithm.fraction import Fraction
+from tests.utils import (FractionWithBuiltin,
+                         IntOrBuiltin,
+                         IntWithBuiltin,
+                         is_equivalent_to_builtin_fraction)
+from . import strategies
+
+
+@given(strategies.fractions, strategies.positive_ints_with_builtins)
+def test_basic(fraction: Fraction,
+               max_denominator_with_builtin: IntWithBuiltin) -> None:
+    max_denominator, _ = max_denominator_with_builtin
+
+    result = fraction.limit_denominator(max_denominator)
+
+    assert isinstance(result, Fraction)
+    assert result.denominator <= max_denominator
+
+
+@given(strategies.fractions_with_builtins,
+       strategies.positive_ints_with_builtins)
+def test_connection_with_builtin(
+        fraction_with_builtin: FractionWithBuiltin,
+        max_denominator_with_builtin: IntWithBuiltin
+) -> None:
+    fraction, builtin_fraction = fraction_with_builtin
+    max_denominator, builtin_max_denominator = max_denominator_with_builtin
+
+    assert is_equivalent_to_builtin_fraction(
+            fraction.limit_denominator(max_denominator),
+            builtin_fraction.limit_denominator(builtin_max_denominator)
+    )
+
+
+@given(strategies.fractions_with_builtins)
+def test_default(fraction_with_builtin: FractionWithBuiltin) -> None:
+    fraction, builtin_fraction = fraction_with_builtin
+
+    assert is_equivalent_to_builtin_fraction(
+            fraction.limit_denominator(),
+            builtin_fraction.limit_denominator()
+    )
+
+
+@given(strategies.fractions, strategies.non_positive_ints_or_builtins)
+def test_non_positive_max_denominator(fraction: Fraction,
+                                      max_denominator: IntOrBuiltin) -> None:
+    with pytest.raises(ValueError):
+        fraction.limit_denominator(max_denominator)
@@ -1,6 +1,11 @@
+import decimal
+import math
+
+import pytest
 from hypothesis import given
 
-from rithm.enums import TieBreaking
+from rithm.enums import (RoundingMode,
+                         TieBreaking)
 from rithm.fraction import Fraction
 from rithm.integer import Int
 from tests.utils import (FractionWithBuiltin,
@@ -33,3 +38,81 @@ def test_connection_with__round__(
 
     assert is_equivalent_to_builtin_int(fraction.round(TieBreaking.TO_EVEN),
                                         round(builtin_fraction))
+
+
+@given(strategies.fractions, strategies.rounding_modes)
+def test_rounding_mode_value(fraction: Fraction,
+                             rounding_mode: RoundingMode) -> None:
+    result = fraction.round(rounding_mode)
+
+    assert isinstance(result, Int)
+    assert abs(result - fraction) < 1
+    if rounding_mode is RoundingMode.HALF_ODD:
+        # there is no matching decimal rounding,
+        # so it is checked against half-even with ties flipped
+        half_even_result = _round_as_decimal(fraction,
+                                             decimal.ROUND_HALF_EVEN)
+        assert result == (half_even_result
+                          if 2 * abs(fraction - half_even_result) != 1
+                          else 2 * math.floor(fraction) + 1 - half_even_result)
+    else:
+        assert result == _round_as_decimal(
+                fraction, _TO_DECIMAL_ROUNDING[rounding_mode]
+        )
+
+
+@given(strategies.fractions)
+def test_directed_rounding_modes(fraction: Fraction) -> None:
+    assert fraction.round(RoundingMode.FLOOR) == math.floor(fraction)
+    assert fraction.round(RoundingMode.CEILING) == math.ceil(fraction)
+    assert fraction.round(RoundingMode.DOWN) == math.trunc(fraction)
+
+
+@given(strategies.fractions, strategies.tie_breakings)
+def test_connection_with_tie_breaking(fraction: Fraction,
+                                      tie_breaking: TieBreaking) -> None:
+    rounding_mode = {
+        TieBreaking.AWAY_FROM_ZERO: RoundingMode.HALF_UP,
+        TieBreaking.TO_EVEN: RoundingMode.HALF_EVEN,
+        TieBreaking.TO_ODD: RoundingMode.HALF_ODD,
+        TieBreaking.TOWARD_ZERO: RoundingMode.HALF_DOWN,
+    }[tie_breaking]
+
+    assert fraction.round(rounding_mode) == fraction.round(tie_breaking)
+
+
+@given(strategies.fractions)
+def test_unnecessary_rounding_mode(fraction: Fraction) -> None:
+    if fraction.denominator == 1:
+        assert fraction.round(RoundingMode.UNNECESSARY) == fraction.numerator
+    else:
+        with pytest.raises(ValueError):
+            fraction.round(RoundingMode.UNNECESSARY)
+
+
+_TO_DECIMAL_ROUNDING = {
+    RoundingMode.CEILING: decimal.ROUND_CEILING,
+    RoundingMode.DOWN: decimal.ROUND_DOWN,
+    RoundingMode.FLOOR: decimal.ROUND_FLOOR,
+    RoundingMode.HALF_DOWN: decimal.ROUND_HALF_DOWN,
+    RoundingMode.HALF_EVEN: decimal.ROUND_HALF_EVEN,
+    RoundingMode.HALF_UP: decimal.ROUND_HALF_UP,
+    RoundingMode.UP: decimal.ROUND_UP,
+}
+
+
+def _round_as_decimal(fraction: Fraction, rounding: str) -> int:
+    numerator, denominator = int(fraction.numerator), int(fraction.denominator)
+    # truncation keeps enough fractional digits
+    # to tell exact values, ties and their neighbours apart
+    context = decimal.Context(
+            prec=(len(str(abs(numerator) // denominator))
+                  + len(str(denominator)) + 2),
+            rounding=decimal.ROUND_DOWN,
+            Emax=decimal.MAX_EMAX,
+            Emin=decimal.MIN_EMIN
+    )
+    return int(context.divide(decimal.Decimal(numerator),
+                              decimal.Decimal(denominator))
+               .quantize(decimal.Decimal(1), rounding=rounding,
+                         context=context))
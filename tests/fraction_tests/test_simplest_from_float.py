@@ -0,0 +1,45 @@
+import fractions
+
+import pytest
+from hypothesis import given
+
+from rithm.fraction import Fraction
+from . import strategies
+
+
+@given(strategies.finite_floats)
+def test_basic(float_: float) -> None:
+    result = Fraction.simplest_from_float(float_)
+
+    assert isinstance(result, Fraction)
+
+
+@given(strategies.finite_floats)
+def test_round_trip(float_: float) -> None:
+    result = Fraction.simplest_from_float(float_)
+
+    assert float(result) == float_
+
+
+@given(strategies.finite_floats)
+def test_simplicity(float_: float) -> None:
+    result = Fraction.simplest_from_float(float_)
+
+    builtin_fraction = fractions.Fraction(float_)
+    assert result.denominator <= builtin_fraction.denominator
+    assert (result.denominator == 1
+            or float(builtin_fraction.limit_denominator(
+                    int(result.denominator) - 1
+            )) != float_)
+
+
+@given(strategies.infinite_floats)
+def test_infinite(float_: float) -> None:
+    with pytest.raises(OverflowError):
+        Fraction.simplest_from_float(float_)
+
+
+@given(strategies.nan_floats)
+def test_nan(float_: float) -> None:
+    with pytest.raises(ValueError):
+        Fraction.simplest_from_float(float_)
@@ -0,0 +1,35 @@
+import decimal
+import fractions
+
+import pytest
+from hypothesis import given
+
+from rithm.fraction import Fraction
+from . import strategies
+
+
+@given(strategies.decimal_fractions)
+def test_basic(fraction: Fraction) -> None:
+    result = fraction.to_decimal_string()
+
+    assert isinstance(result, str)
+
+
+@given(strategies.decimal_fractions)
+def test_round_trip(fraction: Fraction) -> None:
+    result = fraction.to_decimal_string()
+
+    assert Fraction(fractions.Fraction(result)) == fraction
+
+
+@given(strategies.finite_floats)
+def test_connection_with_decimal(float_: float) -> None:
+    result = Fraction(float_).to_decimal_string()
+
+    assert result == format(decimal.Decimal(float_), 'f')
+
+
+@given(strategies.non_decimal_fractions)
+def test_non_decimal(fraction: Fraction) -> None:
+    with pytest.raises(ValueError):
+        fraction.to_decimal_string()
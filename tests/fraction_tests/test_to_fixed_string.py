@@ -0,0 +1,26 @@
+import pytest
+from hypothesis import given
+
+from rithm.fraction import Fraction
+from . import strategies
+
+
+@given(strategies.fractions, strategies.non_negative_small_integers)
+def test_basic(fraction: Fraction, ndigits: int) -> None:
+    result = fraction.to_fixed_string(ndigits)
+
+    assert isinstance(result, str)
+    assert len(result.partition('.')[2]) == ndigits
+
+
+@given(strategies.fractions, strategies.non_negative_small_integers)
+def test_connection_with__round__(fraction: Fraction, ndigits: int) -> None:
+    result = fraction.to_fixed_string(ndigits)
+
+    assert Fraction(result) == round(fraction, ndigits)
+
+
+@given(strategies.fractions, strategies.negative_integers)
+def test_negative_ndigits(fraction: Fraction, ndigits: int) -> None:
+    with pytest.raises(ValueError):
+        fraction.to_fixed_string(ndigits)
@@ -0,0 +1,77 @@
+import math
+import typing as t
+
+import pytest
+from hypothesis import given
+
+from rithm.fraction import Fraction
+from . import strategies
+
+
+@given(strategies.positional_fractions, strategies.representable_bases)
+def test_basic(fraction: Fraction, base: int) -> None:
+    result = fraction.to_positional_string(base)
+
+    assert isinstance(result, str)
+
+
+@given(strategies.positional_fractions)
+def test_round_trip(fraction: Fraction) -> None:
+    result = fraction.to_positional_string()
+
+    assert Fraction(result) == fraction
+
+
+@given(strategies.decimal_fractions)
+def test_connection_with_to_decimal_string(fraction: Fraction) -> None:
+    result = fraction.to_positional_string()
+
+    assert result == fraction.to_decimal_string()
+
+
+@given(strategies.positional_fractions, strategies.representable_bases)
+def test_period(fraction: Fraction, base: int) -> None:
+    result = fraction.to_positional_string(base)
+
+    _, _, repetend = result.partition('(')
+    period = len(repetend) - 1
+    denominator = int(fraction.denominator)
+    repeating_denominator = (denominator
+                             // _to_non_coprime_part(denominator, base))
+    assert (repetend == '') is (repeating_denominator == 1)
+    assert (repetend == ''
+            or (pow(base, period, repeating_denominator) == 1
+                and all(pow(base, period // factor, repeating_denominator)
+                        != 1
+                        for factor in _to_prime_factors(period))))
+
+
+@given(strategies.positional_fractions, strategies.non_representable_bases)
+def test_invalid_base(fraction: Fraction, base: int) -> None:
+    with pytest.raises(ValueError):
+        fraction.to_positional_string(base)
+
+
+def _to_non_coprime_part(value: int, base: int) -> int:
+    result = 1
+    while True:
+        gcd = math.gcd(value, base)
+        if gcd == 1:
+            return result
+        value //= gcd
+        result *= gcd
+
+
+def _to_prime_factors(value: int) -> t.List[int]:
+    result = []
+    divisor = 2
+    while divisor * divisor <= value:
+        if value % divisor:
+            divisor += 1
+        else:
+            result.append(divisor)
+            while not value % divisor:
+                value //= divisor
+    if value != 1:
+        result.append(value)
+    return result
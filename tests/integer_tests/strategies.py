@@ -14,6 +14,10 @@ decimal_int_strings_with_leading_zeros = (
     _strategies.decimal_int_strings_with_leading_zeros
 )
 int_strings_with_bases = _strategies.int_strings_with_bases
+floating_point_format_specifiers = (
+    _strategies.floating_point_format_specifiers
+)
+integral_format_specifiers = _strategies.integral_format_specifiers
 invalid_int_strings = _st.text(
         _st.sampled_from(_string.whitespace + _string.punctuation)
 )
@@ -27,6 +31,16 @@ zero_integers = _st.builds(int)
 ints = integers.map(_Int)
 ints_or_builtins = ints | integers
 ints_with_builtins = _st.builds(_to_int_with_builtin, integers)
+hash_modulus = (1 << 61) - 1
+hash_boundary_integers = _st.builds(
+        int.__add__,
+        _st.sampled_from([1 << 31, 1 << 61, 1 << 62, 1 << 63, 1 << 64,
+                          1 << 126, 1 << 127, hash_modulus * hash_modulus])
+        | _st.integers(1, 1 << 4).map(hash_modulus.__mul__),
+        _st.integers(-2, 2)
+).flatmap(lambda value: _st.sampled_from([value, -value]))
+hash_boundary_ints_with_builtins = _st.builds(_to_int_with_builtin,
+                                              hash_boundary_integers)
 non_zero_ints = _st.builds(_Int, non_zero_integers)
 non_zero_ints_or_builtins = non_zero_ints | non_zero_integers
 zero_ints = _st.builds(_Int)
@@ -48,3 +62,6 @@ small_ints = small_integers.map(_Int)
 maybe_small_integers = _st.none() | small_integers
 small_ints_with_builtins = (non_negative_one_byte_ints_with_builtins
                             | negative_one_byte_ints_with_builtins)
+float_exact_integers = _st.integers(-(1 << 46), 1 << 46)
+float_exact_ints_with_builtins = _st.builds(_to_int_with_builtin,
+                                            float_exact_integers)
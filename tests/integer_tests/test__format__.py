@@ -0,0 +1,30 @@
+import pytest
+from hypothesis import given
+
+from tests.utils import IntWithBuiltin
+from . import strategies
+
+
+@given(strategies.ints_with_builtins,
+       strategies.integral_format_specifiers)
+def test_integral_connection_with_builtin(int_with_builtin: IntWithBuiltin,
+                                          specifier: str) -> None:
+    int_, builtin_int = int_with_builtin
+
+    try:
+        result = format(int_, specifier)
+    except (OverflowError, ValueError) as exception:
+        with pytest.raises(type(exception)):
+            format(builtin_int, specifier)
+    else:
+        assert result == format(builtin_int, specifier)
+
+
+@given(strategies.float_exact_ints_with_builtins,
+       strategies.floating_point_format_specifiers)
+def test_floating_point_connection_with_builtin(
+        int_with_builtin: IntWithBuiltin, specifier: str
+) -> None:
+    int_, builtin_int = int_with_builtin
+
+    assert format(int_, specifier) == format(float(builtin_int), specifier)
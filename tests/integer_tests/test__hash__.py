@@ -9,3 +9,10 @@ def test_connection_with_builtin(int_with_builtin: IntWithBuiltin) -> None:
     int_, builtin_int = int_with_builtin
 
     assert hash(int_) == hash(builtin_int)
+
+
+@given(strategies.hash_boundary_ints_with_builtins)
+def test_boundary_values(int_with_builtin: IntWithBuiltin) -> None:
+    int_, builtin_int = int_with_builtin
+
+    assert hash(int_) == hash(builtin_int)
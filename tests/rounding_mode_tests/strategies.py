@@ -0,0 +1,17 @@
+from hypothesis import strategies as _st
+
+from rithm.enums import RoundingMode as _RoundingMode
+
+_rounding_modes = [_RoundingMode.UP, _RoundingMode.DOWN,
+                   _RoundingMode.CEILING, _RoundingMode.FLOOR,
+                   _RoundingMode.HALF_UP, _RoundingMode.HALF_DOWN,
+                   _RoundingMode.HALF_EVEN, _RoundingMode.UNNECESSARY,
+                   _RoundingMode.HALF_ODD]
+rounding_modes = _st.sampled_from(_rounding_modes)
+_rounding_modes_values = [rounding_mode.value
+                          for rounding_mode in _rounding_modes]
+rounding_modes_values = _st.sampled_from(_rounding_modes_values)
+non_rounding_modes_values = (
+        _st.integers().filter(lambda value: value not in _rounding_modes_values)
+        | _st.from_type(type).filter(lambda value: not isinstance(value, int))
+)
@@ -0,0 +1,34 @@
+from typing import Any
+
+import pytest
+from hypothesis import given
+
+from rithm.enums import RoundingMode
+from tests.utils import equivalence
+from . import strategies
+
+
+@given(strategies.rounding_modes_values)
+def test_basic(value: int) -> None:
+    result = RoundingMode(value)
+
+    assert isinstance(result, RoundingMode)
+
+
+@given(strategies.rounding_modes_values, strategies.rounding_modes_values)
+def test_bijection(first: int, second: int) -> None:
+    assert equivalence(first == second,
+                       RoundingMode(first) is RoundingMode(second))
+
+
+@given(strategies.rounding_modes_values)
+def test_value_round_trip(value: int) -> None:
+    result = RoundingMode(value)
+
+    assert result is RoundingMode(result.value)
+
+
+@given(strategies.non_rounding_modes_values)
+def test_invalid_value(value: Any) -> None:
+    with pytest.raises(ValueError):
+        RoundingMode(value)
@@ -0,0 +1,14 @@
+import sys
+
+from hypothesis import given
+
+from rithm.enums import RoundingMode
+from . import strategies
+
+
+@given(strategies.rounding_modes)
+def test_round_trip(rounding_mode: RoundingMode) -> None:
+    result = repr(rounding_mode)
+
+    assert (eval(result, vars(sys.modules[RoundingMode.__module__]))
+            is rounding_mode)
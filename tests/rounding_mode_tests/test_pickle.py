@@ -0,0 +1,10 @@
+from hypothesis import given
+
+from rithm.enums import RoundingMode
+from tests.utils import pickle_round_trip
+from . import strategies
+
+
+@given(strategies.rounding_modes)
+def test_round_trip(rounding_mode: RoundingMode) -> None:
+    assert pickle_round_trip(rounding_mode) is rounding_mode
@@ -0,0 +1,18 @@
+from hypothesis import given
+
+from rithm.enums import RoundingMode
+from . import strategies
+
+
+@given(strategies.rounding_modes)
+def test_basic(rounding_mode: RoundingMode) -> None:
+    result = rounding_mode.value
+
+    assert isinstance(result, int)
+
+
+@given(strategies.rounding_modes)
+def test_determinism(rounding_mode: RoundingMode) -> None:
+    result = rounding_mode.value
+
+    assert result == rounding_mode.value
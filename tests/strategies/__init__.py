@@ -1,6 +1,20 @@
-from . import literals as _literals
+from . import (format_specifiers as _format_specifiers,
+               literals as _literals)
 
 decimal_int_strings_with_leading_zeros = (
     _literals.decimal_int_strings_with_leading_zeros
 )
 int_strings_with_bases = _literals.int_strings_with_bases
+aligned_zero_padded_floating_point_format_specifiers = (
+    _format_specifiers.aligned_zero_padded_floating_point_format_specifiers
+)
+floating_point_format_specifiers = (
+    _format_specifiers.floating_point_format_specifiers
+)
+integral_format_specifiers = _format_specifiers.integral_format_specifiers
+non_zero_padded_floating_point_format_specifiers = (
+    _format_specifiers.non_zero_padded_floating_point_format_specifiers
+)
+zero_padded_floating_point_format_specifiers = (
+    _format_specifiers.zero_padded_floating_point_format_specifiers
+)
@@ -0,0 +1,40 @@
+from hypothesis import strategies
+
+alignments = strategies.sampled_from(['<', '>', '=', '^'])
+fills_with_alignments = (
+        strategies.just('')
+        | alignments
+        | strategies.tuples(strategies.sampled_from([' ', '*', '0']),
+                            alignments).map(''.join)
+)
+signs = strategies.sampled_from(['', '+', '-', ' '])
+alternate_forms = strategies.sampled_from(['', '#'])
+zero_paddings = strategies.sampled_from(['', '0'])
+widths = strategies.just('') | strategies.integers(1, 50).map(str)
+groupings = strategies.sampled_from(['', ',', '_'])
+precisions = (strategies.just('')
+              | strategies.integers(0, 50).map('.{}'.format))
+integral_types = strategies.sampled_from(['', 'b', 'c', 'd', 'n', 'o', 'x',
+                                          'X'])
+floating_point_types = strategies.sampled_from(['e', 'E', 'f', 'F', 'g', 'G',
+                                                '%'])
+integral_format_specifiers = strategies.tuples(
+        fills_with_alignments, signs, alternate_forms, zero_paddings, widths,
+        groupings, precisions, integral_types
+).map(''.join)
+floating_point_format_specifiers = strategies.tuples(
+        fills_with_alignments, signs, alternate_forms, zero_paddings, widths,
+        groupings, precisions, floating_point_types
+).map(''.join)
+zero_padded_floating_point_format_specifiers = strategies.tuples(
+        signs, alternate_forms, strategies.just('0'), widths, groupings,
+        precisions, floating_point_types
+).map(''.join)
+non_zero_padded_floating_point_format_specifiers = strategies.tuples(
+        fills_with_alignments, signs, alternate_forms, widths, groupings,
+        precisions, floating_point_types
+).map(''.join)
+aligned_zero_padded_floating_point_format_specifiers = strategies.tuples(
+        alignments, signs, alternate_forms, strategies.just('0'),
+        widths.filter(bool), groupings, precisions, floating_point_types
+).map(''.join)
@@ -0,0 +1,141 @@
+use rithm::big_int::BigInt;
+use rithm::fraction::Fraction;
+use traiter::numbers::{One, Zero};
+
+type BigInt31 = BigInt<u32, 31>;
+type Fraction31 = Fraction<BigInt31>;
+
+fn to_big_ints(values: &[i64]) -> Vec<BigInt31> {
+    values.iter().map(|&value| BigInt31::from(value)).collect()
+}
+
+fn to_fractions(values: &[(i64, i64)]) -> Vec<Fraction31> {
+    values
+        .iter()
+        .map(|&(numerator, denominator)| {
+            Fraction31::new(
+                BigInt31::from(numerator),
+                BigInt31::from(denominator),
+            )
+            .unwrap()
+        })
+        .collect()
+}
+
+const BIG_INT_CASES: [&[i64]; 7] = [
+    &[],
+    &[-7],
+    &[0],
+    &[3, -3],
+    &[1 << 40, 1 << 40, -(1 << 41)],
+    &[2, 3, 0, 5],
+    &[-1, -2, -3, -4, -5, -6, -7, -8, -9, -10, -11],
+];
+
+const FRACTION_CASES: [&[(i64, i64)]; 7] = [
+    &[],
+    &[(-7, 3)],
+    &[(0, 5)],
+    &[(1, 2), (-1, 2)],
+    &[(1, 6), (1, 10), (1, 15)],
+    &[(2, 3), (3, -4), (0, 1), (5, 7)],
+    &[
+        (1, 2),
+        (2, 3),
+        (3, 4),
+        (4, 5),
+        (5, 6),
+        (6, 7),
+        (7, 8),
+        (8, 9),
+    ],
+];
+
+#[test]
+fn big_int_sum() {
+    for case in BIG_INT_CASES {
+        let values = to_big_ints(case);
+        let expected = values
+            .iter()
+            .fold(BigInt31::zero(), |result, value| result + value);
+
+        assert_eq!(values.iter().sum::<BigInt31>(), expected);
+        assert_eq!(values.into_iter().sum::<BigInt31>(), expected);
+    }
+}
+
+#[test]
+fn big_int_product() {
+    for case in BIG_INT_CASES {
+        let values = to_big_ints(case);
+        let expected = values
+            .iter()
+            .fold(BigInt31::one(), |result, value| result * value);
+
+        assert_eq!(values.iter().product::<BigInt31>(), expected);
+        assert_eq!(values.into_iter().product::<BigInt31>(), expected);
+    }
+}
+
+#[test]
+fn big_int_empty_and_single() {
+    assert_eq!(Vec::<BigInt31>::new().into_iter().sum::<BigInt31>(), 0);
+    assert_eq!(Vec::<BigInt31>::new().iter().product::<BigInt31>(), 1);
+    let value = BigInt31::from(-(1i128 << 100));
+    assert_eq!([value.clone()].iter().sum::<BigInt31>(), value);
+    assert_eq!(vec![value.clone()].into_iter().product::<BigInt31>(), value);
+}
+
+#[test]
+fn fraction_sum() {
+    for case in FRACTION_CASES {
+        let values = to_fractions(case);
+        let expected = values
+            .iter()
+            .fold(Fraction31::zero(), |result, value| result + value);
+
+        assert_eq!(values.iter().sum::<Fraction31>(), expected);
+        assert_eq!(values.into_iter().sum::<Fraction31>(), expected);
+    }
+}
+
+#[test]
+fn fraction_product() {
+    for case in FRACTION_CASES {
+        let values = to_fractions(case);
+        let expected = values
+            .iter()
+            .fold(Fraction31::one(), |result, value| result * value);
+
+        assert_eq!(values.iter().product::<Fraction31>(), expected);
+        assert_eq!(values.into_iter().product::<Fraction31>(), expected);
+    }
+}
+
+#[test]
+fn fraction_sum_is_normalized() {
+    let values = to_fractions(&[(1, 6), (1, 3)]);
+
+    let result = values.iter().sum::<Fraction31>();
+
+    assert_eq!(result.numerator(), &BigInt31::from(1u8));
+    assert_eq!(result.denominator(), &BigInt31::from(2u8));
+}
+
+#[test]
+fn fraction_empty_and_single() {
+    assert_eq!(
+        Vec::<Fraction31>::new().into_iter().sum::<Fraction31>(),
+        Fraction31::zero()
+    );
+    assert_eq!(
+        Vec::<Fraction31>::new().iter().product::<Fraction31>(),
+        Fraction31::one()
+    );
+    let value = to_fractions(&[(-22, 7)]).remove(0);
+    assert_eq!([value.clone()].iter().sum::<Fraction31>(), value);
+    assert_eq!(
+        vec![value.clone()].into_iter().product::<Fraction31>(),
+        value
+    );
+}
@@ -1,4 +1,5 @@
 import fractions
+import math
 import pickle
 import typing as t
 
@@ -27,6 +28,11 @@ def is_equivalent_to_builtin_fraction(value: Fraction,
                                              builtin.denominator))
 
 
+def is_equivalent_to_builtin_float(value: float, builtin: float) -> bool:
+    return (value == builtin
+            and math.copysign(1.0, value) == math.copysign(1.0, builtin))
+
+
 def is_equivalent_to_builtin_int(int_: Int, builtin_int: int) -> bool:
     assert isinstance(int_, Int)
     assert isinstance(builtin_int, int)
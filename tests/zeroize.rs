@@ -0,0 +1,184 @@
+#![cfg(feature = "zeroize")]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::ptr;
+use std::slice;
+
+use rithm::big_int::BigInt;
+use rithm::fraction::Fraction;
+use traiter::numbers::{One, Sign, Zero};
+use zeroize::{Zeroize, Zeroizing};
+
+type BigInt31 = BigInt<u32, 31>;
+
+/// Checks contents of buffers on release per thread,
+/// so tests running concurrently do not affect each other.
+struct WipeCheckingAllocator;
+
+thread_local! {
+    static WATCHED_POINTER: Cell<*const u8> = const { Cell::new(ptr::null()) };
+    static IS_WATCHED_WIPED: Cell<Option<bool>> = const { Cell::new(None) };
+    static IS_CHECKING: Cell<bool> = const { Cell::new(false) };
+    static NOT_WIPED_COUNT: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for WipeCheckingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let is_wiped = slice::from_raw_parts(ptr, layout.size())
+            .iter()
+            .all(|&byte| byte == 0);
+        let _ = WATCHED_POINTER.try_with(|pointer| {
+            if ptr::eq(pointer.get(), ptr) {
+                pointer.set(ptr::null());
+                let _ =
+                    IS_WATCHED_WIPED.try_with(|flag| flag.set(Some(is_wiped)));
+            }
+        });
+        if !is_wiped && IS_CHECKING.try_with(Cell::get).unwrap_or(false) {
+            let _ =
+                NOT_WIPED_COUNT.try_with(|count| count.set(count.get() + 1));
+        }
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: WipeCheckingAllocator = WipeCheckingAllocator;
+
+fn is_wiped_on_release<T>(pointer: *const T, function: impl FnOnce()) -> bool {
+    WATCHED_POINTER.with(|watched| watched.set(pointer as *const u8));
+    IS_WATCHED_WIPED.with(|flag| flag.set(None));
+    function();
+    WATCHED_POINTER.with(|watched| watched.set(ptr::null()));
+    IS_WATCHED_WIPED
+        .with(Cell::take)
+        .expect("Buffer should be released.")
+}
+
+fn count_not_wiped_releases<T>(function: impl FnOnce() -> T) -> (T, usize) {
+    NOT_WIPED_COUNT.with(|count| count.set(0));
+    IS_CHECKING.with(|flag| flag.set(true));
+    let result = function();
+    IS_CHECKING.with(|flag| flag.set(false));
+    (result, NOT_WIPED_COUNT.with(Cell::get))
+}
+
+fn to_big_int(digits_count: usize, seed: u32) -> BigInt31 {
+    let digits = (0..digits_count as u32)
+        .map(|index| {
+            (index.wrapping_mul(0x9e37_79b9) ^ seed) & 0x7fff_ffff | 1
+        })
+        .collect();
+    unsafe { BigInt31::from_sign_and_digits_unchecked(Sign::Positive, digits) }
+}
+
+#[test]
+fn big_int_wipes_whole_buffer() {
+    let mut digits = Vec::with_capacity(32);
+    digits.extend([0x5555_5555u32; 16]);
+    let buffer_pointer = digits.as_ptr();
+    let mut value = unsafe {
+        BigInt31::from_sign_and_digits_unchecked(Sign::Negative, digits)
+    };
+
+    value.zeroize();
+
+    assert_eq!(value, BigInt31::zero());
+    let (sign, mut digits) = value.into_sign_and_digits();
+    assert_eq!(sign, Sign::Zero);
+    assert_eq!(digits.as_ptr(), buffer_pointer);
+    let capacity = digits.capacity();
+    unsafe { digits.set_len(capacity) };
+    assert!(digits.iter().all(|&digit| digit == 0));
+}
+
+#[test]
+fn big_int_wipes_inline_digits() {
+    let mut value = BigInt31::from(-0x3fff_ffff_ffff_ffffi64);
+
+    value.zeroize();
+
+    assert_eq!(value, BigInt31::zero());
+    assert_eq!(value.clone() + BigInt31::one(), BigInt31::one());
+}
+
+#[test]
+fn fraction_becomes_zero() {
+    let mut value =
+        Fraction::new(BigInt31::from(-22i8), BigInt31::from(7u8)).unwrap();
+
+    value.zeroize();
+
+    assert_eq!(value, Fraction::from(BigInt31::zero()));
+    assert_eq!(value.denominator(), &BigInt31::one());
+}
+
+#[test]
+fn zeroizing_wrapper() {
+    let value = Zeroizing::new(BigInt31::from(u64::MAX));
+
+    assert_eq!(*value, BigInt31::from(u64::MAX));
+    assert_eq!(&*value + &BigInt31::one(), BigInt31::from(1u128 << 64));
+}
+
+#[test]
+fn big_int_is_wiped_on_drop() {
+    let value = to_big_int(16, 0x5555_5555);
+    let buffer_pointer = value.digits().as_ptr();
+
+    assert!(is_wiped_on_release(buffer_pointer, || drop(value)));
+}
+
+#[test]
+fn fraction_is_wiped_on_drop() {
+    let value = Fraction::from(to_big_int(16, 0x5555_5555));
+    let buffer_pointer = value.numerator().digits().as_ptr();
+
+    assert!(is_wiped_on_release(buffer_pointer, || drop(value)));
+}
+
+#[test]
+fn buffer_is_wiped_on_reallocation() {
+    let mut digits = Vec::with_capacity(16);
+    digits.extend([0x7fff_ffffu32; 16]);
+    let buffer_pointer = digits.as_ptr();
+    let mut value = unsafe {
+        BigInt31::from_sign_and_digits_unchecked(Sign::Positive, digits)
+    };
+
+    assert!(is_wiped_on_release(buffer_pointer, || {
+        value += BigInt31::one()
+    }));
+    assert_eq!(value, BigInt31::one() << (16 * 31));
+}
+
+#[test]
+fn intermediate_buffers_are_wiped() {
+    let first = to_big_int(300, 0x1234_5678);
+    let second = to_big_int(200, 0x0fed_cba9);
+    let lopsided = to_big_int(80, 0x2468_ace0);
+
+    let (results, not_wiped_count) = count_not_wiped_releases(|| {
+        [
+            &first * &second,
+            &first * &first,
+            &first * &lopsided,
+            &first / &second,
+            &first % &lopsided,
+            {
+                let mut value = first.clone();
+                value *= &second;
+                value
+            },
+        ]
+    });
+
+    assert_eq!(not_wiped_count, 0);
+    assert_eq!(&results[0] / &second, first);
+    assert_eq!(&results[2] / &lopsided, first);
+}